@@ -1,6 +1,6 @@
 use bridge::{
     msg::{BridgeExecuteMsg, BridgeQueryMsg},
-    structs::BridgeInstantiateMsg,
+    structs::{BridgeInstantiateMsg, BridgeMigrateMsg},
 };
 use cosmwasm_schema::write_api;
 
@@ -8,6 +8,7 @@ fn main() {
     write_api! {
         instantiate: BridgeInstantiateMsg,
         execute: BridgeExecuteMsg,
-        query: BridgeQueryMsg
+        query: BridgeQueryMsg,
+        migrate: BridgeMigrateMsg
     }
 }
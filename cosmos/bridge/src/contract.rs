@@ -1,40 +1,63 @@
-use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 
 use collection_deployer::msg::{CollectionDeployerExecuteMsg, CollectionDeployerInstantiateMsg};
-use cosm_nft::royalty::RoyaltyData;
+use cosm_nft::royalty::{Royalty, RoyaltyData, RoyaltyInfo};
 use cosmwasm_std::{
-    entry_point, from_json, to_json_binary, Addr, Api, Attribute, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Reply, Response, StdError, StdResult, Storage, SubMsg, SubMsgResult, Uint128, WasmMsg
+    entry_point, from_json, to_json_binary, Addr, Api, Attribute, BankMsg, Binary, Coin, CosmosMsg,
+    Deps, DepsMut, Empty, Env, MessageInfo, Order, Reply, Response, StdError, StdResult, Storage,
+    SubMsg, SubMsgResult, Uint128, WasmMsg,
 };
 
 use cw0::{parse_execute_response_data, parse_reply_instantiate_data};
-use cw_storage_plus::Map;
+use cw_storage_plus::{Bound, Map};
 
 use crate::error::ContractError;
 use crate::events::{
-    AddNewValidatorEventInfo, BlacklistValidatorEventInfo, ClaimedEventInfo, LockedEventInfo, RewardValidatorEventInfo, UnLock721EventInfo
+    AddNewValidatorEventInfo, BlacklistValidatorEventInfo, ChildCodeUpgradedEventInfo,
+    Claimed1155EventInfo, ClaimedEventInfo, EmergencyReturnedEventInfo, LockedEventInfo,
+    PausedEventInfo, RewardValidatorEventInfo, SkippedSignersEventInfo,
+    SupportedChainUpdatedEventInfo, ThresholdPolicyUpdatedEventInfo, UnLock1155EventInfo,
+    UnLock721EventInfo, UnpausedEventInfo, ValidatorKeyRotatedEventInfo,
 };
 use crate::msg::{
-    BridgeExecuteMsg, BridgeQueryMsg, GetCollectionDeployerResponse,
-    GetDuplicateToOriginalResponse, GetOriginalToDuplicateResponse, GetStorageDeployerResponse,
-    GetStorageResponse, GetValidatorCountResponse, GetValidatorResponse,
+    BridgeExecuteMsg, BridgeQueryMsg, ComputeClaimHashResponse, GetChildCodeUpgradeNonceResponse,
+    GetClaimStatusResponse, GetCollectionDeployerResponse, GetDuplicateToOriginalResponse,
+    GetOriginalToDuplicateResponse, GetPrepaidLockFeesResponse, GetRetiredValidatorResponse,
+    GetRewardDustResponse, GetStorageDeployerResponse, GetStorageResponse,
+    GetValidatorCountResponse, GetValidatorResponse, GetValidatorRewardsResponse,
+    ListBlacklistedResponse, ListClaimsResponse, ListLockedTokensResponse, ListMappingsResponse,
+    ListStoragesResponse, ListSupportedChainsResponse, ListValidatorsResponse, LockedTokenEntry,
+    MappingEntry, MappingKind, StorageEntry, StorageKind, SupportedChainEntry, ValidatorEntry,
 };
 
 use crate::state::{
-    BLACKLISTED_VALIDATORS, COLLECTION_DEPLOYER_721_REPLY_ID, COLLECTION_DEPLOYER_REPLY_ID,
-    COLLETION_DEPLOYER_CODE, CONFIG, DUPLICATE_STORAGE_721, DUPLICATE_TO_ORIGINAL_STORAGE,
-    NFT_COLLECTION_OWNER, ORIGINAL_STORAGE_721, ORIGINAL_TO_DUPLICATE_STORAGE,
-    STORAGE_DEPLOYER_721_REPLY_ID, STORAGE_DEPLOYER_CODE, STORAGE_DEPLOYER_REPLY_ID,
-    UNIQUE_IDENTIFIER_STORAGE, VALIDATORS_STORAGE,
+    ClaimRecordKey, BLACKLISTED_VALIDATORS, BURN_ON_RETURN_721, CHILD_CODE_UPGRADE_NONCE,
+    CLAIM_RECORDS, COLLECTION_DEPLOYER_1155_REPLY_ID, COLLECTION_DEPLOYER_721_REPLY_ID,
+    COLLECTION_DEPLOYER_REPLY_ID, COLLETION_DEPLOYER_CODE, CONFIG, CONTRACT_NAME, CONTRACT_VERSION,
+    DEFAULT_MAX_BATCH_SIZE, DEFAULT_QUERY_LIMIT, DUPLICATE_STORAGE_1155, DUPLICATE_STORAGE_721,
+    DUPLICATE_TO_ORIGINAL_STORAGE, MAX_QUERY_LIMIT, NFT_COLLECTION_OWNER, ORIGINAL_STORAGE_1155,
+    ORIGINAL_STORAGE_721, ORIGINAL_TO_DUPLICATE_STORAGE, PENDING_COLLECTION_CLAIMS_721,
+    PENDING_STORAGE_LOCKS_721, PREPAID_LOCK_FEES, RETIRED_VALIDATORS, REWARD_DUST, REWARD_NONCES,
+    STORAGE_DEPLOYER_1155_REPLY_ID, STORAGE_DEPLOYER_721_REPLY_ID, STORAGE_DEPLOYER_CODE,
+    STORAGE_DEPLOYER_REPLY_ID, SUPPORTED_CHAINS, SUPPORTED_CHAINS_NONCE, UNIQUE_IDENTIFIER_STORAGE,
+    VALIDATORS_STORAGE, VALIDATOR_REWARDS,
 };
 
 use crate::structs::{
-    AddValidatorMsg, BlacklistValidatorMsg, BridgeInstantiateMsg, ClaimData, ClaimMsg,
-    ClaimValidatorRewardsMsg, DuplicateToOriginalContractInfo, Lock721Msg,
-    OriginalToDuplicateContractInfo, ReplyCollectionDeployerInfo, ReplyCollectionInfo,
-    ReplyStorageDeployerInfo, ReplyStorageInfo, SignerAndSignature, State, Validator, VerifyMsg,
+    AddValidatorMsg, BlacklistValidatorMsg, BridgeInstantiateMsg, BridgeMigrateMsg, ChildContract,
+    ClaimData, ClaimMsg, ClaimRecord, ClaimValidatorRewardsMsg, Custody721,
+    DuplicateToOriginalContractInfo, EmergencyReturnMsg, GovernanceMessage, Lock1155Msg,
+    Lock721Msg, OriginalToDuplicateContractInfo, PauseMatrix, ReplyCollectionDeployerInfo,
+    ReplyCollectionInfo, ReplyStorage1155Info, ReplyStorageDeployerInfo, ReplyStorageInfo,
+    RotateValidatorKeyMsg, SetPausedMsg, SetSupportedChainMsg, SetThresholdPolicyMsg,
+    SignerAndSignature, State, UpgradeChildCodeMsg, Validator, ValidatorAddition,
+    ValidatorBlacklisting, VerifyMsg,
 };
-use cosm_nft::NftExecuteMsg;
-use nft_store::msg::NftStoreExecuteMsg;
+use cosm_nft::{msg::NftExtensionMsg, NftExecuteMsg};
+use cosm_nft1155::msg::Nft1155ExecuteMsg;
+use cw721::{Cw721QueryMsg, Cw721ReceiveMsg, OperatorResponse, OwnerOfResponse};
+use nft_store::msg::{GetTokenCustodyResponse, NftStoreExecuteMsg, NftStoreQueryMsg};
+use semver::Version;
 use sha2::{Digest, Sha256};
 use store_deployer::msg::{StoreFactoryExecuteMsg, StoreFactoryInstantiateMsg};
 
@@ -44,9 +67,13 @@ pub fn instantiate(
     env: Env,
     _info: MessageInfo,
     msg: BridgeInstantiateMsg,
-) -> StdResult<Response> {
+) -> Result<Response, ContractError> {
     let mut validators_count = 0;
 
+    for (chain, config) in msg.supported_chains {
+        SUPPORTED_CHAINS.save(deps.storage, chain, &config)?;
+    }
+
     for (pubk, address) in msg.validators {
         validators_count += 1;
         VALIDATORS_STORAGE.save(
@@ -62,25 +89,37 @@ pub fn instantiate(
 
     let state = State {
         collection_deployer: env.contract.address.clone(),
-        storage_deployer: env.contract.address,
+        storage_deployer: env.contract.address.clone(),
         validators_count,
         self_chain: msg.chain_type,
         type_erc_721: "singular".to_owned(),
         type_erc_1155: "multiple".to_owned(),
+        fee_denom: msg.fee_denom,
+        legacy_claim_hash_until: msg.legacy_claim_hash_until,
+        pause: PauseMatrix::default(),
+        max_batch_size: msg.max_batch_size.unwrap_or(DEFAULT_MAX_BATCH_SIZE),
+        burn_on_return: msg.burn_on_return,
+        threshold: msg.threshold.unwrap_or_default(),
+        threshold_nonce: 0,
     };
+    if !state.threshold.is_valid() {
+        return Err(ContractError::InvalidThresholdPolicy {});
+    }
 
     CONFIG.save(deps.storage, &state)?;
+    cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
 
     STORAGE_DEPLOYER_CODE.save(deps.storage, &msg.storage_deployer_code_id)?;
     COLLETION_DEPLOYER_CODE.save(deps.storage, &msg.collection_deployer_code_id)?;
 
     let init_storage_deployer_msg = StoreFactoryInstantiateMsg {
         storage721_code_id: msg.storage721_code_id,
+        storage1155_code_id: msg.storage1155_code_id,
     };
 
     let init_storage_deployer_sub_msg = SubMsg::reply_always(
         CosmosMsg::Wasm(WasmMsg::Instantiate {
-            admin: None,
+            admin: Some(env.contract.address.to_string()),
             code_id: msg.storage_deployer_code_id,
             msg: to_json_binary(&init_storage_deployer_msg)?,
             funds: vec![],
@@ -91,11 +130,12 @@ pub fn instantiate(
 
     let init_collection_deployer_msg = CollectionDeployerInstantiateMsg {
         collection721_code_id: msg.collection721_code_id,
+        collection1155_code_id: msg.collection1155_code_id,
     };
 
     let init_collection_deployer_submsg = SubMsg::reply_always(
         CosmosMsg::Wasm(WasmMsg::Instantiate {
-            admin: None,
+            admin: Some(env.contract.address.to_string()),
             code_id: msg.collection_deployer_code_id,
             msg: to_json_binary(&init_collection_deployer_msg)?,
             funds: vec![],
@@ -115,18 +155,338 @@ pub fn execute(
     env: Env,
     info: MessageInfo,
     msg: BridgeExecuteMsg,
-) -> StdResult<Response> {
+) -> Result<Response, ContractError> {
     match msg {
-        BridgeExecuteMsg::AddValidator { data } => add_validator(deps, data),
-        BridgeExecuteMsg::ClaimValidatorRewards { data } => claim_validator_rewards(deps, data),
-        BridgeExecuteMsg::BlacklistValidator { data } => blacklist_validator(deps, data),
-        BridgeExecuteMsg::Lock721 { data } => lock721(deps, env, data),
+        BridgeExecuteMsg::AddValidator { data } => add_validator(deps, env, data),
+        BridgeExecuteMsg::ClaimValidatorRewards { data } => {
+            claim_validator_rewards(deps, env, data)
+        }
+        BridgeExecuteMsg::RotateValidatorKey { data } => rotate_validator_key(deps, env, data),
+        BridgeExecuteMsg::BlacklistValidator { data } => blacklist_validator(deps, env, data),
+        BridgeExecuteMsg::Lock721 { data } => lock721(deps, env, info, data),
+        BridgeExecuteMsg::Lock1155 { data } => lock1155(deps, env, info, data),
+        BridgeExecuteMsg::BatchLock721 { items } => batch_lock721(deps, env, info, items),
         BridgeExecuteMsg::Claim721 { data } => claim721(deps, env, info, data),
+        BridgeExecuteMsg::Claim1155 { data } => claim1155(deps, env, info, data),
+        BridgeExecuteMsg::BatchClaim721 { claims } => batch_claim721(deps, env, info, claims),
         BridgeExecuteMsg::VerifySig { data } => verify_sig(deps, data),
+        BridgeExecuteMsg::UpgradeChildCode { data } => upgrade_child_code(deps, env, data),
+        BridgeExecuteMsg::SetPaused { data } => set_paused(deps, env, data),
+        BridgeExecuteMsg::SetSupportedChain { data } => set_supported_chain(deps, env, data),
+        BridgeExecuteMsg::SetThresholdPolicy { data } => set_threshold_policy(deps, env, data),
+        BridgeExecuteMsg::ReceiveNft(receive) => receive_nft(deps, env, info, receive),
+        BridgeExecuteMsg::DepositLockFees {} => deposit_lock_fees(deps, info),
+        BridgeExecuteMsg::WithdrawLockFees {} => withdraw_lock_fees(deps, info),
+        BridgeExecuteMsg::EmergencyReturn721 { data } => emergency_return_721(deps, env, data),
+    }
+}
+
+#[entry_point]
+pub fn migrate(deps: DepsMut, _env: Env, msg: BridgeMigrateMsg) -> Result<Response, ContractError> {
+    let current = parse_version(CONTRACT_VERSION)?;
+    // bridges instantiated before cw2 was recorded have no version to compare
+    if let Some(stored) = cw2::CONTRACT.may_load(deps.storage)? {
+        if stored.contract != CONTRACT_NAME {
+            return Err(ContractError::InvalidMigrationContract {
+                expected: CONTRACT_NAME.to_string(),
+                found: stored.contract,
+            });
+        }
+        if parse_version(&stored.version)? > current {
+            return Err(ContractError::CannotMigrateFromNewerVersion {
+                stored: stored.version,
+                current: CONTRACT_VERSION.to_string(),
+            });
+        }
+    }
+    cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    if let Some(until) = msg.legacy_claim_hash_until {
+        CONFIG.update(deps.storage, |mut state| -> StdResult<_> {
+            state.legacy_claim_hash_until = Some(until);
+            Ok(state)
+        })?;
+    }
+    if let Some(max_batch_size) = msg.max_batch_size {
+        CONFIG.update(deps.storage, |mut state| -> StdResult<_> {
+            state.max_batch_size = max_batch_size;
+            Ok(state)
+        })?;
+    }
+    if let Some(burn_on_return) = msg.burn_on_return {
+        CONFIG.update(deps.storage, |mut state| -> StdResult<_> {
+            state.burn_on_return = burn_on_return;
+            Ok(state)
+        })?;
+    }
+    // legacy rewards were paid in the denom before the migration
+    migrate_legacy_rewards(deps.storage)?;
+    if let Some(fee_denom) = msg.fee_denom {
+        CONFIG.update(deps.storage, |mut state| -> StdResult<_> {
+            state.fee_denom = fee_denom;
+            Ok(state)
+        })?;
+    }
+
+    Ok(Response::new()
+        .add_attribute("action", "migrate")
+        .add_attribute("version", CONTRACT_VERSION))
+}
+
+/// Moves rewards accrued before they were accounted per denom to
+/// [VALIDATOR_REWARDS], under the fee denom they were paid in
+fn migrate_legacy_rewards(storage: &mut dyn Storage) -> StdResult<()> {
+    let legacy: Vec<(Vec<u8>, Validator)> = VALIDATORS_STORAGE
+        .range(storage, None, None, Order::Ascending)
+        .filter(|item| !matches!(item, Ok((_, v)) if v.pending_reward == 0))
+        .collect::<StdResult<_>>()?;
+    if legacy.is_empty() {
+        return Ok(());
+    }
+    let fee_denom = CONFIG.load(storage)?.fee_denom;
+    for (public_key, mut validator) in legacy {
+        add_reward(
+            storage,
+            public_key.clone(),
+            &fee_denom,
+            Uint128::from(validator.pending_reward),
+        )?;
+        validator.pending_reward = 0;
+        VALIDATORS_STORAGE.save(storage, public_key, &validator)?;
+    }
+    Ok(())
+}
+
+fn parse_version(version: &str) -> Result<Version, ContractError> {
+    version
+        .parse()
+        .map_err(|_| ContractError::InvalidContractVersion {
+            version: version.to_string(),
+        })
+}
+
+/// Rotates the code id a deployer uses for new children. Upgrades are applied
+/// in nonce order, each once.
+fn upgrade_child_code(
+    deps: DepsMut,
+    env: Env,
+    msg: UpgradeChildCodeMsg,
+) -> Result<Response, ContractError> {
+    let nonce = CHILD_CODE_UPGRADE_NONCE
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    if msg.upgrade.nonce != nonce {
+        return Err(ContractError::InvalidUpgradeNonce {
+            expected: nonce,
+            found: msg.upgrade.nonce,
+        });
+    }
+
+    let state = CONFIG.load(deps.storage)?;
+    let hash = governance_hash(&msg.upgrade, &env, &state)?;
+    let (signers, skipped) = aggregate_signatures(deps.storage, deps.api, &hash, &msg.signatures)?;
+    ensure_threshold(signers.len(), required_threshold(&state))?;
+    CHILD_CODE_UPGRADE_NONCE.save(deps.storage, &(nonce + 1))?;
+
+    let code_id = Some(msg.upgrade.code_id);
+    let (deployer, update_msg) = match msg.upgrade.child {
+        ChildContract::Storage721 => (
+            state.storage_deployer,
+            to_json_binary(&StoreFactoryExecuteMsg::UpdateStorageCode {
+                storage721_code_id: code_id,
+                storage1155_code_id: None,
+            })?,
+        ),
+        ChildContract::Storage1155 => (
+            state.storage_deployer,
+            to_json_binary(&StoreFactoryExecuteMsg::UpdateStorageCode {
+                storage721_code_id: None,
+                storage1155_code_id: code_id,
+            })?,
+        ),
+        ChildContract::Collection721 => (
+            state.collection_deployer,
+            to_json_binary(&CollectionDeployerExecuteMsg::UpdateCollectionCode {
+                collection721_code_id: code_id,
+                collection1155_code_id: None,
+            })?,
+        ),
+        ChildContract::Collection1155 => (
+            state.collection_deployer,
+            to_json_binary(&CollectionDeployerExecuteMsg::UpdateCollectionCode {
+                collection721_code_id: None,
+                collection1155_code_id: code_id,
+            })?,
+        ),
+    };
+
+    let message = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: deployer.into_string(),
+        msg: update_msg,
+        funds: vec![],
+    });
+    let log: Vec<Attribute> =
+        vec![ChildCodeUpgradedEventInfo::new(msg.upgrade.child, msg.upgrade.code_id).try_into()?];
+
+    Ok(Response::new()
+        .add_message(message)
+        .add_attributes(log)
+        .add_attributes(skipped.into_log()?))
+}
+
+/// Replaces the pause matrix. Updates are applied in nonce order, each once.
+fn set_paused(deps: DepsMut, env: Env, msg: SetPausedMsg) -> Result<Response, ContractError> {
+    let mut state = CONFIG.load(deps.storage)?;
+    if msg.update.nonce != state.pause.nonce {
+        return Err(ContractError::InvalidPauseNonce {
+            expected: state.pause.nonce,
+            found: msg.update.nonce,
+        });
+    }
+
+    let hash = governance_hash(&msg.update, &env, &state)?;
+    let (signers, skipped) = aggregate_signatures(deps.storage, deps.api, &hash, &msg.signatures)?;
+    ensure_threshold(signers.len(), required_threshold(&state))?;
+
+    let pause = PauseMatrix {
+        locks: msg.update.locks,
+        claims: msg.update.claims,
+        disabled_destination_chains: msg.update.disabled_destination_chains,
+        disabled_source_chains: msg.update.disabled_source_chains,
+        nonce: state.pause.nonce + 1,
+    };
+    let paused = PausedEventInfo::new(&state.pause, &pause);
+    let unpaused = UnpausedEventInfo::new(&state.pause, &pause);
+    state.pause = pause;
+    CONFIG.save(deps.storage, &state)?;
+
+    Ok(Response::new()
+        .add_attributes(paused.into_log()?)
+        .add_attributes(unpaused.into_log()?)
+        .add_attributes(skipped.into_log()?))
+}
+
+/// Registers or reconfigures a destination chain. Updates are applied in
+/// nonce order, each once.
+fn set_supported_chain(
+    deps: DepsMut,
+    env: Env,
+    msg: SetSupportedChainMsg,
+) -> Result<Response, ContractError> {
+    let nonce = SUPPORTED_CHAINS_NONCE
+        .may_load(deps.storage)?
+        .unwrap_or_default();
+    if msg.update.nonce != nonce {
+        return Err(ContractError::InvalidSupportedChainNonce {
+            expected: nonce,
+            found: msg.update.nonce,
+        });
+    }
+
+    let state = CONFIG.load(deps.storage)?;
+    let hash = governance_hash(&msg.update, &env, &state)?;
+    let (signers, skipped) = aggregate_signatures(deps.storage, deps.api, &hash, &msg.signatures)?;
+    ensure_threshold(signers.len(), required_threshold(&state))?;
+
+    SUPPORTED_CHAINS.save(deps.storage, msg.update.chain.clone(), &msg.update.config)?;
+    SUPPORTED_CHAINS_NONCE.save(deps.storage, &(nonce + 1))?;
+
+    let log: Vec<Attribute> =
+        vec![SupportedChainUpdatedEventInfo::new(msg.update.chain, msg.update.config).try_into()?];
+    Ok(Response::new()
+        .add_attributes(log)
+        .add_attributes(skipped.into_log()?))
+}
+
+/// Replaces the threshold policy. Updates are applied in nonce order, each
+/// once, and can't require more signatures than there are validators.
+fn set_threshold_policy(
+    deps: DepsMut,
+    env: Env,
+    msg: SetThresholdPolicyMsg,
+) -> Result<Response, ContractError> {
+    let mut state = CONFIG.load(deps.storage)?;
+    if msg.update.nonce != state.threshold_nonce {
+        return Err(ContractError::InvalidThresholdPolicyNonce {
+            expected: state.threshold_nonce,
+            found: msg.update.nonce,
+        });
+    }
+    let policy = msg.update.policy.clone();
+    if !policy.is_valid() || policy.minimum as i128 > state.validators_count {
+        return Err(ContractError::InvalidThresholdPolicy {});
+    }
+
+    let hash = governance_hash(&msg.update, &env, &state)?;
+    let (signers, skipped) = aggregate_signatures(deps.storage, deps.api, &hash, &msg.signatures)?;
+    ensure_threshold(signers.len(), required_threshold(&state))?;
+
+    state.threshold = policy.clone();
+    state.threshold_nonce += 1;
+    CONFIG.save(deps.storage, &state)?;
+
+    let log: Vec<Attribute> = vec![ThresholdPolicyUpdatedEventInfo::new(policy).try_into()?];
+    Ok(Response::new()
+        .add_attributes(log)
+        .add_attributes(skipped.into_log()?))
+}
+
+/// Returns the lock fee of `destination_chain`
+fn ensure_supported_destination(
+    storage: &dyn Storage,
+    destination_chain: &str,
+    destination_user_address: &str,
+) -> Result<u128, ContractError> {
+    let chain = SUPPORTED_CHAINS
+        .may_load(storage, destination_chain.to_string())?
+        .ok_or_else(|| ContractError::UnsupportedChain {
+            chain: destination_chain.to_string(),
+        })?;
+    if !chain.enabled {
+        return Err(ContractError::ChainNotEnabled {
+            chain: destination_chain.to_string(),
+        });
     }
+    if !chain.address_format.is_valid(destination_user_address) {
+        return Err(ContractError::InvalidDestinationAddress {
+            chain: destination_chain.to_string(),
+            address: destination_user_address.to_string(),
+        });
+    }
+    Ok(chain.lock_fee.unwrap_or_default().u128())
 }
 
-fn verify_sig(deps: DepsMut, msg: VerifyMsg) -> StdResult<Response> {
+fn ensure_locks_allowed(pause: &PauseMatrix, destination_chain: &str) -> Result<(), ContractError> {
+    if pause.locks {
+        return Err(ContractError::LocksPaused {});
+    }
+    if pause
+        .disabled_destination_chains
+        .iter()
+        .any(|chain| chain == destination_chain)
+    {
+        return Err(ContractError::DestinationChainDisabled {
+            chain: destination_chain.to_string(),
+        });
+    }
+    Ok(())
+}
+
+fn ensure_claims_allowed(pause: &PauseMatrix, data: &ClaimData) -> Result<(), ContractError> {
+    if pause.claims {
+        return Err(ContractError::ClaimsPaused {});
+    }
+    for chain in [&data.source_chain, &data.lock_tx_chain] {
+        if pause.disabled_source_chains.contains(chain) {
+            return Err(ContractError::SourceChainDisabled {
+                chain: chain.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
+fn verify_sig(deps: DepsMut, msg: VerifyMsg) -> Result<Response, ContractError> {
     let serialized = serde_json::to_vec(&msg.claim_data_as_binary).unwrap();
     let mut hasher = Sha256::new();
     hasher.update(serialized);
@@ -143,128 +503,238 @@ fn verify_sig(deps: DepsMut, msg: VerifyMsg) -> StdResult<Response> {
 
     let res = deps
         .api
-        .secp256k1_verify(&msg.claim_data, &msg.signature, &msg.user)?;
+        .secp256k1_verify(&msg.claim_data, &msg.signature, &msg.user)
+        .unwrap_or(false);
     if !res {
-        Err(StdError::generic_err(
-            "Signature verification unsuccessful".to_string(),
-        ))
+        Err(ContractError::InvalidSignature {})
     } else {
         Ok(Response::default())
     }
 }
 
-fn matches_current_chain(storage: &dyn Storage, destination_chain: String) -> StdResult<Response> {
-    if destination_chain == CONFIG.load(storage)?.self_chain {
-        return Err(StdError::generic_err("Invalid destination chain!"));
-    } else {
-        Ok(Response::default())
+fn matches_current_chain(
+    storage: &dyn Storage,
+    destination_chain: &str,
+) -> Result<(), ContractError> {
+    if destination_chain != CONFIG.load(storage)?.self_chain {
+        return Err(ContractError::InvalidDestinationChain {});
     }
+    Ok(())
 }
 
-fn has_correct_fee(fee: u128, info: MessageInfo) -> StdResult<Response> {
-    let msg_value = info.funds[0].amount;
-
-    if Uint128::from(fee) <= msg_value {
-        Ok(Response::default())
-    } else {
-        return Err(StdError::generic_err("data.fee LESS THAN sent amount!"));
+fn has_correct_fee(fee: u128, fee_denom: &str, info: &MessageInfo) -> Result<(), ContractError> {
+    let sent: Uint128 = info
+        .funds
+        .iter()
+        .filter(|coin| coin.denom == fee_denom)
+        .map(|coin| coin.amount)
+        .sum();
+
+    if sent < Uint128::from(fee) {
+        return Err(ContractError::FeeTooLow {
+            sent,
+            required: Uint128::from(fee),
+        });
     }
+    Ok(())
 }
 
-fn blacklist_validator(deps: DepsMut, blacklist_msg: BlacklistValidatorMsg) -> StdResult<Response> {
-    if blacklist_msg.signatures.len() <= 0 {
-        return Err(StdError::generic_err("Must have signatures!"));
+fn blacklist_validator(
+    deps: DepsMut,
+    env: Env,
+    blacklist_msg: BlacklistValidatorMsg,
+) -> Result<Response, ContractError> {
+    if blacklist_msg.signatures.is_empty() {
+        return Err(ContractError::MissingSignatures {});
     }
-    if !VALIDATORS_STORAGE.has( deps.storage, blacklist_msg.validator.0 .0.clone()) {
-        return Err(StdError::generic_err("Validator is not added"));
+    if !VALIDATORS_STORAGE.has(deps.storage, blacklist_msg.validator.0 .0.clone()) {
+        return Err(ContractError::UnknownValidator {});
     }
-    let percentage = validate_signatures(
-        deps.api,
-        &blacklist_msg.validator.0.clone(),
-        &blacklist_msg.signatures,
-    )?;
     let state = CONFIG.load(deps.storage)?;
-    if percentage < required_threshold(state.validators_count as u128) {
-        return Err(StdError::generic_err("Threshold not reached!"));
-    }
+    let blacklisting = ValidatorBlacklisting {
+        public_key: blacklist_msg.validator.0.clone(),
+    };
+    let hash = governance_hash(&blacklisting, &env, &state)?;
+    let (signers, skipped) =
+        aggregate_signatures(deps.storage, deps.api, &hash, &blacklist_msg.signatures)?;
+    ensure_threshold(signers.len(), required_threshold(&state))?;
     VALIDATORS_STORAGE.remove(deps.storage, blacklist_msg.validator.0 .0.clone());
     CONFIG.update(deps.storage, |mut state| -> Result<_, StdError> {
         state.validators_count -= 1;
         Ok(state) // Return the modified state
     })?;
+    let forfeited = forfeit_rewards(deps.storage, &blacklist_msg.validator.0)?;
     BLACKLISTED_VALIDATORS.save(deps.storage, blacklist_msg.validator.0 .0, &true)?;
-    let log: Vec<Attribute> = vec![BlacklistValidatorEventInfo::new(blacklist_msg.validator.1).try_into()?];
-    Ok(Response::new().add_attributes(log))
+    let log: Vec<Attribute> =
+        vec![BlacklistValidatorEventInfo::new(blacklist_msg.validator.1, forfeited).try_into()?];
+    Ok(Response::new()
+        .add_attributes(log)
+        .add_attributes(skipped.into_log()?))
+}
+
+/// Moves the pending rewards of a blacklisted validator to the reward dust, so
+/// they are split between the signers of the next fee in their denom
+fn forfeit_rewards(storage: &mut dyn Storage, validator: &Binary) -> StdResult<Vec<Coin>> {
+    let rewards: Vec<Coin> = VALIDATOR_REWARDS
+        .prefix(validator.to_vec())
+        .range(storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(denom, amount)| Coin { denom, amount }))
+        .collect::<StdResult<_>>()?;
+    for coin in &rewards {
+        VALIDATOR_REWARDS.remove(storage, (validator.to_vec(), coin.denom.clone()));
+        REWARD_DUST.update(storage, coin.denom.clone(), |dust| -> StdResult<_> {
+            Ok(dust.unwrap_or_default() + coin.amount)
+        })?;
+    }
+    Ok(rewards)
+}
+
+/// Moves a validator, with its rewards and withdrawal nonce, from `old` to
+/// `new`. The old key has to sign along with the threshold and is retired
+/// instead of blacklisted.
+fn rotate_validator_key(
+    deps: DepsMut,
+    env: Env,
+    msg: RotateValidatorKeyMsg,
+) -> Result<Response, ContractError> {
+    let old = msg.rotation.old.to_vec();
+    let new = msg.rotation.new.to_vec();
+    let validator = VALIDATORS_STORAGE
+        .may_load(deps.storage, old.clone())?
+        .filter(|v| v.added)
+        .ok_or(ContractError::UnknownValidator {})?;
+    if new.len() != 33 {
+        return Err(ContractError::InvalidValidatorKey {});
+    }
+    if VALIDATORS_STORAGE.has(deps.storage, new.clone())
+        || BLACKLISTED_VALIDATORS.has(deps.storage, new.clone())
+        || RETIRED_VALIDATORS.has(deps.storage, new.clone())
+    {
+        return Err(ContractError::ValidatorKeyInUse {});
+    }
+
+    let state = CONFIG.load(deps.storage)?;
+    let hash = governance_hash(&msg.rotation, &env, &state)?;
+    let (signers, skipped) = aggregate_signatures(deps.storage, deps.api, &hash, &msg.signatures)?;
+    if !signers.contains(&msg.rotation.old) {
+        return Err(ContractError::MissingRotatedKeySignature {});
+    }
+    ensure_threshold(signers.len(), required_threshold(&state))?;
+
+    let rewards: Vec<(String, Uint128)> = VALIDATOR_REWARDS
+        .prefix(old.clone())
+        .range(deps.storage, None, None, Order::Ascending)
+        .collect::<StdResult<_>>()?;
+    for (denom, amount) in rewards {
+        VALIDATOR_REWARDS.remove(deps.storage, (old.clone(), denom.clone()));
+        VALIDATOR_REWARDS.save(deps.storage, (new.clone(), denom), &amount)?;
+    }
+    if let Some(nonce) = REWARD_NONCES.may_load(deps.storage, old.clone())? {
+        REWARD_NONCES.remove(deps.storage, old.clone());
+        REWARD_NONCES.save(deps.storage, new.clone(), &nonce)?;
+    }
+
+    VALIDATORS_STORAGE.remove(deps.storage, old.clone());
+    VALIDATORS_STORAGE.save(deps.storage, new, &validator)?;
+    RETIRED_VALIDATORS.save(deps.storage, old, &msg.rotation.new)?;
+
+    let log: Vec<Attribute> = vec![ValidatorKeyRotatedEventInfo::new(
+        validator.address,
+        msg.rotation.old,
+        msg.rotation.new,
+    )
+    .try_into()?];
+    Ok(Response::new()
+        .add_attributes(log)
+        .add_attributes(skipped.into_log()?))
 }
 
-fn add_validator(deps: DepsMut, add_validator_msg: AddValidatorMsg) -> StdResult<Response> {
+fn add_validator(
+    deps: DepsMut,
+    env: Env,
+    add_validator_msg: AddValidatorMsg,
+) -> Result<Response, ContractError> {
     if BLACKLISTED_VALIDATORS.has(deps.storage, add_validator_msg.validator.0 .0.clone()) {
-        return Err(StdError::generic_err("validator blacklisted"));
+        return Err(ContractError::ValidatorBlacklisted {});
+    }
+    if RETIRED_VALIDATORS.has(deps.storage, add_validator_msg.validator.0 .0.clone()) {
+        return Err(ContractError::ValidatorRetired {});
     }
 
     if add_validator_msg.signatures.is_empty() {
-        return Err(StdError::generic_err("Must have signatures!"));
+        return Err(ContractError::MissingSignatures {});
     }
 
     let state = CONFIG.load(deps.storage)?;
     if VALIDATORS_STORAGE.has(deps.storage, add_validator_msg.validator.0 .0.clone()) {
-        return Err(StdError::generic_err("Validator already added"));
+        return Err(ContractError::ValidatorAlreadyAdded {});
     }
 
-    let percentage = validate_signatures(
-        deps.api,
-        &add_validator_msg.validator.0.clone(),
-        &add_validator_msg.signatures,
-    )?;
-    if percentage < required_threshold(state.validators_count as u128) {
-        return Err(StdError::generic_err("Threshold not reached!"));
-    }
+    let addition = ValidatorAddition {
+        public_key: add_validator_msg.validator.0.clone(),
+        address: add_validator_msg.validator.1.clone(),
+    };
+    let hash = governance_hash(&addition, &env, &state)?;
+    let (signers, skipped) =
+        aggregate_signatures(deps.storage, deps.api, &hash, &add_validator_msg.signatures)?;
+    ensure_threshold(signers.len(), required_threshold(&state))?;
 
-    Ok(add_validator_to_state(
-        deps.storage,
-        &add_validator_msg.validator,
-    )?)
+    let res = add_validator_to_state(deps.storage, &add_validator_msg.validator)?;
+    Ok(res.add_attributes(skipped.into_log()?))
 }
 
-fn validate_signatures(
+/// Returns the signers counted towards the threshold: registered, non-blacklisted
+/// validators with a valid signature over `hash`, each counted once. Every other
+/// signature is skipped and reported.
+fn aggregate_signatures(
+    storage: &dyn Storage,
     api: &dyn Api,
-    key: &Binary,
-    sigs: &Vec<SignerAndSignature>,
-) -> StdResult<i128> {
-    let mut percentage = 0;
-    let serialized = key.to_string();
-    let mut hasher = Sha256::new();
-
-    hasher.update(serialized);
-    let hash: [u8; 32] = hasher.finalize().into();
-    let mut unique = BTreeMap::new();
+    hash: &[u8; 32],
+    sigs: &[SignerAndSignature],
+) -> StdResult<(Vec<Binary>, SkippedSignersEventInfo)> {
+    let mut signers: Vec<Binary> = Vec::new();
+    let mut skipped = SkippedSignersEventInfo::default();
+    let mut seen = BTreeSet::new();
 
     for ele in sigs {
-        if unique.contains_key(&ele.signer_address) {
+        let signer = ele.signer_address.clone();
+        if !seen.insert(signer.clone()) {
+            skipped.duplicate.push(signer);
             continue;
         }
-        unique.insert(ele.signer_address.clone(), true);
-        if verify_signature(api, &ele.signature, &ele.signer_address, &hash)? {
-            percentage += 1;
+        if BLACKLISTED_VALIDATORS.has(storage, signer.to_vec()) {
+            skipped.blacklisted.push(signer);
+            continue;
+        }
+        match VALIDATORS_STORAGE.may_load(storage, signer.to_vec())? {
+            Some(validator) if validator.added => {}
+            _ => {
+                skipped.unknown.push(signer);
+                continue;
+            }
+        }
+        // a malformed signature or key counts as an invalid signature
+        if let Ok(true) = verify_signatures(api, &ele.signature, &signer, hash) {
+            signers.push(signer);
+        } else {
+            skipped.invalid.push(signer);
         }
     }
-    Ok(percentage)
+    Ok((signers, skipped))
 }
 
-fn verify_signature(
-    api: &dyn Api,
-    signature: &[u8],
-    signer_address: &[u8],
-    hash: &[u8; 32],
-) -> StdResult<bool> {
-    if signature.len() == 64 && signer_address.len() == 33 {
-        return Ok(api.secp256k1_verify(hash, &signature, &signer_address)?);
-    }
-    Ok(false)
+fn required_threshold(state: &State) -> i128 {
+    state.threshold.required(state.validators_count)
 }
 
-fn required_threshold(validators_count: u128) -> i128 {
-    ((validators_count * 2) / 3) as i128 + 1
+fn ensure_threshold(got: usize, need: i128) -> Result<(), ContractError> {
+    if (got as i128) < need {
+        return Err(ContractError::ThresholdNotReached {
+            got: got as u64,
+            need: need as u64,
+        });
+    }
+    Ok(())
 }
 
 fn add_validator_to_state(
@@ -290,69 +760,598 @@ fn add_validator_to_state(
     Ok(Response::new().add_attributes(log)) // Indicate successful completion of the function
 }
 
-fn claim_validator_rewards(deps: DepsMut, data: ClaimValidatorRewardsMsg) -> StdResult<Response> {
-    let rewards_option = VALIDATORS_STORAGE.may_load(deps.storage, data.validator.clone().0)?;
-    let res;
-    match rewards_option {
-        Some(mut v) => {
-            let coins_to_send: Vec<Coin> = vec![Coin {
-                denom: "uscrt".to_string(),
-                amount: Uint128::from(v.pending_reward),
-            }];
+/// Pays part or all of a validator's rewards in one denom to the payout
+/// address the validator signed for
+fn claim_validator_rewards(
+    deps: DepsMut,
+    env: Env,
+    data: ClaimValidatorRewardsMsg,
+) -> Result<Response, ContractError> {
+    let validator = VALIDATORS_STORAGE
+        .may_load(deps.storage, data.validator.to_vec())?
+        .ok_or(ContractError::UnknownValidator {})?;
+    let withdrawal = data.withdrawal;
+
+    let nonce = REWARD_NONCES
+        .may_load(deps.storage, data.validator.to_vec())?
+        .unwrap_or_default();
+    if withdrawal.nonce != nonce {
+        return Err(ContractError::InvalidRewardNonce {
+            expected: nonce,
+            found: withdrawal.nonce,
+        });
+    }
 
-            let message = CosmosMsg::<Empty>::Bank(BankMsg::Send {
-                // replace with recipient of your choice
-                to_address: v.address.clone().into_string(),
-                amount: coins_to_send,
-            });
+    let state = CONFIG.load(deps.storage)?;
+    let hash = governance_hash(&withdrawal, &env, &state)?;
+    if !verify_signatures(deps.api, &data.signature, &data.validator, &hash)? {
+        return Err(ContractError::Unauthorized {});
+    }
+    let to = deps.api.addr_validate(&withdrawal.payout_address)?;
+
+    let key = (data.validator.to_vec(), withdrawal.denom.clone());
+    let pending = VALIDATOR_REWARDS
+        .may_load(deps.storage, key.clone())?
+        .unwrap_or_default();
+    let amount = withdrawal.amount.unwrap_or(pending);
+    if amount.is_zero() {
+        return Err(ContractError::NothingToWithdraw {});
+    }
+    if amount > pending {
+        return Err(ContractError::InsufficientRewards {
+            pending,
+            requested: amount,
+        });
+    }
+    if amount == pending {
+        VALIDATOR_REWARDS.remove(deps.storage, key);
+    } else {
+        VALIDATOR_REWARDS.save(deps.storage, key, &(pending - amount))?;
+    }
+    REWARD_NONCES.save(deps.storage, data.validator.to_vec(), &(nonce + 1))?;
 
-            v.pending_reward = 0;
+    let coin = Coin {
+        denom: withdrawal.denom,
+        amount,
+    };
+    let message = CosmosMsg::<Empty>::Bank(BankMsg::Send {
+        to_address: to.to_string(),
+        amount: vec![coin.clone()],
+    });
+    let log: Vec<Attribute> =
+        vec![RewardValidatorEventInfo::new(validator.address, to, coin).try_into()?];
 
-            let _ = VALIDATORS_STORAGE.save(deps.storage, data.validator.0.clone(), &v);
+    Ok(Response::new().add_message(message).add_attributes(log))
+}
 
-            let log: Vec<Attribute> = vec![RewardValidatorEventInfo::new(v.address).try_into()?];
+#[allow(clippy::too_many_arguments)]
+fn check_storage_721(
+    deps: DepsMut,
+    self_chain: String,
+    storage_mapping_721: &Map<'static, (String, String), Addr>,
+    source_nft_contract_address: Addr,
+    original: (String, String),
+    token_id: String,
+    custody: Custody721,
+    collection_code_id: u64,
+    owner: Addr,
+    is_original: bool,
+) -> StdResult<Response> {
+    let storage_address_option = storage_mapping_721.may_load(
+        deps.storage,
+        (
+            source_nft_contract_address.clone().into_string(),
+            self_chain.clone(),
+        ),
+    )?;
+
+    match storage_address_option {
+        Some(v) => transfer_to_storage_721(
+            deps.storage,
+            v,
+            source_nft_contract_address.clone(),
+            token_id,
+            custody,
+        ),
+        // an earlier item of the same batch is already deploying the storage
+        None if PENDING_STORAGE_LOCKS_721
+            .has(deps.storage, source_nft_contract_address.to_string()) =>
+        {
+            PENDING_STORAGE_LOCKS_721.update(
+                deps.storage,
+                source_nft_contract_address.into_string(),
+                |pending| -> StdResult<_> {
+                    let mut pending = pending.unwrap_or_default();
+                    pending.push((token_id, custody));
+                    Ok(pending)
+                },
+            )?;
+            Ok(Response::new())
+        }
+        None => {
+            PENDING_STORAGE_LOCKS_721.save(
+                deps.storage,
+                source_nft_contract_address.to_string(),
+                &vec![(token_id.clone(), custody)],
+            )?;
+            let create_storage_msg = StoreFactoryExecuteMsg::CreateStorage721 {
+                label: source_nft_contract_address.clone().into_string(),
+                collection_address: source_nft_contract_address.clone(),
+                source_chain: original.0,
+                source_nft_contract_address: original.1,
+                collection_code_id,
+                owner: owner.into_string(),
+                is_original,
+                token_id,
+            };
 
-            res = Response::new().add_message(message).add_attributes(log);
+            let init_submsg = SubMsg::reply_always(
+                CosmosMsg::Wasm(WasmMsg::Execute {
+                    contract_addr: CONFIG.load(deps.storage)?.storage_deployer.to_string(),
+                    msg: to_json_binary(&create_storage_msg)?,
+                    funds: vec![],
+                }),
+                STORAGE_DEPLOYER_721_REPLY_ID,
+            );
+            Ok(Response::new().add_submessage(init_submsg))
         }
-        None => todo!(),
     }
+}
+
+fn transfer_to_storage_721(
+    storage: &mut dyn Storage,
+    storage_address: Addr,
+    source_nft_contract_address: Addr,
+    token_id: String,
+    custody: Custody721,
+) -> StdResult<Response> {
+    let transfer_msg = NftExecuteMsg::TransferNft {
+        recipient: storage_address.clone().into_string(),
+        token_id: token_id.to_string(),
+    };
+
+    let transfer_submsg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: source_nft_contract_address.clone().into_string(),
+        msg: to_json_binary(&transfer_msg)?,
+        funds: vec![],
+    });
+
+    let _ = NFT_COLLECTION_OWNER.save(
+        storage,
+        (
+            source_nft_contract_address.clone().into_string(),
+            token_id.to_string(),
+        ),
+        &(storage_address.clone(), 1u128),
+    );
+
+    let record_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: storage_address.into_string(),
+        msg: to_json_binary(&NftStoreExecuteMsg::RecordCustody {
+            token_id,
+            locker: custody.locker,
+            destination_chain: custody.destination_chain,
+            destination_user_address: custody.destination_user_address,
+        })?,
+        funds: vec![],
+    });
+
+    Ok(Response::new()
+        .add_message(transfer_submsg)
+        .add_message(record_msg))
+}
+
+fn lock721(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: Lock721Msg,
+) -> Result<Response, ContractError> {
+    let state = CONFIG.load(deps.storage)?;
+    let lock_fee = check_lock_721(deps.as_ref(), &state, &msg)?;
+    has_correct_fee(lock_fee, &state.fee_denom, &info)?;
+    let owner = token_owner_721(deps.as_ref(), &msg, &info.sender)?;
+    lock721_impl(deps, &env, &state, &info.sender, &owner, msg)
+}
+
+fn batch_lock721(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    items: Vec<Lock721Msg>,
+) -> Result<Response, ContractError> {
+    let state = CONFIG.load(deps.storage)?;
+    ensure_batch_size(&state, items.len())?;
 
+    let mut lock_fee = Uint128::zero();
+    for item in &items {
+        lock_fee = lock_fee
+            .checked_add(check_lock_721(deps.as_ref(), &state, item)?.into())
+            .map_err(StdError::from)?;
+    }
+    has_correct_fee(lock_fee.u128(), &state.fee_denom, &info)?;
+
+    let mut res = Response::new();
+    for item in items {
+        let owner = token_owner_721(deps.as_ref(), &item, &info.sender)?;
+        res = merge_responses(
+            res,
+            lock721_impl(deps.branch(), &env, &state, &info.sender, &owner, item)?,
+        );
+    }
     Ok(res)
 }
 
-fn check_storage_721(
+fn ensure_batch_size(state: &State, size: usize) -> Result<(), ContractError> {
+    if size == 0 {
+        return Err(ContractError::EmptyBatch {});
+    }
+    if size > state.max_batch_size as usize {
+        return Err(ContractError::BatchTooLarge {
+            size,
+            max: state.max_batch_size,
+        });
+    }
+    Ok(())
+}
+
+fn merge_responses(res: Response, other: Response) -> Response {
+    res.add_submessages(other.messages)
+        .add_attributes(other.attributes)
+        .add_events(other.events)
+}
+
+/// Checks that `msg` may be locked and returns its lock fee
+fn check_lock_721(deps: Deps, state: &State, msg: &Lock721Msg) -> Result<u128, ContractError> {
+    ensure_locks_allowed(&state.pause, &msg.destination_chain)?;
+    let lock_fee = ensure_supported_destination(
+        deps.storage,
+        &msg.destination_chain,
+        &msg.destination_user_address,
+    )?;
+    if deps
+        .api
+        .addr_validate(&msg.source_nft_contract_address)
+        .is_err()
+    {
+        return Err(ContractError::InvalidSourceNftContract {
+            address: msg.source_nft_contract_address.clone(),
+        });
+    }
+    Ok(lock_fee)
+}
+
+/// Returns the owner of the token `sender` wants to lock. The bridge may be
+/// approved for tokens of other owners, so only the owner, or a spender or
+/// operator it approved, may lock the token.
+fn token_owner_721(deps: Deps, msg: &Lock721Msg, sender: &Addr) -> Result<Addr, ContractError> {
+    let collection = &msg.source_nft_contract_address;
+    let owner: OwnerOfResponse = deps.querier.query_wasm_smart(
+        collection,
+        &Cw721QueryMsg::OwnerOf {
+            token_id: msg.token_id.clone(),
+            include_expired: None,
+        },
+    )?;
+    let approved = owner.owner == sender.as_str()
+        || owner.approvals.iter().any(|a| a.spender == sender.as_str())
+        || deps
+            .querier
+            .query_wasm_smart::<OperatorResponse>(
+                collection,
+                &Cw721QueryMsg::Operator {
+                    owner: owner.owner.clone(),
+                    operator: sender.to_string(),
+                    include_expired: None,
+                },
+            )
+            .is_ok();
+    if !approved {
+        return Err(ContractError::NotTokenOwner {
+            sender: sender.to_string(),
+            token_id: msg.token_id.clone(),
+        });
+    }
+    Ok(deps.api.addr_validate(&owner.owner)?)
+}
+
+/// `from` is who the token is taken from, it must be allowed to send it.
+/// `locker` owned the token before the lock, emergency returns go back to it.
+fn lock721_impl(
+    deps: DepsMut,
+    env: &Env,
+    state: &State,
+    from: &Addr,
+    locker: &Addr,
+    msg: Lock721Msg,
+) -> Result<Response, ContractError> {
+    let self_chain = state.self_chain.clone();
+    let custody = Custody721 {
+        locker: locker.clone(),
+        destination_chain: msg.destination_chain.clone(),
+        destination_user_address: msg.destination_user_address.clone(),
+    };
+
+    let original_collection_address_option = DUPLICATE_TO_ORIGINAL_STORAGE.may_load(
+        deps.storage,
+        (
+            Addr::unchecked(msg.source_nft_contract_address.clone()),
+            self_chain.clone(),
+        ),
+    )?;
+
+    match original_collection_address_option {
+        Some(_v) => {
+            // notOriginal
+            let original = (_v.chain.clone(), _v.contract_address.clone());
+            let log: Vec<Attribute> = vec![LockedEventInfo::new(
+                msg.token_id.clone(),
+                msg.destination_chain,
+                msg.destination_user_address,
+                _v.contract_address,
+                1,
+                state.type_erc_721.clone(),
+                _v.chain,
+            )
+            .try_into()?];
+
+            let collection = Addr::unchecked(msg.source_nft_contract_address.clone());
+            let res = if BURN_ON_RETURN_721.has(deps.storage, collection.clone()) {
+                burn_from_721(&collection, from, msg.token_id)?
+            } else {
+                check_storage_721(
+                    deps,
+                    self_chain.clone(),
+                    &DUPLICATE_STORAGE_721,
+                    collection,
+                    original,
+                    msg.token_id,
+                    custody,
+                    msg.collection_code_id,
+                    env.contract.address.clone(),
+                    false,
+                )?
+            };
+
+            Ok(res.add_attributes(log))
+        }
+        None => {
+            // isOriginal
+
+            let log: Vec<Attribute> = vec![LockedEventInfo::new(
+                msg.token_id.clone(),
+                msg.destination_chain.clone(),
+                msg.destination_user_address,
+                msg.source_nft_contract_address.to_string(),
+                1,
+                state.type_erc_721.clone(),
+                self_chain.clone(),
+            )
+            .try_into()?];
+
+            let res = check_storage_721(
+                deps,
+                self_chain.clone(),
+                &ORIGINAL_STORAGE_721,
+                Addr::unchecked(msg.source_nft_contract_address.clone()),
+                (self_chain, msg.source_nft_contract_address),
+                msg.token_id,
+                custody,
+                msg.collection_code_id,
+                env.contract.address.clone(),
+                true,
+            )?;
+
+            Ok(res.add_attributes(log))
+        }
+    }
+}
+
+/// Locks a token sent with cw721 `SendNft`. The bridge already owns the token
+/// at this point, so it is moved into the storage without a prior approval.
+fn receive_nft(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    receive: Cw721ReceiveMsg,
+) -> Result<Response, ContractError> {
+    let msg: Lock721Msg = from_json(&receive.msg)?;
+    // the hook is called by the collection itself, which vouches for the token
+    if msg.source_nft_contract_address != info.sender.as_str() || msg.token_id != receive.token_id {
+        return Err(ContractError::ReceivedNftMismatch {
+            sender: info.sender.into_string(),
+            token_id: receive.token_id,
+        });
+    }
+
+    let state = CONFIG.load(deps.storage)?;
+    let lock_fee = check_lock_721(deps.as_ref(), &state, &msg)?;
+    let locker = deps.api.addr_validate(&receive.sender)?;
+    // the collection sends no funds along, so the fee is prepaid by the locker
+    charge_prepaid_lock_fee(deps.storage, &locker, &state.fee_denom, lock_fee)?;
+    // the bridge holds the token by now
+    let from = env.contract.address.clone();
+    lock721_impl(deps, &env, &state, &from, &locker, msg)
+}
+
+fn charge_prepaid_lock_fee(
+    storage: &mut dyn Storage,
+    locker: &Addr,
+    fee_denom: &str,
+    fee: u128,
+) -> Result<(), ContractError> {
+    let key = (locker.clone(), fee_denom.to_string());
+    let prepaid = PREPAID_LOCK_FEES
+        .may_load(storage, key.clone())?
+        .unwrap_or_default();
+    let remaining =
+        prepaid
+            .checked_sub(Uint128::from(fee))
+            .map_err(|_| ContractError::FeeTooLow {
+                sent: prepaid,
+                required: Uint128::from(fee),
+            })?;
+    if remaining.is_zero() {
+        PREPAID_LOCK_FEES.remove(storage, key);
+    } else {
+        PREPAID_LOCK_FEES.save(storage, key, &remaining)?;
+    }
+    Ok(())
+}
+
+fn deposit_lock_fees(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    if info.funds.iter().all(|coin| coin.amount.is_zero()) {
+        return Err(ContractError::NoFundsSent {});
+    }
+    for coin in info.funds {
+        PREPAID_LOCK_FEES.update(
+            deps.storage,
+            (info.sender.clone(), coin.denom),
+            |prepaid| -> StdResult<_> { Ok(prepaid.unwrap_or_default() + coin.amount) },
+        )?;
+    }
+    Ok(Response::new())
+}
+
+fn withdraw_lock_fees(deps: DepsMut, info: MessageInfo) -> Result<Response, ContractError> {
+    let amount: Vec<Coin> = PREPAID_LOCK_FEES
+        .prefix(info.sender.clone())
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(denom, amount)| Coin { denom, amount }))
+        .collect::<StdResult<_>>()?;
+    if amount.is_empty() {
+        return Err(ContractError::NothingToWithdraw {});
+    }
+    for coin in &amount {
+        PREPAID_LOCK_FEES.remove(deps.storage, (info.sender.clone(), coin.denom.clone()));
+    }
+    Ok(Response::new().add_message(BankMsg::Send {
+        to_address: info.sender.into_string(),
+        amount,
+    }))
+}
+
+/// Duplicates that burn on return give the token up instead of holding it in a
+/// storage, the claim coming back mints it again
+fn burn_from_721(collection: &Addr, from: &Addr, token_id: String) -> StdResult<Response> {
+    let burn_msg = NftExecuteMsg::Extension {
+        msg: NftExtensionMsg::BurnFrom {
+            from: from.to_string(),
+            token_id,
+        },
+    };
+    Ok(Response::new().add_message(WasmMsg::Execute {
+        contract_addr: collection.to_string(),
+        msg: to_json_binary(&burn_msg)?,
+        funds: vec![],
+    }))
+}
+
+/// Gives a locked 721 token back to its locker, for a lock the validators agree
+/// was never claimed on the destination chain
+fn emergency_return_721(
+    deps: DepsMut,
+    env: Env,
+    msg: EmergencyReturnMsg,
+) -> Result<Response, ContractError> {
+    let state = CONFIG.load(deps.storage)?;
+    let data = msg.data;
+    let collection = data.source_nft_contract_address.clone();
+
+    let storages = if DUPLICATE_TO_ORIGINAL_STORAGE.has(
+        deps.storage,
+        (Addr::unchecked(&collection), state.self_chain.clone()),
+    ) {
+        &DUPLICATE_STORAGE_721
+    } else {
+        &ORIGINAL_STORAGE_721
+    };
+    let not_in_custody = || ContractError::NotInCustody {
+        token_id: data.token_id.clone(),
+    };
+    let storage_address = storages
+        .may_load(deps.storage, (collection.clone(), state.self_chain.clone()))?
+        .ok_or_else(not_in_custody)?;
+    let custody: GetTokenCustodyResponse = deps.querier.query_wasm_smart(
+        &storage_address,
+        &NftStoreQueryMsg::GetTokenCustody {
+            token_id: data.token_id.clone(),
+        },
+    )?;
+    match custody.data {
+        Some(custody) if custody.locker == data.locker && custody.locked_at == data.locked_at => {}
+        Some(_) => {
+            return Err(ContractError::CustodyMismatch {
+                token_id: data.token_id,
+            })
+        }
+        None => return Err(not_in_custody()),
+    }
+
+    let hash = governance_hash(&data, &env, &state)?;
+    let (signers, skipped) = aggregate_signatures(deps.storage, deps.api, &hash, &msg.signatures)?;
+    ensure_threshold(signers.len(), required_threshold(&state))?;
+
+    NFT_COLLECTION_OWNER.remove(deps.storage, (collection.clone(), data.token_id.clone()));
+    let return_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: storage_address.into_string(),
+        msg: to_json_binary(&NftStoreExecuteMsg::EmergencyReturn {
+            token_id: data.token_id.clone(),
+        })?,
+        funds: vec![],
+    });
+
+    let log: Vec<Attribute> =
+        vec![EmergencyReturnedEventInfo::new(data.locker, data.token_id, collection).try_into()?];
+    Ok(Response::new()
+        .add_message(return_msg)
+        .add_attributes(log)
+        .add_attributes(skipped.into_log()?))
+}
+
+#[allow(clippy::too_many_arguments)]
+fn check_storage_1155(
     deps: DepsMut,
     self_chain: String,
-    storage_mapping_721: &Map<'static, (String, String), Addr>,
+    storage_mapping_1155: &Map<'static, (String, String), Addr>,
     source_nft_contract_address: Addr,
+    original: (String, String),
     token_id: String,
+    token_amount: u128,
     collection_code_id: u64,
     owner: Addr,
     is_original: bool,
+    from: Addr,
 ) -> StdResult<Response> {
-    let storage_address_option = storage_mapping_721.may_load(
+    let storage_address_option = storage_mapping_1155.may_load(
         deps.storage,
         (
             source_nft_contract_address.clone().into_string(),
-            self_chain.clone(),
+            self_chain,
         ),
     )?;
 
     match storage_address_option {
-        Some(v) => transfer_to_storage_721(
+        Some(v) => transfer_to_storage_1155(
             deps.storage,
+            from,
             v,
-            source_nft_contract_address.clone(),
+            source_nft_contract_address,
             token_id,
+            token_amount,
         ),
         None => {
-            let create_storage_msg = StoreFactoryExecuteMsg::CreateStorage721 {
+            let create_storage_msg = StoreFactoryExecuteMsg::CreateStorage1155 {
                 label: source_nft_contract_address.clone().into_string(),
-                collection_address: source_nft_contract_address.clone(),
+                collection_address: source_nft_contract_address,
+                source_chain: original.0,
+                source_nft_contract_address: original.1,
                 collection_code_id,
                 owner: owner.into_string(),
                 is_original,
                 token_id,
+                token_amount,
+                from,
             };
 
             let init_submsg = SubMsg::reply_always(
@@ -361,22 +1360,27 @@ fn check_storage_721(
                     msg: to_json_binary(&create_storage_msg)?,
                     funds: vec![],
                 }),
-                STORAGE_DEPLOYER_721_REPLY_ID,
+                STORAGE_DEPLOYER_1155_REPLY_ID,
             );
             Ok(Response::new().add_submessage(init_submsg))
         }
     }
 }
 
-fn transfer_to_storage_721(
+fn transfer_to_storage_1155(
     storage: &mut dyn Storage,
+    from: Addr,
     storage_address: Addr,
     source_nft_contract_address: Addr,
     token_id: String,
+    token_amount: u128,
 ) -> StdResult<Response> {
-    let transfer_msg = NftExecuteMsg::TransferNft {
-        recipient: storage_address.clone().into_string(),
-        token_id: token_id.to_string(),
+    let transfer_msg = Nft1155ExecuteMsg::SendFrom {
+        from: from.into_string(),
+        to: storage_address.clone().into_string(),
+        token_id: token_id.clone(),
+        value: Uint128::from(token_amount),
+        msg: None,
     };
 
     let transfer_submsg = CosmosMsg::Wasm(WasmMsg::Execute {
@@ -385,90 +1389,116 @@ fn transfer_to_storage_721(
         funds: vec![],
     });
 
-    let _ = NFT_COLLECTION_OWNER.save(
+    // editions of the same token id accumulate in the storage
+    NFT_COLLECTION_OWNER.update(
         storage,
-        (
-            source_nft_contract_address.clone().into_string(),
-            token_id.to_string(),
-        ),
-        &(storage_address, 1u128),
-    );
+        (source_nft_contract_address.into_string(), token_id),
+        |locked| -> StdResult<_> {
+            let held = locked.map(|(_, amount)| amount).unwrap_or_default();
+            let total = Uint128::from(held).checked_add(Uint128::from(token_amount))?;
+            Ok((storage_address, total.u128()))
+        },
+    )?;
 
     Ok(Response::new().add_message(transfer_submsg))
 }
 
-fn lock721(deps: DepsMut, env: Env, msg: Lock721Msg) -> StdResult<Response> {
-    let addr_result = deps
+fn lock1155(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: Lock1155Msg,
+) -> Result<Response, ContractError> {
+    let state = CONFIG.load(deps.storage)?;
+    ensure_locks_allowed(&state.pause, &msg.destination_chain)?;
+    let lock_fee = ensure_supported_destination(
+        deps.storage,
+        &msg.destination_chain,
+        &msg.destination_user_address,
+    )?;
+    has_correct_fee(lock_fee, &state.fee_denom, &info)?;
+
+    if deps
         .api
-        .addr_validate(&msg.source_nft_contract_address.clone());
-    let self_chain = CONFIG.load(deps.storage)?.self_chain.clone();
-    match addr_result {
-        Ok(_v) => {}
-        Err(_) => {
-            return Err(StdError::generic_err(
-                "sourceNftContractAddress cannot be zero address",
-            ));
-        }
+        .addr_validate(msg.source_nft_contract_address.as_str())
+        .is_err()
+    {
+        return Err(ContractError::InvalidSourceNftContract {
+            address: msg.source_nft_contract_address.to_string(),
+        });
+    }
+
+    if msg.token_amount == 0 {
+        return Err(ContractError::InvalidTokenAmount {});
     }
 
     let original_collection_address_option = DUPLICATE_TO_ORIGINAL_STORAGE.may_load(
         deps.storage,
         (
-            Addr::unchecked(msg.source_nft_contract_address.clone()),
-            self_chain.clone(),
+            msg.source_nft_contract_address.clone(),
+            state.self_chain.clone(),
         ),
     )?;
 
     match original_collection_address_option {
-        Some(_v) => {
+        Some(v) => {
             // notOriginal
+            let original = (v.chain.clone(), v.contract_address.clone());
             let log: Vec<Attribute> = vec![LockedEventInfo::new(
                 msg.token_id.clone(),
                 msg.destination_chain,
                 msg.destination_user_address,
-                _v.contract_address,
-                1,
-                CONFIG.load(deps.storage)?.type_erc_721,
-                _v.chain,
+                v.contract_address,
+                msg.token_amount,
+                state.type_erc_1155,
+                v.chain,
             )
             .try_into()?];
 
-            let res = check_storage_721(
+            let res = check_storage_1155(
                 deps,
-                self_chain.clone(),
-                &DUPLICATE_STORAGE_721,
-                Addr::unchecked(msg.source_nft_contract_address.clone()),
+                state.self_chain,
+                &DUPLICATE_STORAGE_1155,
+                msg.source_nft_contract_address,
+                original,
                 msg.token_id,
+                msg.token_amount,
                 msg.collection_code_id,
                 env.contract.address,
                 false,
+                info.sender,
             )?;
 
             Ok(res.add_attributes(log))
         }
         None => {
             // isOriginal
-
             let log: Vec<Attribute> = vec![LockedEventInfo::new(
                 msg.token_id.clone(),
-                msg.destination_chain.clone(),
+                msg.destination_chain,
                 msg.destination_user_address,
                 msg.source_nft_contract_address.to_string(),
-                1,
-                CONFIG.load(deps.storage)?.type_erc_721,
-                CONFIG.load(deps.storage)?.self_chain,
+                msg.token_amount,
+                state.type_erc_1155,
+                state.self_chain.clone(),
             )
             .try_into()?];
 
-            let res = check_storage_721(
+            let res = check_storage_1155(
                 deps,
-                self_chain,
-                &ORIGINAL_STORAGE_721,
-                Addr::unchecked(msg.source_nft_contract_address.clone()),
+                state.self_chain.clone(),
+                &ORIGINAL_STORAGE_1155,
+                msg.source_nft_contract_address.clone(),
+                (
+                    state.self_chain,
+                    msg.source_nft_contract_address.into_string(),
+                ),
                 msg.token_id,
+                msg.token_amount,
                 msg.collection_code_id,
                 env.contract.address,
                 true,
+                info.sender,
             )?;
 
             Ok(res.add_attributes(log))
@@ -476,12 +1506,74 @@ fn lock721(deps: DepsMut, env: Env, msg: Lock721Msg) -> StdResult<Response> {
     }
 }
 
-fn create_claim_data_hash(data: ClaimData) -> [u8; 32] {
+/// sha256 of the [GovernanceMessage::governance_encoding] of `msg` for this bridge
+fn governance_hash<T: GovernanceMessage>(msg: &T, env: &Env, state: &State) -> StdResult<[u8; 32]> {
+    let mut hasher = Sha256::new();
+    hasher.update(msg.governance_encoding(env.contract.address.as_str(), &state.self_chain)?);
+    Ok(hasher.finalize().into())
+}
+
+fn create_claim_data_hash(data: &ClaimData, bridge: &Addr, self_chain: &str) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(data.canonical_encoding(bridge.as_str(), self_chain));
+    hasher.finalize().into()
+}
+
+fn create_legacy_claim_data_hash(data: &ClaimData) -> [u8; 32] {
     let serialized = data.concat_all_fields();
     let mut hasher = Sha256::new();
     hasher.update(serialized);
-    let output = hasher.finalize().into();
-    output
+    hasher.finalize().into()
+}
+
+fn accepts_legacy_claim_hash(state: &State, env: &Env) -> bool {
+    state
+        .legacy_claim_hash_until
+        .is_some_and(|until| env.block.height <= until)
+}
+
+/// Checks the signatures over the canonical hash and marks the claim processed.
+/// While the transition window is open the legacy hash is accepted as well,
+/// and marked too so the claim can't be replayed through either encoding.
+/// Returns the canonical hash.
+fn verify_claim(
+    storage: &mut dyn Storage,
+    api: &dyn Api,
+    env: &Env,
+    data: &ClaimData,
+    signatures: &[SignerAndSignature],
+) -> Result<([u8; 32], Vec<Binary>, SkippedSignersEventInfo), ContractError> {
+    let state = CONFIG.load(storage)?;
+    let hash = create_claim_data_hash(data, &env.contract.address, &state.self_chain);
+    // the legacy concatenation is ambiguous, outside the window it must not
+    // block claims that merely collide with an earlier one
+    let legacy_hash =
+        accepts_legacy_claim_hash(&state, env).then(|| create_legacy_claim_data_hash(data));
+
+    if UNIQUE_IDENTIFIER_STORAGE.has(storage, hash)
+        || legacy_hash.is_some_and(|legacy| UNIQUE_IDENTIFIER_STORAGE.has(storage, legacy))
+    {
+        return Err(ContractError::AlreadyProcessed {
+            hash: Binary::from(hash),
+        });
+    }
+    UNIQUE_IDENTIFIER_STORAGE.save(storage, hash, &true)?;
+    if let Some(legacy) = legacy_hash {
+        UNIQUE_IDENTIFIER_STORAGE.save(storage, legacy, &true)?;
+    }
+
+    let threshold = required_threshold(&state);
+    match (
+        validate_signature(storage, api, hash, signatures, threshold),
+        legacy_hash,
+    ) {
+        (Err(_), Some(legacy)) => {
+            let (signers, skipped) =
+                validate_signature(storage, api, legacy, signatures, threshold)?;
+            Ok((hash, signers, skipped))
+        }
+        (result, _) => result.map(|(signers, skipped)| (hash, signers, skipped)),
+    }
 }
 
 fn verify_signatures(
@@ -491,62 +1583,128 @@ fn verify_signatures(
     hash: &[u8; 32],
 ) -> StdResult<bool> {
     if signature.len() == 64 && signer_address.len() == 33 {
-        return Ok(api.secp256k1_verify(hash, &signature, &signer_address)?);
+        return Ok(api.secp256k1_verify(hash, signature, signer_address)?);
     }
     Ok(false)
 }
 
 fn validate_signature(
+    storage: &dyn Storage,
     api: &dyn Api,
     hash: [u8; 32],
-    signatures: Vec<SignerAndSignature>,
-    validators_count: u128,
-) -> StdResult<Vec<Binary>> {
-    let mut percentage = 0;
-    let mut arr: Vec<Binary> = Vec::new();
-    for ele in signatures {
-        if verify_signatures(api, &ele.signature, &ele.signer_address, &hash)? {
-            percentage += 1;
-            arr.push(ele.signer_address);
-        }
-    }
-
-    if percentage < required_threshold(validators_count) {
-        return Err(StdError::generic_err("Threshold not reached!"));
-    }
-    Ok(arr)
+    signatures: &[SignerAndSignature],
+    threshold: i128,
+) -> Result<(Vec<Binary>, SkippedSignersEventInfo), ContractError> {
+    let (signers, skipped) = aggregate_signatures(storage, api, &hash, signatures)?;
+    ensure_threshold(signers.len(), threshold)?;
+    Ok((signers, skipped))
 }
 
+/// Splits `fee` and the dust left from earlier splits in `denom` evenly
+/// between the signers. What can't be split evenly is kept as dust.
 fn reward_validators(
     storage: &mut dyn Storage,
+    denom: &str,
     fee: u128,
     validators_to_reward: Vec<Binary>,
     balance: u128,
-) -> StdResult<()> {
-    if fee <= 0 {
-        return Err(StdError::generic_err("Invalid fees"));
+) -> Result<(), ContractError> {
+    if fee == 0 {
+        return Err(ContractError::InvalidFee {});
     }
 
     if balance < fee {
-        return Err(StdError::generic_err("No rewards available"));
+        return Err(ContractError::InsufficientRewardBalance {
+            balance: Uint128::from(balance),
+            fee: Uint128::from(fee),
+        });
     }
 
-    let fee_per_validator = fee / validators_to_reward.len() as u128;
+    let dust = REWARD_DUST
+        .may_load(storage, denom.to_string())?
+        .unwrap_or_default();
+    let pool = Uint128::from(fee) + dust;
+    let signers = Uint128::from(validators_to_reward.len() as u128);
+    let fee_per_validator = pool
+        .checked_div(signers)
+        .map_err(StdError::divide_by_zero)?;
 
     for val in validators_to_reward {
-        let validator_option = VALIDATORS_STORAGE.may_load(storage, val.0.clone())?;
-        match validator_option {
-            Some(mut v) => {
-                v.pending_reward = v.pending_reward + fee_per_validator;
+        add_reward(storage, val.0, denom, fee_per_validator)?;
+    }
+    REWARD_DUST.save(
+        storage,
+        denom.to_string(),
+        &(pool - fee_per_validator * signers),
+    )?;
+    Ok(())
+}
 
-                let _ = VALIDATORS_STORAGE.save(storage, val.0, &v);
-            }
-            None => todo!(),
-        }
+fn add_reward(
+    storage: &mut dyn Storage,
+    public_key: Vec<u8>,
+    denom: &str,
+    amount: Uint128,
+) -> StdResult<()> {
+    if amount.is_zero() {
+        return Ok(());
+    }
+    VALIDATOR_REWARDS.update(
+        storage,
+        (public_key, denom.to_string()),
+        |pending| -> StdResult<_> { Ok(pending.unwrap_or_default().checked_add(amount)?) },
+    )?;
+    Ok(())
+}
+
+fn save_claim_record(
+    storage: &mut dyn Storage,
+    env: &Env,
+    hash: [u8; 32],
+    data: &ClaimData,
+    contract: Option<Addr>,
+) -> StdResult<()> {
+    CLAIM_RECORDS.save(
+        storage,
+        claim_record_key(data),
+        &ClaimRecord {
+            lock_tx_chain: data.lock_tx_chain.clone(),
+            transaction_hash: data.transaction_hash.clone(),
+            source_nft_contract_address: data.source_nft_contract_address.clone(),
+            claim_hash: Binary::from(hash),
+            height: env.block.height,
+            recipient: data.destination_user_address.clone(),
+            contract,
+            token_id: data.token_id.clone(),
+        },
+    )
+}
+
+fn claim_record_key(data: &ClaimData) -> ClaimRecordKey {
+    (
+        data.lock_tx_chain.clone(),
+        data.transaction_hash.clone(),
+        (
+            data.source_nft_contract_address.clone(),
+            data.token_id.clone(),
+        ),
+    )
+}
+
+/// Fills in the collection of a claim that had to deploy it first
+fn set_claimed_contract(
+    storage: &mut dyn Storage,
+    key: ClaimRecordKey,
+    contract: &Addr,
+) -> StdResult<()> {
+    if let Some(mut record) = CLAIM_RECORDS.may_load(storage, key.clone())? {
+        record.contract = Some(contract.clone());
+        CLAIM_RECORDS.save(storage, key, &record)?;
     }
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn deploy_collection_721(
     deps: DepsMut,
     name: String,
@@ -562,6 +1720,7 @@ fn deploy_collection_721(
     metadata: String,
     transaction_hash: String,
     lock_tx_chain: String,
+    burn_on_return: bool,
 ) -> StdResult<Response> {
     let create_collection_msg = CollectionDeployerExecuteMsg::CreateCollection721 {
         owner,
@@ -577,6 +1736,7 @@ fn deploy_collection_721(
         metadata,
         transaction_hash,
         lock_tx_chain,
+        burn_on_return,
     };
 
     let init_wasm_msg = CosmosMsg::Wasm(WasmMsg::Execute {
@@ -589,49 +1749,90 @@ fn deploy_collection_721(
     Ok(Response::new().add_submessage(init_submsg))
 }
 
-fn claim721(deps: DepsMut, env: Env, info: MessageInfo, msg: ClaimMsg) -> StdResult<Response> {
-    let balance = deps
+fn claim721(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ClaimMsg,
+) -> Result<Response, ContractError> {
+    let state = CONFIG.load(deps.storage)?;
+    ensure_claims_allowed(&state.pause, &msg.data)?;
+    has_correct_fee(msg.data.fee, &state.fee_denom, &info)?;
+
+    let mut balance = deps
         .querier
-        .query_balance(env.contract.address.clone(), "uscrt".to_string())?
-        .amount;
+        .query_balance(env.contract.address.clone(), state.fee_denom.clone())?
+        .amount
+        .u128();
+    claim721_impl(deps, &env, &state, &mut balance, msg)
+}
 
-    let self_chain = CONFIG.load(deps.storage)?.self_chain;
+fn batch_claim721(
+    mut deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    claims: Vec<ClaimMsg>,
+) -> Result<Response, ContractError> {
+    let state = CONFIG.load(deps.storage)?;
+    ensure_batch_size(&state, claims.len())?;
 
-    let _ = has_correct_fee(msg.data.fee.clone(), info);
+    let mut fee = Uint128::zero();
+    for claim in &claims {
+        fee = fee
+            .checked_add(claim.data.fee.into())
+            .map_err(StdError::from)?;
+    }
+    has_correct_fee(fee.u128(), &state.fee_denom, &info)?;
 
-    let type_erc_721 = CONFIG.load(deps.storage)?.type_erc_721;
+    let mut balance = deps
+        .querier
+        .query_balance(env.contract.address.clone(), state.fee_denom.clone())?
+        .amount
+        .u128();
+    let mut res = Response::new();
+    for claim in claims {
+        res = merge_responses(
+            res,
+            claim721_impl(deps.branch(), &env, &state, &mut balance, claim)?,
+        );
+    }
+    Ok(res)
+}
 
-    let validators_count = CONFIG.load(deps.storage)?.validators_count;
+/// `balance` is what is left of the bridge balance to reward validators from
+fn claim721_impl(
+    deps: DepsMut,
+    env: &Env,
+    state: &State,
+    balance: &mut u128,
+    msg: ClaimMsg,
+) -> Result<Response, ContractError> {
+    ensure_claims_allowed(&state.pause, &msg.data)?;
 
-    let _ = matches_current_chain(deps.storage, msg.data.destination_chain.clone());
+    let self_chain = state.self_chain.clone();
 
-    if msg.data.nft_type != type_erc_721 {
-        return Err(StdError::generic_err("Invalid NFT type!"));
+    if msg.data.destination_chain != self_chain {
+        return Err(ContractError::InvalidDestinationChain {});
     }
 
-    let hash = create_claim_data_hash(msg.data.clone());
-
-    let exists = UNIQUE_IDENTIFIER_STORAGE.may_load(deps.storage, hash)?;
-    let _ = match exists {
-        Some(_v) => {
-            return Err(StdError::generic_err("Data already processed!"));
-        }
-        None => UNIQUE_IDENTIFIER_STORAGE.save(deps.storage, hash, &true),
-    };
+    if msg.data.nft_type != state.type_erc_721 {
+        return Err(ContractError::InvalidNftType {
+            expected: state.type_erc_721.clone(),
+            found: msg.data.nft_type,
+        });
+    }
 
-    let validators_to_reward = validate_signature(
-        deps.api,
-        hash,
-        msg.signatures,
-        validators_count.try_into().unwrap(),
-    )?;
+    let (hash, validators_to_reward, skipped) =
+        verify_claim(deps.storage, deps.api, env, &msg.data, &msg.signatures)?;
 
-    let _ = reward_validators(
+    reward_validators(
         deps.storage,
-        msg.data.fee.clone(),
+        &state.fee_denom,
+        msg.data.fee,
         validators_to_reward,
-        balance.into(),
-    );
+        *balance,
+    )?;
+    *balance -= msg.data.fee;
 
     let duplicate_collection_address_option = ORIGINAL_TO_DUPLICATE_STORAGE.may_load(
         deps.storage,
@@ -679,6 +1880,17 @@ fn claim721(deps: DepsMut, env: Env, info: MessageInfo, msg: ClaimMsg) -> StdRes
             has_storage = false;
         }
     }
+
+    let claimed_contract = if has_duplicate {
+        Some(duplicate_collection_address.contract_address.clone())
+    } else if has_storage {
+        Some(Addr::unchecked(
+            msg.data.source_nft_contract_address.clone(),
+        ))
+    } else {
+        None
+    };
+    save_claim_record(deps.storage, env, hash, &msg.data, claimed_contract)?;
     let res = if has_duplicate && has_storage {
         let is_storage_is_nft_owner_option = NFT_COLLECTION_OWNER.may_load(
             deps.storage,
@@ -722,7 +1934,7 @@ fn claim721(deps: DepsMut, env: Env, info: MessageInfo, msg: ClaimMsg) -> StdRes
                     .try_into()?,
                 ];
 
-                let _ = NFT_COLLECTION_OWNER.remove(
+                NFT_COLLECTION_OWNER.remove(
                     deps.storage,
                     (
                         duplicate_collection_address.contract_address.into_string(),
@@ -738,10 +1950,7 @@ fn claim721(deps: DepsMut, env: Env, info: MessageInfo, msg: ClaimMsg) -> StdRes
                     owner: msg.data.destination_user_address.into_string(),
 
                     token_uri: Some(msg.data.metadata),
-                    extension: RoyaltyData {
-                        royalty_percentage: msg.data.royalty as u64,
-                        royalty_payment_address: msg.data.royalty_receiver.into_string(),
-                    },
+                    extension: claim_royalty(msg.data.royalty, &msg.data.royalty_receiver),
                 };
 
                 let message = CosmosMsg::Wasm(WasmMsg::Execute {
@@ -769,10 +1978,7 @@ fn claim721(deps: DepsMut, env: Env, info: MessageInfo, msg: ClaimMsg) -> StdRes
             token_id: msg.data.token_id.to_string(),
             owner: msg.data.destination_user_address.into_string(),
             token_uri: Some(msg.data.metadata),
-            extension: RoyaltyData {
-                royalty_percentage: msg.data.royalty as u64,
-                royalty_payment_address: msg.data.royalty_receiver.into_string(),
-            },
+            extension: claim_royalty(msg.data.royalty, &msg.data.royalty_receiver),
         };
 
         let message = CosmosMsg::Wasm(WasmMsg::Execute {
@@ -794,11 +2000,29 @@ fn claim721(deps: DepsMut, env: Env, info: MessageInfo, msg: ClaimMsg) -> StdRes
 
         Ok(Response::new().add_message(message).add_attributes(log))
     } else if !has_duplicate && !has_storage {
+        let source = (
+            msg.data.source_nft_contract_address.clone(),
+            msg.data.source_chain.clone(),
+        );
+        // an earlier item of the same batch is already deploying the collection
+        if PENDING_COLLECTION_CLAIMS_721.has(deps.storage, source.clone()) {
+            PENDING_COLLECTION_CLAIMS_721.update(
+                deps.storage,
+                source,
+                |pending| -> StdResult<_> {
+                    let mut pending = pending.unwrap_or_default();
+                    pending.push(msg.data);
+                    Ok(pending)
+                },
+            )?;
+            return Ok(Response::new().add_attributes(skipped.into_log()?));
+        }
+        PENDING_COLLECTION_CLAIMS_721.save(deps.storage, source, &vec![])?;
         deploy_collection_721(
             deps,
             msg.data.name,
             msg.data.symbol,
-            env.contract.address.into_string(),
+            env.contract.address.to_string(),
             msg.data.source_nft_contract_address,
             msg.data.source_chain.clone(),
             msg.data.destination_user_address,
@@ -809,10 +2033,11 @@ fn claim721(deps: DepsMut, env: Env, info: MessageInfo, msg: ClaimMsg) -> StdRes
             msg.data.metadata,
             msg.data.transaction_hash,
             msg.data.lock_tx_chain,
+            state.burn_on_return,
         )
     }
     // ===============================/ NOT hasDuplicate && hasStorage /=======================
-    else if !has_duplicate && has_storage {
+    else {
         let is_storage_is_nft_owner_option = NFT_COLLECTION_OWNER.may_load(
             deps.storage,
             (
@@ -852,7 +2077,7 @@ fn claim721(deps: DepsMut, env: Env, info: MessageInfo, msg: ClaimMsg) -> StdRes
                     .try_into()?,
                 ];
 
-                let _ = NFT_COLLECTION_OWNER.remove(
+                NFT_COLLECTION_OWNER.remove(
                     deps.storage,
                     (
                         msg.data.source_nft_contract_address.clone(),
@@ -867,43 +2092,356 @@ fn claim721(deps: DepsMut, env: Env, info: MessageInfo, msg: ClaimMsg) -> StdRes
                     token_id: msg.data.token_id.to_string(),
                     owner: msg.data.destination_user_address.into_string(),
                     token_uri: Some(msg.data.metadata),
-                    extension: RoyaltyData {
-                        royalty_percentage: msg.data.royalty as u64,
-                        royalty_payment_address: msg.data.royalty_receiver.into_string(),
-                    },
+                    extension: claim_royalty(msg.data.royalty, &msg.data.royalty_receiver),
                 };
 
                 let message = CosmosMsg::Wasm(WasmMsg::Execute {
                     contract_addr: msg.data.source_nft_contract_address.clone(),
 
-                    msg: to_json_binary(&create_collection_msg)?,
-                    funds: vec![],
-                });
+                    msg: to_json_binary(&create_collection_msg)?,
+                    funds: vec![],
+                });
+
+                let log: Vec<Attribute> = vec![ClaimedEventInfo::new(
+                    msg.data.lock_tx_chain,
+                    msg.data.source_chain,
+                    msg.data.transaction_hash,
+                    Addr::unchecked(msg.data.source_nft_contract_address),
+                    msg.data.token_id,
+                )
+                .try_into()?];
+
+                Ok(Response::new().add_message(message).add_attributes(log))
+            }
+        }
+    }?;
+    Ok(res.add_attributes(skipped.into_log()?))
+}
+
+fn deploy_collection_1155(deps: DepsMut, owner: Addr, data: ClaimData) -> StdResult<Response> {
+    let create_collection_msg = CollectionDeployerExecuteMsg::CreateCollection1155 {
+        owner: owner.into_string(),
+        name: data.name,
+        symbol: data.symbol,
+        source_nft_contract_address: data.source_nft_contract_address,
+        source_chain: data.source_chain,
+        destination_user_address: data.destination_user_address,
+        token_id: data.token_id,
+        token_amount: data.token_amount,
+        royalty: data.royalty,
+        royalty_receiver: data.royalty_receiver,
+        metadata: data.metadata,
+        transaction_hash: data.transaction_hash,
+        lock_tx_chain: data.lock_tx_chain,
+    };
+
+    let init_wasm_msg = CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: CONFIG.load(deps.storage)?.collection_deployer.to_string(),
+        msg: to_json_binary(&create_collection_msg)?,
+        funds: vec![],
+    });
+
+    let init_submsg = SubMsg::reply_always(init_wasm_msg, COLLECTION_DEPLOYER_1155_REPLY_ID);
+    Ok(Response::new().add_submessage(init_submsg))
+}
+
+/// Royalty of a claimed token. Claims carry the rate in basis points, as the
+/// source chains do.
+fn claim_royalty(royalty: u16, royalty_receiver: &Addr) -> RoyaltyData {
+    RoyaltyData {
+        royalty_percentage: 0,
+        royalty_payment_address: royalty_receiver.to_string(),
+        royalty_info: Some(RoyaltyInfo {
+            decimal_places_in_rates: 4,
+            royalties: vec![Royalty {
+                recipient: royalty_receiver.to_string(),
+                rate: royalty,
+            }],
+        }),
+    }
+}
+
+fn mint_721_msg(
+    collection: &Addr,
+    to: &Addr,
+    token_id: String,
+    royalty: u16,
+    royalty_receiver: &Addr,
+    metadata: String,
+) -> StdResult<CosmosMsg> {
+    let mint_msg = NftExecuteMsg::Mint {
+        token_id,
+        owner: to.to_string(),
+        token_uri: Some(metadata),
+        extension: claim_royalty(royalty, royalty_receiver),
+    };
+    Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: collection.to_string(),
+        msg: to_json_binary(&mint_msg)?,
+        funds: vec![],
+    }))
+}
+
+fn mint_1155_msg(
+    collection: &Addr,
+    to: &Addr,
+    token_id: String,
+    amount: u128,
+    metadata: String,
+) -> StdResult<CosmosMsg> {
+    let mint_msg = Nft1155ExecuteMsg::Mint {
+        to: to.to_string(),
+        token_id,
+        value: Uint128::from(amount),
+        token_uri: Some(metadata),
+        msg: None,
+    };
+    Ok(CosmosMsg::Wasm(WasmMsg::Execute {
+        contract_addr: collection.to_string(),
+        msg: to_json_binary(&mint_msg)?,
+        funds: vec![],
+    }))
+}
+
+/// Releases the claimed editions held by `storage_contract`. Whatever the storage
+/// cannot cover is minted on the collection, which is only allowed for duplicates.
+fn release_from_storage_1155(
+    storage: &mut dyn Storage,
+    storage_contract: Addr,
+    collection: Addr,
+    data: ClaimData,
+    mint_shortfall: bool,
+) -> Result<Response, ContractError> {
+    let custody_key = (collection.to_string(), data.token_id.clone());
+    let held = NFT_COLLECTION_OWNER
+        .may_load(storage, custody_key.clone())?
+        .map(|(_, amount)| amount)
+        .unwrap_or_default();
+    let unlocked = held.min(data.token_amount);
+    let shortfall = data.token_amount - unlocked;
+
+    if shortfall > 0 && !mint_shortfall {
+        return Err(ContractError::InsufficientLockedEditions {
+            token_id: data.token_id,
+            locked: held,
+            requested: data.token_amount,
+        });
+    }
+
+    let mut messages: Vec<CosmosMsg> = vec![];
+    let mut log: Vec<Attribute> = vec![];
+
+    if unlocked > 0 {
+        let create_unlock_msg = nft_store1155::msg::NftStoreExecuteMsg::UnLockToken {
+            token_id: data.token_id.clone(),
+            amount: unlocked,
+            to: data.destination_user_address.clone(),
+        };
+        messages.push(CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: storage_contract.to_string(),
+            msg: to_json_binary(&create_unlock_msg)?,
+            funds: vec![],
+        }));
+        log.push(
+            UnLock1155EventInfo::new(
+                data.destination_user_address.clone(),
+                data.token_id.clone(),
+                storage_contract.to_string(),
+                unlocked,
+            )
+            .try_into()?,
+        );
+
+        if held == unlocked {
+            NFT_COLLECTION_OWNER.remove(storage, custody_key);
+        } else {
+            NFT_COLLECTION_OWNER.save(
+                storage,
+                custody_key,
+                &(storage_contract, held - unlocked),
+            )?;
+        }
+    }
+
+    if shortfall > 0 {
+        messages.push(mint_1155_msg(
+            &collection,
+            &data.destination_user_address,
+            data.token_id.clone(),
+            shortfall,
+            data.metadata,
+        )?);
+    }
+
+    log.push(
+        Claimed1155EventInfo::new(
+            data.lock_tx_chain,
+            data.source_chain,
+            data.transaction_hash,
+            collection,
+            data.token_id,
+            data.token_amount,
+        )
+        .try_into()?,
+    );
+
+    Ok(Response::new().add_messages(messages).add_attributes(log))
+}
+
+fn claim1155(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ClaimMsg,
+) -> Result<Response, ContractError> {
+    let state = CONFIG.load(deps.storage)?;
+    ensure_claims_allowed(&state.pause, &msg.data)?;
+
+    let balance = deps
+        .querier
+        .query_balance(env.contract.address.clone(), state.fee_denom.clone())?
+        .amount;
+
+    has_correct_fee(msg.data.fee, &state.fee_denom, &info)?;
+
+    matches_current_chain(deps.storage, &msg.data.destination_chain)?;
+
+    if msg.data.nft_type != state.type_erc_1155 {
+        return Err(ContractError::InvalidNftType {
+            expected: state.type_erc_1155,
+            found: msg.data.nft_type,
+        });
+    }
+
+    if msg.data.token_amount == 0 {
+        return Err(ContractError::InvalidTokenAmount {});
+    }
+
+    let (hash, validators_to_reward, skipped) =
+        verify_claim(deps.storage, deps.api, &env, &msg.data, &msg.signatures)?;
+
+    reward_validators(
+        deps.storage,
+        &state.fee_denom,
+        msg.data.fee,
+        validators_to_reward,
+        balance.into(),
+    )?;
+
+    let duplicate_collection_address_option = ORIGINAL_TO_DUPLICATE_STORAGE.may_load(
+        deps.storage,
+        (
+            msg.data.source_nft_contract_address.clone(),
+            msg.data.source_chain.clone(),
+        ),
+    )?;
+
+    let claimed_contract = match &duplicate_collection_address_option {
+        Some(duplicate) => Some(duplicate.contract_address.clone()),
+        None if ORIGINAL_STORAGE_1155.has(
+            deps.storage,
+            (
+                msg.data.source_nft_contract_address.clone(),
+                state.self_chain.clone(),
+            ),
+        ) =>
+        {
+            Some(Addr::unchecked(
+                msg.data.source_nft_contract_address.clone(),
+            ))
+        }
+        None => None,
+    };
+    save_claim_record(deps.storage, &env, hash, &msg.data, claimed_contract)?;
 
-                let log: Vec<Attribute> = vec![ClaimedEventInfo::new(
-                    msg.data.lock_tx_chain,
-                    msg.data.source_chain,
-                    msg.data.transaction_hash,
-                    Addr::unchecked(msg.data.source_nft_contract_address),
-                    msg.data.token_id,
-                )
-                .try_into()?];
+    let res = match duplicate_collection_address_option {
+        Some(duplicate) => {
+            let storage_contract_option = DUPLICATE_STORAGE_1155.may_load(
+                deps.storage,
+                (duplicate.contract_address.to_string(), state.self_chain),
+            )?;
+            match storage_contract_option {
+                // ===============================/ hasDuplicate && hasStorage /=======================
+                Some(storage_contract) => release_from_storage_1155(
+                    deps.storage,
+                    storage_contract,
+                    duplicate.contract_address,
+                    msg.data,
+                    true,
+                ),
+                // ===============================/ hasDuplicate && NOT hasStorage /=======================
+                None => {
+                    let message = mint_1155_msg(
+                        &duplicate.contract_address,
+                        &msg.data.destination_user_address,
+                        msg.data.token_id.clone(),
+                        msg.data.token_amount,
+                        msg.data.metadata,
+                    )?;
+                    let log: Vec<Attribute> = vec![Claimed1155EventInfo::new(
+                        msg.data.lock_tx_chain,
+                        msg.data.source_chain,
+                        msg.data.transaction_hash,
+                        duplicate.contract_address,
+                        msg.data.token_id,
+                        msg.data.token_amount,
+                    )
+                    .try_into()?];
 
-                Ok(Response::new().add_message(message).add_attributes(log))
+                    Ok(Response::new().add_message(message).add_attributes(log))
+                }
+            }
+        }
+        None => {
+            let storage_contract_option = ORIGINAL_STORAGE_1155.may_load(
+                deps.storage,
+                (
+                    msg.data.source_nft_contract_address.clone(),
+                    state.self_chain,
+                ),
+            )?;
+            match storage_contract_option {
+                // ===============================/ NOT hasDuplicate && hasStorage /=======================
+                Some(storage_contract) => {
+                    let collection = deps
+                        .api
+                        .addr_validate(&msg.data.source_nft_contract_address)?;
+                    release_from_storage_1155(
+                        deps.storage,
+                        storage_contract,
+                        collection,
+                        msg.data,
+                        false,
+                    )
+                }
+                // ===============================/ NOT hasDuplicate && NOT hasStorage /=======================
+                None => Ok(deploy_collection_1155(
+                    deps,
+                    env.contract.address,
+                    msg.data,
+                )?),
             }
         }
-    } else {
-        return Err(StdError::generic_err("Invalid bridge state"));
     }?;
-    Ok(res)
+    Ok(res.add_attributes(skipped.into_log()?))
 }
 
 // Queries
 #[entry_point]
-pub fn query(deps: Deps, _env: Env, msg: BridgeQueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: BridgeQueryMsg) -> StdResult<Binary> {
     match msg {
         BridgeQueryMsg::GetValidatorsCount {} => to_json_binary(&validators_count(deps)?),
         BridgeQueryMsg::GetValidator { address } => to_json_binary(&validators(deps, address)?),
+        BridgeQueryMsg::GetValidatorRewards { validator } => {
+            to_json_binary(&validator_rewards(deps, validator)?)
+        }
+        BridgeQueryMsg::GetRewardDust {} => to_json_binary(&reward_dust(deps)?),
+        BridgeQueryMsg::GetPrepaidLockFees { address } => {
+            to_json_binary(&prepaid_lock_fees(deps, address)?)
+        }
+        BridgeQueryMsg::GetRetiredValidator { address } => {
+            to_json_binary(&retired_validator(deps, address)?)
+        }
         BridgeQueryMsg::GetCollectionDeployer {} => to_json_binary(&collection_deployer(deps)?),
         BridgeQueryMsg::GetStorageDeployer {} => to_json_binary(&storage_deployer(deps)?),
         BridgeQueryMsg::GetOriginalStorage721 {
@@ -922,6 +2460,53 @@ pub fn query(deps: Deps, _env: Env, msg: BridgeQueryMsg) -> StdResult<Binary> {
             contract_address,
             chain,
         } => to_json_binary(&duplicate_to_original(deps, contract_address, chain)?),
+        BridgeQueryMsg::Config {} => to_json_binary(&CONFIG.load(deps.storage)?),
+        BridgeQueryMsg::ListValidators { start_after, limit } => {
+            to_json_binary(&list_validators(deps, start_after, limit)?)
+        }
+        BridgeQueryMsg::ListBlacklisted { start_after, limit } => {
+            to_json_binary(&list_blacklisted(deps, start_after, limit)?)
+        }
+        BridgeQueryMsg::ListMappings {
+            kind,
+            start_after,
+            limit,
+        } => to_json_binary(&list_mappings(deps, kind, start_after, limit)?),
+        BridgeQueryMsg::ListStorages {
+            kind,
+            start_after,
+            limit,
+        } => to_json_binary(&list_storages(deps, kind, start_after, limit)?),
+        BridgeQueryMsg::ListLockedTokens { start_after, limit } => {
+            to_json_binary(&list_locked_tokens(deps, start_after, limit)?)
+        }
+        BridgeQueryMsg::GetClaimStatus {
+            lock_tx_chain,
+            transaction_hash,
+        } => to_json_binary(&GetClaimStatusResponse {
+            data: CLAIM_RECORDS
+                .prefix((lock_tx_chain, transaction_hash))
+                .range(deps.storage, None, None, Order::Ascending)
+                .map(|item| item.map(|(_, record)| record))
+                .collect::<StdResult<_>>()?,
+        }),
+        BridgeQueryMsg::ListClaims { start_after, limit } => {
+            to_json_binary(&list_claims(deps, start_after, limit)?)
+        }
+        BridgeQueryMsg::ListSupportedChains { start_after, limit } => {
+            to_json_binary(&list_supported_chains(deps, start_after, limit)?)
+        }
+        BridgeQueryMsg::GetPauseMatrix {} => to_json_binary(&CONFIG.load(deps.storage)?.pause),
+        BridgeQueryMsg::GetChildCodeUpgradeNonce {} => {
+            to_json_binary(&GetChildCodeUpgradeNonceResponse {
+                nonce: CHILD_CODE_UPGRADE_NONCE
+                    .may_load(deps.storage)?
+                    .unwrap_or_default(),
+            })
+        }
+        BridgeQueryMsg::ComputeClaimHash { data } => {
+            to_json_binary(&compute_claim_hash(deps, env, data)?)
+        }
     }
 }
 
@@ -939,6 +2524,43 @@ fn validators(deps: Deps, address: Binary) -> StdResult<GetValidatorResponse> {
     })
 }
 
+fn validator_rewards(deps: Deps, validator: Binary) -> StdResult<GetValidatorRewardsResponse> {
+    let data = VALIDATOR_REWARDS
+        .prefix(validator.to_vec())
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(denom, amount)| Coin { denom, amount }))
+        .collect::<StdResult<_>>()?;
+    let nonce = REWARD_NONCES
+        .may_load(deps.storage, validator.0)?
+        .unwrap_or_default();
+    Ok(GetValidatorRewardsResponse { data, nonce })
+}
+
+fn retired_validator(deps: Deps, address: Binary) -> StdResult<GetRetiredValidatorResponse> {
+    Ok(GetRetiredValidatorResponse {
+        data: RETIRED_VALIDATORS.may_load(deps.storage, address.0)?,
+    })
+}
+
+fn reward_dust(deps: Deps) -> StdResult<GetRewardDustResponse> {
+    let data = REWARD_DUST
+        .range(deps.storage, None, None, Order::Ascending)
+        .filter(|item| !matches!(item, Ok((_, amount)) if amount.is_zero()))
+        .map(|item| item.map(|(denom, amount)| Coin { denom, amount }))
+        .collect::<StdResult<_>>()?;
+    Ok(GetRewardDustResponse { data })
+}
+
+fn prepaid_lock_fees(deps: Deps, address: String) -> StdResult<GetPrepaidLockFeesResponse> {
+    let address = deps.api.addr_validate(&address)?;
+    let data = PREPAID_LOCK_FEES
+        .prefix(address)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(denom, amount)| Coin { denom, amount }))
+        .collect::<StdResult<_>>()?;
+    Ok(GetPrepaidLockFeesResponse { data })
+}
+
 fn collection_deployer(deps: Deps) -> StdResult<GetCollectionDeployerResponse> {
     let collection_deployer = CONFIG.load(deps.storage)?.collection_deployer;
     Ok(GetCollectionDeployerResponse {
@@ -999,6 +2621,191 @@ fn duplicate_to_original(
     })
 }
 
+fn query_limit(limit: Option<u32>) -> usize {
+    limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize
+}
+
+fn list_validators(
+    deps: Deps,
+    start_after: Option<Binary>,
+    limit: Option<u32>,
+) -> StdResult<ListValidatorsResponse> {
+    let start = start_after.map(|key| Bound::exclusive(key.0));
+    let data = VALIDATORS_STORAGE
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(query_limit(limit))
+        .map(|item| {
+            item.map(|(public_key, validator)| ValidatorEntry {
+                public_key: Binary(public_key),
+                validator,
+            })
+        })
+        .collect::<StdResult<_>>()?;
+    Ok(ListValidatorsResponse { data })
+}
+
+fn list_blacklisted(
+    deps: Deps,
+    start_after: Option<Binary>,
+    limit: Option<u32>,
+) -> StdResult<ListBlacklistedResponse> {
+    let start = start_after.map(|key| Bound::exclusive(key.0));
+    let data = BLACKLISTED_VALIDATORS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(query_limit(limit))
+        .map(|item| item.map(|(public_key, _)| Binary(public_key)))
+        .collect::<StdResult<_>>()?;
+    Ok(ListBlacklistedResponse { data })
+}
+
+fn list_mappings(
+    deps: Deps,
+    kind: MappingKind,
+    start_after: Option<(String, String)>,
+    limit: Option<u32>,
+) -> StdResult<ListMappingsResponse> {
+    let limit = query_limit(limit);
+    let data = match kind {
+        MappingKind::OriginalToDuplicate => {
+            let start = start_after.map(Bound::exclusive);
+            ORIGINAL_TO_DUPLICATE_STORAGE
+                .range(deps.storage, start, None, Order::Ascending)
+                .take(limit)
+                .map(|item| {
+                    item.map(|((contract_address, chain), info)| MappingEntry {
+                        contract_address,
+                        chain,
+                        mapped_contract_address: info.contract_address.into_string(),
+                        mapped_chain: info.chain,
+                    })
+                })
+                .collect::<StdResult<_>>()?
+        }
+        MappingKind::DuplicateToOriginal => {
+            let start = start_after.map(|(contract_address, chain)| {
+                Bound::exclusive((Addr::unchecked(contract_address), chain))
+            });
+            DUPLICATE_TO_ORIGINAL_STORAGE
+                .range(deps.storage, start, None, Order::Ascending)
+                .take(limit)
+                .map(|item| {
+                    item.map(|((contract_address, chain), info)| MappingEntry {
+                        contract_address: contract_address.into_string(),
+                        chain,
+                        mapped_contract_address: info.contract_address,
+                        mapped_chain: info.chain,
+                    })
+                })
+                .collect::<StdResult<_>>()?
+        }
+    };
+    Ok(ListMappingsResponse { data })
+}
+
+fn list_storages(
+    deps: Deps,
+    kind: StorageKind,
+    start_after: Option<(String, String)>,
+    limit: Option<u32>,
+) -> StdResult<ListStoragesResponse> {
+    let storages = match kind {
+        StorageKind::Original721 => &ORIGINAL_STORAGE_721,
+        StorageKind::Duplicate721 => &DUPLICATE_STORAGE_721,
+        StorageKind::Original1155 => &ORIGINAL_STORAGE_1155,
+        StorageKind::Duplicate1155 => &DUPLICATE_STORAGE_1155,
+    };
+    let start = start_after.map(Bound::exclusive);
+    let data = storages
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(query_limit(limit))
+        .map(|item| {
+            item.map(
+                |((contract_address, chain), storage_address)| StorageEntry {
+                    contract_address,
+                    chain,
+                    storage_address,
+                },
+            )
+        })
+        .collect::<StdResult<_>>()?;
+    Ok(ListStoragesResponse { data })
+}
+
+fn list_locked_tokens(
+    deps: Deps,
+    start_after: Option<(String, String)>,
+    limit: Option<u32>,
+) -> StdResult<ListLockedTokensResponse> {
+    let start = start_after.map(Bound::exclusive);
+    let data = NFT_COLLECTION_OWNER
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(query_limit(limit))
+        .map(|item| {
+            item.map(
+                |((contract_address, token_id), (storage_address, amount))| LockedTokenEntry {
+                    contract_address,
+                    token_id,
+                    storage_address,
+                    amount,
+                },
+            )
+        })
+        .collect::<StdResult<_>>()?;
+    Ok(ListLockedTokensResponse { data })
+}
+
+fn list_claims(
+    deps: Deps,
+    start_after: Option<ClaimRecordKey>,
+    limit: Option<u32>,
+) -> StdResult<ListClaimsResponse> {
+    let start = start_after.map(Bound::exclusive);
+    let data = CLAIM_RECORDS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(query_limit(limit))
+        .map(|item| item.map(|(_, record)| record))
+        .collect::<StdResult<_>>()?;
+    Ok(ListClaimsResponse { data })
+}
+
+fn list_supported_chains(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<ListSupportedChainsResponse> {
+    let start = start_after.map(Bound::exclusive);
+    let data = SUPPORTED_CHAINS
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(query_limit(limit))
+        .map(|item| item.map(|(chain, config)| SupportedChainEntry { chain, config }))
+        .collect::<StdResult<_>>()?;
+    Ok(ListSupportedChainsResponse {
+        data,
+        nonce: SUPPORTED_CHAINS_NONCE
+            .may_load(deps.storage)?
+            .unwrap_or_default(),
+    })
+}
+
+fn compute_claim_hash(
+    deps: Deps,
+    env: Env,
+    data: ClaimData,
+) -> StdResult<ComputeClaimHashResponse> {
+    let state = CONFIG.load(deps.storage)?;
+    let encoding = data.canonical_encoding(env.contract.address.as_str(), &state.self_chain);
+    Ok(ComputeClaimHashResponse {
+        hash: Binary::from(create_claim_data_hash(
+            &data,
+            &env.contract.address,
+            &state.self_chain,
+        )),
+        legacy_hash: Binary::from(create_legacy_claim_data_hash(&data)),
+        legacy_accepted: accepts_legacy_claim_hash(&state, &env),
+        encoding: Binary::from(encoding),
+    })
+}
+
 // Replies
 #[entry_point]
 pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
@@ -1007,6 +2814,8 @@ pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, Contract
         STORAGE_DEPLOYER_721_REPLY_ID => handle_storage_reply_721(_deps, msg),
         COLLECTION_DEPLOYER_REPLY_ID => handle_collection_deployer_reply(_deps, msg),
         COLLECTION_DEPLOYER_721_REPLY_ID => handle_collection_reply_721(_deps, msg),
+        STORAGE_DEPLOYER_1155_REPLY_ID => handle_storage_reply_1155(_deps, msg),
+        COLLECTION_DEPLOYER_1155_REPLY_ID => handle_collection_reply_1155(_deps, msg),
         id => Err(ContractError::UnexpectedReplyId { id }),
     }
 }
@@ -1053,6 +2862,21 @@ fn handle_storage_reply_721(_deps: DepsMut, msg: Reply) -> Result<Response, Cont
     }
 }
 
+fn handle_storage_reply_1155(deps: DepsMut, msg: Reply) -> Result<Response, ContractError> {
+    let parsed = cw0::parse_reply_execute_data(msg).map_err(|e| ContractError::CustomError {
+        val: format!("Failed to parse execute data: {}", e),
+    })?;
+    match parsed.data {
+        Some(bin) => {
+            let reply_info: ReplyStorage1155Info = from_json(&bin)?;
+            register_storage_1155_impl(deps, reply_info)
+        }
+        None => Err(ContractError::CustomError {
+            val: "Init didn't response with storage address 1155".to_string(),
+        }),
+    }
+}
+
 fn handle_collection_deployer_reply(_deps: DepsMut, msg: Reply) -> Result<Response, ContractError> {
     let parsed = parse_reply_instantiate_data(msg).map_err(|e| ContractError::CustomError {
         val: format!("Failed to parse instantiate data: {}", e),
@@ -1083,6 +2907,21 @@ fn handle_collection_reply_721(_deps: DepsMut, msg: Reply) -> Result<Response, C
     }
 }
 
+fn handle_collection_reply_1155(deps: DepsMut, msg: Reply) -> Result<Response, ContractError> {
+    let parsed = cw0::parse_reply_execute_data(msg).map_err(|e| ContractError::CustomError {
+        val: format!("Failed to parse execute data: {}", e),
+    })?;
+    match parsed.data {
+        Some(bin) => {
+            let reply_info: ReplyCollectionInfo = from_json(&bin)?;
+            register_collection_1155_impl(deps, reply_info)
+        }
+        None => Err(ContractError::CustomError {
+            val: "Init didn't response with collection address 1155".to_string(),
+        }),
+    }
+}
+
 fn register_storage_deployer_impl(
     deps: DepsMut,
     reply_info: ReplyStorageDeployerInfo,
@@ -1113,13 +2952,55 @@ fn register_storage_721_impl(
             &reply_info.address.clone(),
         );
     }
-    let res = transfer_to_storage_721(
+    let collection = deps.api.addr_validate(&reply_info.label.clone())?;
+    // starts with the token the storage was deployed for
+    let pending = PENDING_STORAGE_LOCKS_721
+        .may_load(deps.storage, reply_info.label.clone())?
+        .unwrap_or_default();
+    PENDING_STORAGE_LOCKS_721.remove(deps.storage, reply_info.label);
+    let mut res = Response::new();
+    for (token_id, custody) in pending {
+        res = merge_responses(
+            res,
+            transfer_to_storage_721(
+                deps.storage,
+                reply_info.address.clone(),
+                collection.clone(),
+                token_id,
+                custody,
+            )?,
+        );
+    }
+    Ok(res.add_attribute("storage_address_721", &reply_info.address))
+}
+
+fn register_storage_1155_impl(
+    deps: DepsMut,
+    reply_info: ReplyStorage1155Info,
+) -> Result<Response, ContractError> {
+    let self_chain = CONFIG.load(deps.storage)?.self_chain;
+    if reply_info.is_original {
+        ORIGINAL_STORAGE_1155.save(
+            deps.storage,
+            (reply_info.label.clone(), self_chain),
+            &reply_info.address,
+        )?;
+    } else {
+        DUPLICATE_STORAGE_1155.save(
+            deps.storage,
+            (reply_info.label.clone(), self_chain),
+            &reply_info.address,
+        )?;
+    }
+    let res = transfer_to_storage_1155(
         deps.storage,
+        reply_info.from,
         reply_info.address.clone(),
-        deps.api.addr_validate(&reply_info.label.clone())?,
+        deps.api.addr_validate(&reply_info.label)?,
         reply_info.token_id,
+        reply_info.token_amount,
     )?;
-    Ok(res.add_attribute("storage_address_721", &reply_info.address))
+    Ok(res.add_attribute("storage_address_1155", &reply_info.address))
 }
 
 fn register_collection_deployer_impl(
@@ -1139,18 +3020,37 @@ fn register_collection_721_impl(
 ) -> Result<Response, ContractError> {
     let self_chain = CONFIG.load(deps.storage)?.self_chain;
 
-    let _ = ORIGINAL_TO_DUPLICATE_STORAGE.save(
+    set_claimed_contract(
         deps.storage,
         (
-            reply_info.source_nft_contract_address.clone(),
-            reply_info.source_chain.clone(),
+            reply_info.lock_tx_chain.clone(),
+            reply_info.transaction_hash.clone(),
+            (
+                reply_info.source_nft_contract_address.clone(),
+                reply_info.token_id.clone(),
+            ),
         ),
+        &reply_info.address,
+    )?;
+
+    let source = (
+        reply_info.source_nft_contract_address.clone(),
+        reply_info.source_chain.clone(),
+    );
+
+    let _ = ORIGINAL_TO_DUPLICATE_STORAGE.save(
+        deps.storage,
+        source.clone(),
         &OriginalToDuplicateContractInfo {
             chain: self_chain.clone(),
             contract_address: reply_info.address.clone(),
         },
     );
 
+    if reply_info.burn_on_return {
+        BURN_ON_RETURN_721.save(deps.storage, reply_info.address.clone(), &true)?;
+    }
+
     let _ = DUPLICATE_TO_ORIGINAL_STORAGE.save(
         deps.storage,
         (reply_info.address.clone(), self_chain),
@@ -1160,34 +3060,118 @@ fn register_collection_721_impl(
         },
     );
 
-    let create_collection_msg = NftExecuteMsg::Mint {
-        token_id: reply_info.token_id.to_string(),
-        owner: reply_info.destination_user_address.into_string(),
-        extension: RoyaltyData {
-            royalty_payment_address: reply_info.royalty_receiver.to_string(),
-            royalty_percentage: reply_info.royalty as u64,
+    let message = mint_721_msg(
+        &reply_info.address,
+        &reply_info.destination_user_address,
+        reply_info.token_id.clone(),
+        reply_info.royalty,
+        &reply_info.royalty_receiver,
+        reply_info.metadata,
+    )?;
+    let mut messages = vec![message];
+    let mut emit: Vec<Attribute> = vec![ClaimedEventInfo::new(
+        reply_info.lock_tx_chain,
+        reply_info.source_chain,
+        reply_info.transaction_hash,
+        reply_info.address.clone(),
+        reply_info.token_id,
+    )
+    .try_into()?];
+
+    // claims of the same batch that waited on this collection
+    let pending = PENDING_COLLECTION_CLAIMS_721
+        .may_load(deps.storage, source.clone())?
+        .unwrap_or_default();
+    PENDING_COLLECTION_CLAIMS_721.remove(deps.storage, source);
+    for data in pending {
+        set_claimed_contract(deps.storage, claim_record_key(&data), &reply_info.address)?;
+        messages.push(mint_721_msg(
+            &reply_info.address,
+            &data.destination_user_address,
+            data.token_id.clone(),
+            data.royalty,
+            &data.royalty_receiver,
+            data.metadata,
+        )?);
+        emit.push(
+            ClaimedEventInfo::new(
+                data.lock_tx_chain,
+                data.source_chain,
+                data.transaction_hash,
+                reply_info.address.clone(),
+                data.token_id,
+            )
+            .try_into()?,
+        );
+    }
+
+    Ok(Response::new()
+        .add_messages(messages)
+        .add_attributes(emit)
+        .add_attribute("collection_address_721", &reply_info.address))
+}
+
+fn register_collection_1155_impl(
+    deps: DepsMut,
+    reply_info: ReplyCollectionInfo,
+) -> Result<Response, ContractError> {
+    let self_chain = CONFIG.load(deps.storage)?.self_chain;
+
+    set_claimed_contract(
+        deps.storage,
+        (
+            reply_info.lock_tx_chain.clone(),
+            reply_info.transaction_hash.clone(),
+            (
+                reply_info.source_nft_contract_address.clone(),
+                reply_info.token_id.clone(),
+            ),
+        ),
+        &reply_info.address,
+    )?;
+
+    ORIGINAL_TO_DUPLICATE_STORAGE.save(
+        deps.storage,
+        (
+            reply_info.source_nft_contract_address.clone(),
+            reply_info.source_chain.clone(),
+        ),
+        &OriginalToDuplicateContractInfo {
+            chain: self_chain.clone(),
+            contract_address: reply_info.address.clone(),
+        },
+    )?;
+
+    DUPLICATE_TO_ORIGINAL_STORAGE.save(
+        deps.storage,
+        (reply_info.address.clone(), self_chain),
+        &DuplicateToOriginalContractInfo {
+            chain: reply_info.source_chain.clone(),
+            contract_address: reply_info.source_nft_contract_address,
         },
-        token_uri: Some(reply_info.metadata),
-    };
+    )?;
 
-    let message = CosmosMsg::Wasm(WasmMsg::Execute {
-        contract_addr: reply_info.address.clone().into_string(),
-        msg: to_json_binary(&create_collection_msg)?,
-        funds: vec![],
-    });
-    let emit: Vec<Attribute> = vec![ClaimedEventInfo::new(
+    let message = mint_1155_msg(
+        &reply_info.address,
+        &reply_info.destination_user_address,
+        reply_info.token_id.clone(),
+        reply_info.token_amount,
+        reply_info.metadata,
+    )?;
+    let emit: Vec<Attribute> = vec![Claimed1155EventInfo::new(
         reply_info.lock_tx_chain,
         reply_info.source_chain,
         reply_info.transaction_hash,
         reply_info.address.clone(),
         reply_info.token_id,
+        reply_info.token_amount,
     )
     .try_into()?];
 
     Ok(Response::new()
         .add_message(message)
         .add_attributes(emit)
-        .add_attribute("collection_address_721", &reply_info.address))
+        .add_attribute("collection_address_1155", &reply_info.address))
 }
 
 #[cfg(test)]
@@ -1213,10 +3197,18 @@ mod tests {
         let init_msg = BridgeInstantiateMsg {
             validators: vec![(validator_pub_key.clone(), info.sender.clone())],
             chain_type: "SECRET".to_string(),
+            fee_denom: "uscrt".to_string(),
+            legacy_claim_hash_until: None,
+            supported_chains: vec![],
+            max_batch_size: None,
+            burn_on_return: false,
+            threshold: None,
             storage_label: "storage11".to_string(),
             collection_label: "collection11".to_string(),
             collection721_code_id: 1,
             storage721_code_id: 2,
+            collection1155_code_id: 3,
+            storage1155_code_id: 4,
             collection_deployer_code_id: 5,
             storage_deployer_code_id: 6,
         };
@@ -1266,8 +3258,7 @@ mod tests {
             1, validators_count_answer.count,
             "Invalid validator count after init"
         );
-        assert_eq!(
-            true,
+        assert!(
             validator_answer.data.unwrap().added,
             "Invalid validator after init"
         );
@@ -1282,4 +3273,185 @@ mod tests {
             .addr_validate(&storage_deployer_answer.data.into_string());
         assert!(valid_addr.is_ok(), "Invalid storage deployer after init");
     }
+
+    #[test]
+    fn migrate_checks_contract_version() {
+        let mut deps = mock_dependencies();
+        let msg = || BridgeMigrateMsg {
+            legacy_claim_hash_until: None,
+            max_batch_size: None,
+            burn_on_return: None,
+            fee_denom: None,
+        };
+
+        // bridges deployed before cw2 was recorded can be migrated
+        migrate(deps.as_mut(), mock_env(), msg()).unwrap();
+        let version = cw2::get_contract_version(&deps.storage).unwrap();
+        assert_eq!(version.contract, CONTRACT_NAME);
+        assert_eq!(version.version, CONTRACT_VERSION);
+
+        cw2::set_contract_version(deps.as_mut().storage, "crates.io:other", "0.1.0").unwrap();
+        let err = migrate(deps.as_mut(), mock_env(), msg()).unwrap_err();
+        assert!(
+            matches!(err, ContractError::InvalidMigrationContract { .. }),
+            "{:?}",
+            err
+        );
+
+        cw2::set_contract_version(deps.as_mut().storage, CONTRACT_NAME, "99.0.0").unwrap();
+        let err = migrate(deps.as_mut(), mock_env(), msg()).unwrap_err();
+        assert!(
+            matches!(err, ContractError::CannotMigrateFromNewerVersion { .. }),
+            "{:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn reward_validators_carries_dust() {
+        let mut deps = mock_dependencies();
+        let signers: Vec<Binary> = (1..=3u8).map(|i| Binary::from(vec![i; 33])).collect();
+        let pending = |deps: &cosmwasm_std::OwnedDeps<_, _, _>, signer: &Binary| {
+            VALIDATOR_REWARDS
+                .load(&deps.storage, (signer.to_vec(), "uscrt".to_string()))
+                .unwrap()
+                .u128()
+        };
+        let dust = |deps: &cosmwasm_std::OwnedDeps<_, _, _>| {
+            REWARD_DUST
+                .load(&deps.storage, "uscrt".to_string())
+                .unwrap()
+                .u128()
+        };
+
+        reward_validators(deps.as_mut().storage, "uscrt", 1000, signers.clone(), 1000).unwrap();
+        assert!(signers.iter().all(|s| pending(&deps, s) == 333));
+        assert_eq!(dust(&deps), 1);
+
+        reward_validators(deps.as_mut().storage, "uscrt", 1001, signers.clone(), 1001).unwrap();
+        assert!(signers.iter().all(|s| pending(&deps, s) == 667));
+        assert_eq!(dust(&deps), 0);
+
+        // dust is kept apart per denom
+        reward_validators(deps.as_mut().storage, "uluna", 2, signers.clone(), 2).unwrap();
+        assert_eq!(dust(&deps), 0);
+        assert!(!VALIDATOR_REWARDS.has(&deps.storage, (signers[0].to_vec(), "uluna".to_string())));
+        let res: GetRewardDustResponse =
+            from_json(query(deps.as_ref(), mock_env(), BridgeQueryMsg::GetRewardDust {}).unwrap())
+                .unwrap();
+        assert_eq!(res.data, vec![Coin::new(2, "uluna")]);
+    }
+
+    #[test]
+    fn migrate_moves_legacy_rewards() {
+        let mut deps = mock_dependencies();
+        let info = mock_info("creator", &[]);
+        let validator = Binary::from(vec![2; 33]);
+        let init_msg = BridgeInstantiateMsg {
+            validators: vec![(validator.clone(), info.sender.clone())],
+            chain_type: "SECRET".to_string(),
+            fee_denom: "uscrt".to_string(),
+            legacy_claim_hash_until: None,
+            supported_chains: vec![],
+            max_batch_size: None,
+            burn_on_return: false,
+            threshold: None,
+            storage_label: "storage".to_string(),
+            collection_label: "collection".to_string(),
+            collection721_code_id: 1,
+            storage721_code_id: 2,
+            collection1155_code_id: 3,
+            storage1155_code_id: 4,
+            collection_deployer_code_id: 5,
+            storage_deployer_code_id: 6,
+        };
+        instantiate(deps.as_mut(), mock_env(), info, init_msg).unwrap();
+        VALIDATORS_STORAGE
+            .update(
+                deps.as_mut().storage,
+                validator.to_vec(),
+                |v| -> StdResult<_> {
+                    let mut v = v.unwrap();
+                    v.pending_reward = 500;
+                    Ok(v)
+                },
+            )
+            .unwrap();
+
+        let msg = BridgeMigrateMsg {
+            legacy_claim_hash_until: None,
+            max_batch_size: None,
+            burn_on_return: None,
+            fee_denom: None,
+        };
+        migrate(deps.as_mut(), mock_env(), msg).unwrap();
+
+        let res: GetValidatorRewardsResponse = from_json(
+            query(
+                deps.as_ref(),
+                mock_env(),
+                BridgeQueryMsg::GetValidatorRewards {
+                    validator: validator.clone(),
+                },
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(res.data, vec![Coin::new(500, "uscrt")]);
+        let v = VALIDATORS_STORAGE
+            .load(&deps.storage, validator.to_vec())
+            .unwrap();
+        assert_eq!(v.pending_reward, 0);
+    }
+
+    #[test]
+    fn migrate_reads_baseline_state() {
+        let validator = Binary::from(vec![2; 33]);
+        let baseline = |fee_denom: Option<&str>| {
+            let mut deps = mock_dependencies();
+            // `State` as saved by bridges deployed before the fee denom
+            deps.storage.set(
+                b"config",
+                br#"{"collection_deployer":"collection_deployer","storage_deployer":"storage_deployer","validators_count":"1","self_chain":"SECRET","type_erc_721":"singular","type_erc_1155":"multiple"}"#,
+            );
+            deps.storage.set(
+                &VALIDATORS_STORAGE.key(validator.to_vec()),
+                br#"{"address":"validator","added":true,"pending_reward":"500"}"#,
+            );
+            let msg = BridgeMigrateMsg {
+                legacy_claim_hash_until: None,
+                max_batch_size: None,
+                burn_on_return: None,
+                fee_denom: fee_denom.map(str::to_string),
+            };
+            migrate(deps.as_mut(), mock_env(), msg).unwrap();
+            deps
+        };
+        let rewards = |deps: &cosmwasm_std::OwnedDeps<_, _, _>| -> Vec<Coin> {
+            let res: GetValidatorRewardsResponse = from_json(
+                query(
+                    deps.as_ref(),
+                    mock_env(),
+                    BridgeQueryMsg::GetValidatorRewards {
+                        validator: validator.clone(),
+                    },
+                )
+                .unwrap(),
+            )
+            .unwrap();
+            res.data
+        };
+
+        let deps = baseline(None);
+        let state = CONFIG.load(&deps.storage).unwrap();
+        assert_eq!(state.fee_denom, "uscrt");
+        assert_eq!(state.max_batch_size, DEFAULT_MAX_BATCH_SIZE);
+        assert_eq!(state.threshold_nonce, 0);
+        assert_eq!(rewards(&deps), vec![Coin::new(500, "uscrt")]);
+
+        // rewards collected before the migration stay in the denom they were paid in
+        let deps = baseline(Some("uluna"));
+        assert_eq!(CONFIG.load(&deps.storage).unwrap().fee_denom, "uluna");
+        assert_eq!(rewards(&deps), vec![Coin::new(500, "uscrt")]);
+    }
 }
@@ -1,4 +1,4 @@
-use cosmwasm_std::StdError;
+use cosmwasm_std::{Binary, StdError, Uint128};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -6,47 +6,153 @@ pub enum ContractError {
     #[error("{0}")]
     Std(#[from] StdError),
 
-    #[error("This contract is stopped")]
-    Stopped {},
-
     #[error("Unauthorized")]
     Unauthorized {},
 
-    #[error("This address is unauthorized and/or viewing key is not valid")]
-    ViewingKeyOrUnauthorized {},
-
-    #[error("Submessage (id: {id:?}) reply cannot be parsed.")]
-    ParseReplyError { id: u64 },
-
     #[error("Unknown reply id: {id:?}")]
     UnexpectedReplyId { id: u64 },
 
-    /// Whenever UTF-8 bytes cannot be decoded into a unicode string, e.g. in String::from_utf8 or str::from_utf8.
-    #[error("Cannot decode UTF8 bytes into string: {msg}")]
-    InvalidUtf8 { msg: String },
-
     #[error("Custom Error val: {val:?}")]
     CustomError { val: String },
-    // Add any other custom errors you like here.
-    // Look at https://docs.rs/thiserror/1.0.21/thiserror/ for details.
-}
 
-impl ContractError {
-    pub fn invalid_utf8(msg: impl ToString) -> Self {
-        ContractError::InvalidUtf8 {
-            msg: msg.to_string(),
-        }
-    }
-}
+    #[error("Invalid destination chain")]
+    InvalidDestinationChain {},
 
-impl From<std::str::Utf8Error> for ContractError {
-    fn from(source: std::str::Utf8Error) -> Self {
-        Self::invalid_utf8(source)
-    }
-}
+    #[error("Fee too low: sent {sent}, required {required}")]
+    FeeTooLow { sent: Uint128, required: Uint128 },
+
+    #[error("Invalid fee")]
+    InvalidFee {},
+
+    #[error("Not enough balance to reward validators: balance {balance}, fee {fee}")]
+    InsufficientRewardBalance { balance: Uint128, fee: Uint128 },
+
+    #[error("Threshold not reached: {got} valid signatures, {need} required")]
+    ThresholdNotReached { got: u64, need: u64 },
+
+    #[error("Claim {hash} already processed")]
+    AlreadyProcessed { hash: Binary },
+
+    #[error("Signature verification failed")]
+    InvalidSignature {},
+
+    #[error("No signatures")]
+    MissingSignatures {},
+
+    #[error("Invalid NFT type: expected {expected}, found {found}")]
+    InvalidNftType { expected: String, found: String },
+
+    #[error("Invalid source NFT contract address {address}")]
+    InvalidSourceNftContract { address: String },
+
+    #[error("Token amount must be greater than zero")]
+    InvalidTokenAmount {},
+
+    #[error("Only {locked} editions of token {token_id} are locked, {requested} requested")]
+    InsufficientLockedEditions {
+        token_id: String,
+        locked: u128,
+        requested: u128,
+    },
+
+    #[error("Invalid child code upgrade nonce: expected {expected}, found {found}")]
+    InvalidUpgradeNonce { expected: u64, found: u64 },
+
+    #[error("Cannot migrate from contract {found}, expected {expected}")]
+    InvalidMigrationContract { expected: String, found: String },
+
+    #[error("Invalid contract version: {version}")]
+    InvalidContractVersion { version: String },
+
+    #[error("Cannot migrate from newer version {stored} to {current}")]
+    CannotMigrateFromNewerVersion { stored: String, current: String },
+
+    #[error("Locks are paused")]
+    LocksPaused {},
+
+    #[error("Claims are paused")]
+    ClaimsPaused {},
+
+    #[error("Bridging to {chain} is disabled")]
+    DestinationChainDisabled { chain: String },
+
+    #[error("Bridging from {chain} is disabled")]
+    SourceChainDisabled { chain: String },
 
-impl From<std::string::FromUtf8Error> for ContractError {
-    fn from(source: std::string::FromUtf8Error) -> Self {
-        Self::invalid_utf8(source)
-    }
-}
\ No newline at end of file
+    #[error("Invalid pause nonce: expected {expected}, found {found}")]
+    InvalidPauseNonce { expected: u64, found: u64 },
+
+    #[error("Invalid threshold policy nonce: expected {expected}, found {found}")]
+    InvalidThresholdPolicyNonce { expected: u64, found: u64 },
+
+    #[error("Invalid threshold policy")]
+    InvalidThresholdPolicy {},
+
+    #[error("Unsupported chain {chain}")]
+    UnsupportedChain { chain: String },
+
+    #[error("Chain {chain} is not enabled")]
+    ChainNotEnabled { chain: String },
+
+    #[error("Invalid address {address} for chain {chain}")]
+    InvalidDestinationAddress { chain: String, address: String },
+
+    #[error("Invalid supported chain nonce: expected {expected}, found {found}")]
+    InvalidSupportedChainNonce { expected: u64, found: u64 },
+
+    #[error("Received token {token_id} from {sender} does not match the lock payload")]
+    ReceivedNftMismatch { sender: String, token_id: String },
+
+    #[error("{sender} may not lock token {token_id}: not its owner or approved by it")]
+    NotTokenOwner { sender: String, token_id: String },
+
+    #[error("Batch is empty")]
+    EmptyBatch {},
+
+    #[error("Batch of {size} items exceeds the maximum of {max}")]
+    BatchTooLarge { size: usize, max: u32 },
+
+    #[error("Token {token_id} is not held in a storage")]
+    NotInCustody { token_id: String },
+
+    #[error("Token {token_id} was locked again since the return was signed")]
+    CustodyMismatch { token_id: String },
+
+    #[error("Unknown validator")]
+    UnknownValidator {},
+
+    #[error("Validator already added")]
+    ValidatorAlreadyAdded {},
+
+    #[error("Validator is blacklisted")]
+    ValidatorBlacklisted {},
+
+    #[error("Validator key is retired")]
+    ValidatorRetired {},
+
+    #[error("Validator key is already registered, blacklisted or retired")]
+    ValidatorKeyInUse {},
+
+    #[error("Invalid validator key")]
+    InvalidValidatorKey {},
+
+    #[error("Key rotation must be signed by the key being rotated")]
+    MissingRotatedKeySignature {},
+
+    #[error("Invalid reward withdrawal nonce: expected {expected}, found {found}")]
+    InvalidRewardNonce { expected: u64, found: u64 },
+
+    #[error("No funds sent")]
+    NoFundsSent {},
+
+    #[error("Nothing to withdraw")]
+    NothingToWithdraw {},
+
+    #[error("Insufficient rewards: pending {pending}, requested {requested}")]
+    InsufficientRewards {
+        pending: Uint128,
+        requested: Uint128,
+    },
+    // Add any other custom errors you like here.
+    // Look at https://docs.rs/thiserror/1.0.21/thiserror/ for details.
+}
@@ -2,7 +2,9 @@
 use std::convert::TryInto;
 
 use cosmwasm_schema::{cw_serde, serde::Serialize};
-use cosmwasm_std::{Addr, Attribute, StdError, StdResult};
+use cosmwasm_std::{Addr, Attribute, Binary, Coin, StdError, StdResult};
+
+use crate::structs::{ChildContract, PauseMatrix, SupportedChain, ThresholdPolicy};
 
 /// Converts an event to [LogAttribute]
 fn to_log_attr<T: Serialize>(name: &str, e: &T) -> StdResult<Attribute> {
@@ -38,29 +40,203 @@ impl AddNewValidatorEventInfo {
     }
 }
 
-
 #[cw_serde]
 pub struct BlacklistValidatorEventInfo {
     pub validator: Addr,
+    /// Pending rewards of the validator, split with the next fees instead
+    pub forfeited_rewards: Vec<Coin>,
 }
 bridge_event!(BlacklistValidatorEventInfo);
 
 impl BlacklistValidatorEventInfo {
-    pub fn new(validator: Addr) -> Self {
-        Self { validator }
+    pub fn new(validator: Addr, forfeited_rewards: Vec<Coin>) -> Self {
+        Self {
+            validator,
+            forfeited_rewards,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct ValidatorKeyRotatedEventInfo {
+    pub validator: Addr,
+    pub old: Binary,
+    pub new: Binary,
+}
+bridge_event!(ValidatorKeyRotatedEventInfo);
+
+impl ValidatorKeyRotatedEventInfo {
+    pub fn new(validator: Addr, old: Binary, new: Binary) -> Self {
+        Self {
+            validator,
+            old,
+            new,
+        }
     }
 }
 
+/// Signatures that were not counted towards the threshold, by signer key
+#[cw_serde]
+#[derive(Default)]
+pub struct SkippedSignersEventInfo {
+    pub unknown: Vec<Binary>,
+    pub duplicate: Vec<Binary>,
+    pub blacklisted: Vec<Binary>,
+    pub invalid: Vec<Binary>,
+}
+bridge_event!(SkippedSignersEventInfo);
+
+impl SkippedSignersEventInfo {
+    pub fn is_empty(&self) -> bool {
+        self.unknown.is_empty()
+            && self.duplicate.is_empty()
+            && self.blacklisted.is_empty()
+            && self.invalid.is_empty()
+    }
+
+    /// Emitted only when at least one signature was skipped
+    pub fn into_log(self) -> StdResult<Vec<Attribute>> {
+        if self.is_empty() {
+            return Ok(vec![]);
+        }
+        Ok(vec![self.try_into()?])
+    }
+}
 
 #[cw_serde]
 pub struct RewardValidatorEventInfo {
     pub validator: Addr,
+    pub to: Addr,
+    pub amount: Coin,
 }
 bridge_event!(RewardValidatorEventInfo);
 
 impl RewardValidatorEventInfo {
-    pub fn new(validator: Addr) -> Self {
-        Self { validator }
+    pub fn new(validator: Addr, to: Addr, amount: Coin) -> Self {
+        Self {
+            validator,
+            to,
+            amount,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct ChildCodeUpgradedEventInfo {
+    pub child: ChildContract,
+    pub code_id: u64,
+}
+bridge_event!(ChildCodeUpgradedEventInfo);
+
+impl ChildCodeUpgradedEventInfo {
+    pub fn new(child: ChildContract, code_id: u64) -> Self {
+        Self { child, code_id }
+    }
+}
+
+#[cw_serde]
+pub struct SupportedChainUpdatedEventInfo {
+    pub chain: String,
+    pub config: SupportedChain,
+}
+bridge_event!(SupportedChainUpdatedEventInfo);
+
+impl SupportedChainUpdatedEventInfo {
+    pub fn new(chain: String, config: SupportedChain) -> Self {
+        Self { chain, config }
+    }
+}
+
+#[cw_serde]
+pub struct ThresholdPolicyUpdatedEventInfo {
+    pub policy: ThresholdPolicy,
+}
+bridge_event!(ThresholdPolicyUpdatedEventInfo);
+
+impl ThresholdPolicyUpdatedEventInfo {
+    pub fn new(policy: ThresholdPolicy) -> Self {
+        Self { policy }
+    }
+}
+
+fn newly_added(before: &[String], after: &[String]) -> Vec<String> {
+    after
+        .iter()
+        .filter(|chain| !before.contains(chain))
+        .cloned()
+        .collect()
+}
+
+/// What a pause update stopped that was running before
+#[cw_serde]
+pub struct PausedEventInfo {
+    pub locks: bool,
+    pub claims: bool,
+    pub destination_chains: Vec<String>,
+    pub source_chains: Vec<String>,
+}
+bridge_event!(PausedEventInfo);
+
+impl PausedEventInfo {
+    pub fn new(before: &PauseMatrix, after: &PauseMatrix) -> Self {
+        Self {
+            locks: !before.locks && after.locks,
+            claims: !before.claims && after.claims,
+            destination_chains: newly_added(
+                &before.disabled_destination_chains,
+                &after.disabled_destination_chains,
+            ),
+            source_chains: newly_added(
+                &before.disabled_source_chains,
+                &after.disabled_source_chains,
+            ),
+        }
+    }
+
+    /// Emitted only when something was paused
+    pub fn into_log(self) -> StdResult<Vec<Attribute>> {
+        if !self.locks
+            && !self.claims
+            && self.destination_chains.is_empty()
+            && self.source_chains.is_empty()
+        {
+            return Ok(vec![]);
+        }
+        Ok(vec![self.try_into()?])
+    }
+}
+
+/// What a pause update resumed
+#[cw_serde]
+pub struct UnpausedEventInfo {
+    pub locks: bool,
+    pub claims: bool,
+    pub destination_chains: Vec<String>,
+    pub source_chains: Vec<String>,
+}
+bridge_event!(UnpausedEventInfo);
+
+impl UnpausedEventInfo {
+    pub fn new(before: &PauseMatrix, after: &PauseMatrix) -> Self {
+        let resumed = PausedEventInfo::new(after, before);
+        Self {
+            locks: resumed.locks,
+            claims: resumed.claims,
+            destination_chains: resumed.destination_chains,
+            source_chains: resumed.source_chains,
+        }
+    }
+
+    /// Emitted only when something was resumed
+    pub fn into_log(self) -> StdResult<Vec<Attribute>> {
+        if !self.locks
+            && !self.claims
+            && self.destination_chains.is_empty()
+            && self.source_chains.is_empty()
+        {
+            return Ok(vec![]);
+        }
+        Ok(vec![self.try_into()?])
     }
 }
 
@@ -116,6 +292,45 @@ impl UnLock721EventInfo {
     }
 }
 
+/// A locked 721 token given back to its locker
+#[cw_serde]
+pub struct EmergencyReturnedEventInfo {
+    pub to: Addr,
+    pub token_id: String,
+    pub contract_address: String,
+}
+bridge_event!(EmergencyReturnedEventInfo);
+
+impl EmergencyReturnedEventInfo {
+    pub fn new(to: Addr, token_id: String, contract_address: String) -> Self {
+        Self {
+            to,
+            token_id,
+            contract_address,
+        }
+    }
+}
+
+#[cw_serde]
+pub struct UnLock1155EventInfo {
+    pub to: Addr,
+    pub token_id: String,
+    pub contract_address: String,
+    pub amount: u128,
+}
+bridge_event!(UnLock1155EventInfo);
+
+impl UnLock1155EventInfo {
+    pub fn new(to: Addr, token_id: String, contract_address: String, amount: u128) -> Self {
+        Self {
+            to,
+            token_id,
+            contract_address,
+            amount,
+        }
+    }
+}
+
 #[cw_serde]
 pub struct ClaimedEventInfo {
     pub lock_tx_chain: String,
@@ -143,3 +358,34 @@ impl ClaimedEventInfo {
         }
     }
 }
+
+#[cw_serde]
+pub struct Claimed1155EventInfo {
+    pub lock_tx_chain: String,
+    pub source_chain: String,
+    pub transaction_hash: String,
+    pub contract: Addr,
+    pub token_id: String,
+    pub amount: u128,
+}
+bridge_event!(Claimed1155EventInfo);
+
+impl Claimed1155EventInfo {
+    pub fn new(
+        lock_tx_chain: String,
+        source_chain: String,
+        transaction_hash: String,
+        contract: Addr,
+        token_id: String,
+        amount: u128,
+    ) -> Self {
+        Self {
+            lock_tx_chain,
+            source_chain,
+            transaction_hash,
+            contract,
+            token_id,
+            amount,
+        }
+    }
+}
@@ -4,4 +4,6 @@ pub mod events;
 pub mod msg;
 pub mod state;
 pub mod structs;
-pub mod tests;
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests;
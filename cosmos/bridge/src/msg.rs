@@ -1,30 +1,100 @@
 use crate::structs::{
-    AddValidatorMsg, BlacklistValidatorMsg, ClaimMsg, ClaimValidatorRewardsMsg, DuplicateToOriginalContractInfo, Lock721Msg, OriginalToDuplicateContractInfo, Validator, VerifyMsg
+    AddValidatorMsg, BlacklistValidatorMsg, ClaimData, ClaimMsg, ClaimRecord,
+    ClaimValidatorRewardsMsg, DuplicateToOriginalContractInfo, EmergencyReturnMsg, Lock1155Msg,
+    Lock721Msg, OriginalToDuplicateContractInfo, PauseMatrix, RotateValidatorKeyMsg, SetPausedMsg,
+    SetSupportedChainMsg, SetThresholdPolicyMsg, State, SupportedChain, UpgradeChildCodeMsg,
+    Validator, VerifyMsg,
 };
 
+use crate::state::ClaimRecordKey;
+
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Addr, Binary};
+use cosmwasm_std::{Addr, Binary, Coin};
+use cw721::Cw721ReceiveMsg;
 
 /// Executes
 #[cw_serde]
 pub enum BridgeExecuteMsg {
-    AddValidator { data: AddValidatorMsg },
-    BlacklistValidator {data: BlacklistValidatorMsg},
-    ClaimValidatorRewards { data: ClaimValidatorRewardsMsg },
-    Lock721 { data: Lock721Msg },
-    Claim721 { data: ClaimMsg },
-    VerifySig { data: VerifyMsg },
+    AddValidator {
+        data: AddValidatorMsg,
+    },
+    BlacklistValidator {
+        data: BlacklistValidatorMsg,
+    },
+    ClaimValidatorRewards {
+        data: ClaimValidatorRewardsMsg,
+    },
+    /// Moves a validator, its rewards included, to a new key and retires
+    /// the old one
+    RotateValidatorKey {
+        data: RotateValidatorKeyMsg,
+    },
+    Lock721 {
+        data: Lock721Msg,
+    },
+    Lock1155 {
+        data: Lock1155Msg,
+    },
+    /// Locks every item or none of them
+    BatchLock721 {
+        items: Vec<Lock721Msg>,
+    },
+    Claim721 {
+        data: ClaimMsg,
+    },
+    Claim1155 {
+        data: ClaimMsg,
+    },
+    /// Claims every item or none of them. The fees of all claims are paid upfront.
+    BatchClaim721 {
+        claims: Vec<ClaimMsg>,
+    },
+    VerifySig {
+        data: VerifyMsg,
+    },
+    UpgradeChildCode {
+        data: UpgradeChildCodeMsg,
+    },
+    SetPaused {
+        data: SetPausedMsg,
+    },
+    SetSupportedChain {
+        data: SetSupportedChainMsg,
+    },
+    /// Replaces [State::threshold], signed under the current policy
+    SetThresholdPolicy {
+        data: SetThresholdPolicyMsg,
+    },
+    /// cw721 `SendNft` hook, `msg` carries a [Lock721Msg] for the sent token.
+    /// The lock fee is charged from the prepaid lock fees of the token's sender.
+    ReceiveNft(Cw721ReceiveMsg),
+    /// Prepays lock fees for [BridgeExecuteMsg::ReceiveNft] locks
+    DepositLockFees {},
+    /// Sends back every prepaid lock fee of the sender
+    WithdrawLockFees {},
+    /// Gives a locked 721 token back to its locker, see [EmergencyReturnMsg]
+    EmergencyReturn721 {
+        data: EmergencyReturnMsg,
+    },
 }
 
 /// Queries
 #[cw_serde]
 #[derive(QueryResponses)]
+#[allow(clippy::large_enum_variant)]
 pub enum BridgeQueryMsg {
     // GetCount returns the current count as a json-encoded number
     #[returns(GetValidatorCountResponse)]
     GetValidatorsCount {},
     #[returns(GetValidatorResponse)]
     GetValidator { address: Binary },
+    #[returns(GetValidatorRewardsResponse)]
+    GetValidatorRewards { validator: Binary },
+    /// Fee remainders waiting to be split with the next fee in their denom
+    #[returns(GetRewardDustResponse)]
+    GetRewardDust {},
+    #[returns(GetPrepaidLockFeesResponse)]
+    GetPrepaidLockFees { address: String },
     #[returns(GetCollectionDeployerResponse)]
     GetCollectionDeployer {},
     #[returns(GetStorageDeployerResponse)]
@@ -49,6 +119,80 @@ pub enum BridgeQueryMsg {
         contract_address: Addr,
         chain: String,
     },
+    #[returns(State)]
+    Config {},
+    #[returns(ListValidatorsResponse)]
+    ListValidators {
+        start_after: Option<Binary>,
+        limit: Option<u32>,
+    },
+    /// Key a retired validator key was rotated to
+    #[returns(GetRetiredValidatorResponse)]
+    GetRetiredValidator { address: Binary },
+    #[returns(ListBlacklistedResponse)]
+    ListBlacklisted {
+        start_after: Option<Binary>,
+        limit: Option<u32>,
+    },
+    /// `start_after` is a `(contract_address, chain)` key
+    #[returns(ListMappingsResponse)]
+    ListMappings {
+        kind: MappingKind,
+        start_after: Option<(String, String)>,
+        limit: Option<u32>,
+    },
+    /// `start_after` is a `(contract_address, chain)` key
+    #[returns(ListStoragesResponse)]
+    ListStorages {
+        kind: StorageKind,
+        start_after: Option<(String, String)>,
+        limit: Option<u32>,
+    },
+    /// `start_after` is a `(contract_address, token_id)` key
+    #[returns(ListLockedTokensResponse)]
+    ListLockedTokens {
+        start_after: Option<(String, String)>,
+        limit: Option<u32>,
+    },
+    /// Every token claimed from the lock transaction, none if it wasn't claimed
+    #[returns(GetClaimStatusResponse)]
+    GetClaimStatus {
+        lock_tx_chain: String,
+        transaction_hash: String,
+    },
+    /// `start_after` is a `(lock_tx_chain, transaction_hash,
+    /// (source_nft_contract_address, token_id))` key
+    #[returns(ListClaimsResponse)]
+    ListClaims {
+        start_after: Option<ClaimRecordKey>,
+        limit: Option<u32>,
+    },
+    #[returns(PauseMatrix)]
+    GetPauseMatrix {},
+    #[returns(GetChildCodeUpgradeNonceResponse)]
+    GetChildCodeUpgradeNonce {},
+    #[returns(ListSupportedChainsResponse)]
+    ListSupportedChains {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    /// What validators are expected to sign for `data`
+    #[returns(ComputeClaimHashResponse)]
+    ComputeClaimHash { data: ClaimData },
+}
+
+#[cw_serde]
+pub enum MappingKind {
+    OriginalToDuplicate,
+    DuplicateToOriginal,
+}
+
+#[cw_serde]
+pub enum StorageKind {
+    Original721,
+    Duplicate721,
+    Original1155,
+    Duplicate1155,
 }
 
 #[cw_serde]
@@ -61,6 +205,23 @@ pub struct GetValidatorResponse {
     pub data: Option<Validator>,
 }
 
+#[cw_serde]
+pub struct GetValidatorRewardsResponse {
+    pub data: Vec<Coin>,
+    /// Nonce the next withdrawal must be signed with
+    pub nonce: u64,
+}
+
+#[cw_serde]
+pub struct GetRewardDustResponse {
+    pub data: Vec<Coin>,
+}
+
+#[cw_serde]
+pub struct GetPrepaidLockFeesResponse {
+    pub data: Vec<Coin>,
+}
+
 #[cw_serde]
 pub struct GetCollectionDeployerResponse {
     pub data: Addr,
@@ -85,3 +246,103 @@ pub struct GetOriginalToDuplicateResponse {
 pub struct GetDuplicateToOriginalResponse {
     pub data: Option<DuplicateToOriginalContractInfo>,
 }
+
+#[cw_serde]
+pub struct ValidatorEntry {
+    pub public_key: Binary,
+    pub validator: Validator,
+}
+
+#[cw_serde]
+pub struct ListValidatorsResponse {
+    pub data: Vec<ValidatorEntry>,
+}
+
+#[cw_serde]
+pub struct GetRetiredValidatorResponse {
+    pub data: Option<Binary>,
+}
+
+#[cw_serde]
+pub struct ListBlacklistedResponse {
+    pub data: Vec<Binary>,
+}
+
+/// `contract_address` on `chain` maps to `mapped_contract_address` on `mapped_chain`
+#[cw_serde]
+pub struct MappingEntry {
+    pub contract_address: String,
+    pub chain: String,
+    pub mapped_contract_address: String,
+    pub mapped_chain: String,
+}
+
+#[cw_serde]
+pub struct ListMappingsResponse {
+    pub data: Vec<MappingEntry>,
+}
+
+#[cw_serde]
+pub struct StorageEntry {
+    pub contract_address: String,
+    pub chain: String,
+    pub storage_address: Addr,
+}
+
+#[cw_serde]
+pub struct ListStoragesResponse {
+    pub data: Vec<StorageEntry>,
+}
+
+#[cw_serde]
+pub struct LockedTokenEntry {
+    pub contract_address: String,
+    pub token_id: String,
+    pub storage_address: Addr,
+    pub amount: u128,
+}
+
+#[cw_serde]
+pub struct ListLockedTokensResponse {
+    pub data: Vec<LockedTokenEntry>,
+}
+
+#[cw_serde]
+pub struct GetClaimStatusResponse {
+    pub data: Vec<ClaimRecord>,
+}
+
+#[cw_serde]
+pub struct ListClaimsResponse {
+    pub data: Vec<ClaimRecord>,
+}
+
+#[cw_serde]
+pub struct GetChildCodeUpgradeNonceResponse {
+    /// Nonce the next child code upgrade must be signed with
+    pub nonce: u64,
+}
+
+#[cw_serde]
+pub struct SupportedChainEntry {
+    pub chain: String,
+    pub config: SupportedChain,
+}
+
+#[cw_serde]
+pub struct ListSupportedChainsResponse {
+    pub data: Vec<SupportedChainEntry>,
+    /// Nonce the next supported chain update must be signed with
+    pub nonce: u64,
+}
+
+#[cw_serde]
+pub struct ComputeClaimHashResponse {
+    /// Canonical encoding of the claim data, the signing input
+    pub encoding: Binary,
+    /// sha256 of `encoding`
+    pub hash: Binary,
+    pub legacy_hash: Binary,
+    /// Whether signatures over `legacy_hash` are still accepted
+    pub legacy_accepted: bool,
+}
@@ -1,15 +1,24 @@
-use cosmwasm_std::Addr;
+use cosmwasm_std::{Addr, Binary, Uint128};
 // use cosmwasm_storage::{singleton, singleton_read, ReadonlySingleton, Singleton};
 use cw_storage_plus::{Item, Map};
 
 use crate::structs::{
-    DuplicateToOriginalContractInfo, OriginalToDuplicateContractInfo, State, Validator,
+    ClaimData, ClaimRecord, Custody721, DuplicateToOriginalContractInfo,
+    OriginalToDuplicateContractInfo, State, SupportedChain, Validator,
 };
 
 pub static CONFIG_KEY: &[u8] = b"config";
 
+pub const CONTRACT_NAME: &str = "crates.io:xp-bridge";
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
 pub const BLOCK_SIZE: usize = 256;
 
+pub const DEFAULT_QUERY_LIMIT: u32 = 10;
+pub const MAX_QUERY_LIMIT: u32 = 30;
+
+pub const DEFAULT_MAX_BATCH_SIZE: u32 = 25;
+
 pub const STORAGE_DEPLOYER_721_REPLY_ID: u64 = 1;
 pub const STORAGE_DEPLOYER_1155_REPLY_ID: u64 = 2;
 
@@ -22,8 +31,40 @@ pub const COLLECTION_DEPLOYER_1155_REPLY_ID: u64 = 4;
 pub static VALIDATORS_STORAGE: Map<Vec<u8>, Validator> = Map::new("v_s");
 pub static BLACKLISTED_VALIDATORS: Map<Vec<u8>, bool> = Map::new("bvs");
 
+/// retired validator public key -> key it was rotated to. Retired keys can't
+/// be added again.
+pub static RETIRED_VALIDATORS: Map<Vec<u8>, Binary> = Map::new("rvs");
+
+/// (validator public key, denom) -> rewards the validator can withdraw
+pub static VALIDATOR_REWARDS: Map<(Vec<u8>, String), Uint128> = Map::new("vr_s");
+
+/// (depositor, denom) -> lock fees paid ahead, charged for tokens locked
+/// through cw721 `SendNft`, which carries no funds
+pub static PREPAID_LOCK_FEES: Map<(Addr, String), Uint128> = Map::new("plf_s");
+
+/// validator public key -> number of reward withdrawals made so far
+pub static REWARD_NONCES: Map<Vec<u8>, u64> = Map::new("rn_s");
+
+/// denom -> remainder of splitting fees between signers, added to the next
+/// fee split in that denom
+pub static REWARD_DUST: Map<String, Uint128> = Map::new("rd_s");
+
 pub static UNIQUE_IDENTIFIER_STORAGE: Map<[u8; 32], bool> = Map::new("ui_s");
 
+/// Number of child code upgrades applied so far
+pub const CHILD_CODE_UPGRADE_NONCE: Item<u64> = Item::new("ccu_n");
+
+pub static SUPPORTED_CHAINS: Map<String, SupportedChain> = Map::new("sc_s");
+
+/// Number of supported chain updates applied so far
+pub const SUPPORTED_CHAINS_NONCE: Item<u64> = Item::new("sc_n");
+
+/// (lock_tx_chain, transaction_hash, (source_nft_contract_address, token_id)),
+/// a lock transaction can carry several tokens
+pub type ClaimRecordKey = (String, String, (String, String));
+
+pub static CLAIM_RECORDS: Map<ClaimRecordKey, ClaimRecord> = Map::new("cr_s");
+
 pub static ORIGINAL_TO_DUPLICATE_STORAGE: Map<(String, String), OriginalToDuplicateContractInfo> =
     Map::new("otdm_s");
 
@@ -34,8 +75,26 @@ pub static ORIGINAL_STORAGE_721: Map<(String, String), Addr> = Map::new("o721_s"
 
 pub static DUPLICATE_STORAGE_721: Map<(String, String), Addr> = Map::new("d721_s");
 
+pub static ORIGINAL_STORAGE_1155: Map<(String, String), Addr> = Map::new("o1155_s");
+
+pub static DUPLICATE_STORAGE_1155: Map<(String, String), Addr> = Map::new("d1155_s");
+
 pub static NFT_COLLECTION_OWNER: Map<(String, String), (Addr, u128)> = Map::new("nco");
 
+/// Duplicate collections deployed to burn wrapped tokens leaving through the
+/// bridge, instead of holding them in a storage
+pub static BURN_ON_RETURN_721: Map<Addr, bool> = Map::new("bor_721");
+
+/// collection -> tokens locked while its storage is being deployed, within the
+/// same transaction. The storage reply moves them and clears the entry.
+pub static PENDING_STORAGE_LOCKS_721: Map<String, Vec<(String, Custody721)>> = Map::new("psl_721");
+
+/// (source_nft_contract_address, source_chain) -> claims waiting on the duplicate
+/// collection being deployed in the same transaction. The collection reply mints
+/// them and clears the entry.
+pub static PENDING_COLLECTION_CLAIMS_721: Map<(String, String), Vec<ClaimData>> =
+    Map::new("pcc_721");
+
 pub const COLLETION_DEPLOYER_CODE: Item<u64> = Item::new("cd_v");
 
 pub const STORAGE_DEPLOYER_CODE: Item<u64> = Item::new("sd_v");
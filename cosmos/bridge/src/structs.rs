@@ -1,13 +1,17 @@
 // In general, data that is stored for user display may be different from the data used
 // for internal functions of the smart contract. That is why we have StoreOffspringInfo.
 
-use cosmwasm_schema::{cw_serde, schemars::Map};
-use cosmwasm_std::{Addr, Binary};
+use cosmwasm_schema::{cw_serde, schemars::Map, serde::Serialize};
+use cosmwasm_std::{to_json_vec, Addr, Binary, StdResult, Timestamp, Uint128};
+
+use crate::state::DEFAULT_MAX_BATCH_SIZE;
 
 #[cw_serde]
 pub struct Validator {
     pub address: Addr,
     pub added: bool,
+    /// Rewards accrued in [State::fee_denom] before they were accounted per
+    /// denom, moved to [crate::state::VALIDATOR_REWARDS] on migration
     pub pending_reward: u128,
 }
 #[cw_serde]
@@ -23,6 +27,22 @@ pub struct OriginalToDuplicateContractInfo {
     pub contract_address: Addr,
 }
 
+/// Outcome of a claim, keyed by the lock transaction it came from and the
+/// token it locked
+#[cw_serde]
+pub struct ClaimRecord {
+    pub lock_tx_chain: String,
+    pub transaction_hash: String,
+    pub source_nft_contract_address: String,
+    pub claim_hash: Binary,
+    pub height: u64,
+    pub recipient: Addr,
+    /// Collection the token was minted on or unlocked from, `None` while
+    /// the duplicate collection is still being deployed
+    pub contract: Option<Addr>,
+    pub token_id: String,
+}
+
 #[cw_serde]
 pub struct State {
     pub collection_deployer: Addr,
@@ -31,7 +51,229 @@ pub struct State {
     pub self_chain: String,
     pub type_erc_721: String,
     pub type_erc_1155: String,
+    /// Native denom the claim fees are paid and validators rewarded in
+    #[serde(default = "default_fee_denom")]
+    pub fee_denom: String,
+    /// Last block height at which claims signed over the legacy
+    /// [ClaimData::concat_all_fields] hash are still accepted
+    pub legacy_claim_hash_until: Option<u64>,
+    #[serde(default)]
+    pub pause: PauseMatrix,
+    /// Most items a single batch lock or claim may carry
+    #[serde(default = "default_max_batch_size")]
+    pub max_batch_size: u32,
+    /// Duplicate 721 collections deployed from now on burn wrapped tokens
+    /// leaving through the bridge, and mint them again when they come back
+    #[serde(default)]
+    pub burn_on_return: bool,
+    #[serde(default)]
+    pub threshold: ThresholdPolicy,
+    /// Number of threshold policy updates applied so far
+    #[serde(default)]
+    pub threshold_nonce: u64,
+}
+
+/// Bridges deployed before the denom was configurable charged in uscrt
+fn default_fee_denom() -> String {
+    "uscrt".to_string()
+}
+
+fn default_max_batch_size() -> u32 {
+    DEFAULT_MAX_BATCH_SIZE
+}
+
+/// How many validator signatures governance messages and claims need: more
+/// than `numerator / denominator` of the validators, and at least `minimum`
+#[cw_serde]
+pub struct ThresholdPolicy {
+    pub numerator: u64,
+    pub denominator: u64,
+    pub minimum: u64,
+}
+
+impl Default for ThresholdPolicy {
+    /// 2/3 + 1
+    fn default() -> Self {
+        Self {
+            numerator: 2,
+            denominator: 3,
+            minimum: 1,
+        }
+    }
+}
+
+impl ThresholdPolicy {
+    pub fn is_valid(&self) -> bool {
+        self.denominator > 0 && self.numerator < self.denominator
+    }
+
+    /// Never more than `validators_count`, so blacklisting validators below
+    /// [ThresholdPolicy::minimum] can't leave the bridge without enough signers.
+    pub fn required(&self, validators_count: i128) -> i128 {
+        let share = validators_count.max(0) as u128 * self.numerator as u128
+            / self.denominator.max(1) as u128;
+        (share as i128 + 1)
+            .max(self.minimum as i128)
+            .min(validators_count.max(1))
+    }
+}
+
+/// Validators sign the sha256 of its [GovernanceMessage::governance_encoding].
+/// `nonce` must match [State::threshold_nonce], so older updates can't be replayed.
+#[cw_serde]
+pub struct ThresholdPolicyUpdate {
+    pub policy: ThresholdPolicy,
+    pub nonce: u64,
+}
+
+#[cw_serde]
+pub struct SetThresholdPolicyMsg {
+    pub update: ThresholdPolicyUpdate,
+    pub signatures: Vec<SignerAndSignature>,
+}
+
+/// What is currently stopped on the bridge
+#[cw_serde]
+#[derive(Default)]
+pub struct PauseMatrix {
+    pub locks: bool,
+    pub claims: bool,
+    /// Locks towards these chains are rejected
+    pub disabled_destination_chains: Vec<String>,
+    /// Claims of NFTs originating from, or locked on, these chains are rejected
+    pub disabled_source_chains: Vec<String>,
+    /// Number of pause updates applied so far
+    pub nonce: u64,
+}
+
+/// Validators sign the sha256 of its [GovernanceMessage::governance_encoding].
+/// `nonce` must match [PauseMatrix::nonce], so older updates can't be replayed.
+#[cw_serde]
+pub struct PauseUpdate {
+    pub locks: bool,
+    pub claims: bool,
+    pub disabled_destination_chains: Vec<String>,
+    pub disabled_source_chains: Vec<String>,
+    pub nonce: u64,
+}
+
+#[cw_serde]
+pub struct SetPausedMsg {
+    pub update: PauseUpdate,
+    pub signatures: Vec<SignerAndSignature>,
+}
+
+/// Shape of user addresses on a destination chain
+#[cw_serde]
+pub enum AddressFormat {
+    /// `0x` followed by 20 hex encoded bytes
+    Hex20,
+    /// bech32 with the given human readable part
+    Bech32 { prefix: String },
+    /// base58 encoded 32 byte public key
+    Base58,
+    /// NEAR named or implicit account id
+    NearAccountId,
+}
+
+const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+impl AddressFormat {
+    pub fn is_valid(&self, address: &str) -> bool {
+        match self {
+            AddressFormat::Hex20 => address
+                .strip_prefix("0x")
+                .is_some_and(|hex| hex.len() == 40 && hex.chars().all(|c| c.is_ascii_hexdigit())),
+            AddressFormat::Bech32 { prefix } => {
+                bech32::decode(address).is_ok_and(|(hrp, _, variant)| {
+                    &hrp == prefix && variant == bech32::Variant::Bech32
+                })
+            }
+            AddressFormat::Base58 => {
+                (32..=44).contains(&address.len())
+                    && address.chars().all(|c| BASE58_ALPHABET.contains(c))
+            }
+            AddressFormat::NearAccountId => is_near_account_id(address),
+        }
+    }
+}
+
+/// Lowercase alphanumeric parts separated by single `-`, `_` or `.`
+fn is_near_account_id(address: &str) -> bool {
+    if !(2..=64).contains(&address.len()) {
+        return false;
+    }
+    let mut last_was_separator = true;
+    for c in address.chars() {
+        match c {
+            'a'..='z' | '0'..='9' => last_was_separator = false,
+            '-' | '_' | '.' if !last_was_separator => last_was_separator = true,
+            _ => return false,
+        }
+    }
+    !last_was_separator
+}
+
+#[cw_serde]
+pub struct SupportedChain {
+    pub address_format: AddressFormat,
+    /// Charged on locks towards this chain, in [State::fee_denom]
+    pub lock_fee: Option<Uint128>,
+    pub enabled: bool,
+}
+
+/// Validators sign the sha256 of its [GovernanceMessage::governance_encoding].
+/// `nonce` must match the number of chain updates applied so far.
+#[cw_serde]
+pub struct SupportedChainUpdate {
+    pub chain: String,
+    pub config: SupportedChain,
+    pub nonce: u64,
+}
+
+#[cw_serde]
+pub struct SetSupportedChainMsg {
+    pub update: SupportedChainUpdate,
+    pub signatures: Vec<SignerAndSignature>,
+}
+#[cw_serde]
+pub struct BridgeMigrateMsg {
+    /// Replaces the legacy claim hash transition window when set
+    pub legacy_claim_hash_until: Option<u64>,
+    /// Replaces the maximum batch size when set
+    pub max_batch_size: Option<u32>,
+    /// Replaces [State::burn_on_return] when set
+    pub burn_on_return: Option<bool>,
+    /// Replaces [State::fee_denom] when set, before legacy rewards are moved
+    /// to it
+    pub fee_denom: Option<String>,
+}
+
+/// Child contracts the deployers instantiate
+#[cw_serde]
+pub enum ChildContract {
+    Collection721,
+    Storage721,
+    Collection1155,
+    Storage1155,
+}
+
+/// Validators sign the sha256 of its [GovernanceMessage::governance_encoding].
+/// `nonce` must match the number of upgrades applied so far, so older upgrades
+/// can't be replayed while rolling back to an earlier code id stays possible.
+#[cw_serde]
+pub struct ChildCodeUpgrade {
+    pub child: ChildContract,
+    pub code_id: u64,
+    pub nonce: u64,
+}
+
+#[cw_serde]
+pub struct UpgradeChildCodeMsg {
+    pub upgrade: ChildCodeUpgrade,
+    pub signatures: Vec<SignerAndSignature>,
 }
+
 #[cw_serde]
 pub struct SignerAndSignature {
     pub signer_address: Binary,
@@ -42,29 +284,84 @@ pub struct SignerAndSignature {
 pub struct BridgeInstantiateMsg {
     pub validators: Vec<(Binary, Addr)>,
     pub chain_type: String,
+    pub fee_denom: String,
+    pub legacy_claim_hash_until: Option<u64>,
+    pub supported_chains: Vec<(String, SupportedChain)>,
+    /// Defaults to [DEFAULT_MAX_BATCH_SIZE]
+    pub max_batch_size: Option<u32>,
+    /// See [State::burn_on_return]
+    pub burn_on_return: bool,
+    /// Defaults to 2/3 + 1 of the validators
+    pub threshold: Option<ThresholdPolicy>,
     // pub collection_deployer: Addr,
     // pub storage_deployer: Addr,
     pub storage_label: String,
     pub collection_label: String,
     pub collection721_code_id: u64,
     pub storage721_code_id: u64,
+    pub collection1155_code_id: u64,
+    pub storage1155_code_id: u64,
     pub collection_deployer_code_id: u64,
     pub storage_deployer_code_id: u64,
 }
+/// Validators sign a [ValidatorAddition] of `validator`
 #[cw_serde]
 pub struct AddValidatorMsg {
     pub validator: (Binary, Addr),
     pub signatures: Vec<SignerAndSignature>,
 }
 
+/// Validators sign the sha256 of its [GovernanceMessage::governance_encoding]
+#[cw_serde]
+pub struct ValidatorAddition {
+    pub public_key: Binary,
+    pub address: Addr,
+}
+
+/// Validators sign the sha256 of its [GovernanceMessage::governance_encoding],
+/// `old` among them
+#[cw_serde]
+pub struct KeyRotation {
+    pub old: Binary,
+    pub new: Binary,
+}
+
+#[cw_serde]
+pub struct RotateValidatorKeyMsg {
+    pub rotation: KeyRotation,
+    pub signatures: Vec<SignerAndSignature>,
+}
+
+/// Validators sign a [ValidatorBlacklisting] of the key of `validator`
 #[cw_serde]
 pub struct BlacklistValidatorMsg {
     pub validator: (Binary, Addr),
     pub signatures: Vec<SignerAndSignature>,
 }
+
+/// Validators sign the sha256 of its [GovernanceMessage::governance_encoding]
+#[cw_serde]
+pub struct ValidatorBlacklisting {
+    pub public_key: Binary,
+}
+
+/// The validator signs the sha256 of its
+/// [GovernanceMessage::governance_encoding] with its registered key. `nonce` must match the validator's withdrawal nonce,
+/// so older withdrawals can't be replayed.
+#[cw_serde]
+pub struct RewardWithdrawal {
+    pub denom: String,
+    /// Everything pending in `denom` when `None`
+    pub amount: Option<Uint128>,
+    pub payout_address: String,
+    pub nonce: u64,
+}
+
 #[cw_serde]
 pub struct ClaimValidatorRewardsMsg {
     pub validator: Binary,
+    pub withdrawal: RewardWithdrawal,
+    pub signature: Binary,
 }
 
 #[cw_serde]
@@ -92,6 +389,32 @@ pub struct ClaimMsg {
     pub signatures: Vec<SignerAndSignature>,
 }
 
+/// How a 721 token came into its storage, recorded there next to the token
+#[cw_serde]
+pub struct Custody721 {
+    pub locker: Addr,
+    pub destination_chain: String,
+    pub destination_user_address: String,
+}
+
+/// Validators sign the sha256 of its [GovernanceMessage::governance_encoding],
+/// once the lock is known to never have been claimed on the destination. `locker` and
+/// `locked_at` must match the custody record of the storage, so a signed return
+/// only ever applies to that one lock.
+#[cw_serde]
+pub struct EmergencyReturnData {
+    pub source_nft_contract_address: String,
+    pub token_id: String,
+    pub locker: Addr,
+    pub locked_at: Timestamp,
+}
+
+#[cw_serde]
+pub struct EmergencyReturnMsg {
+    pub data: EmergencyReturnData,
+    pub signatures: Vec<SignerAndSignature>,
+}
+
 #[cw_serde]
 pub struct VerifyMsg {
     pub user: Binary,
@@ -123,10 +446,105 @@ pub struct ClaimData {
     pub token_amount: u128,
     pub nft_type: String,
     pub fee: u128,
-    pub lock_tx_chain: String
+    pub lock_tx_chain: String,
+}
+
+/// Domain separator prefixed to every canonical claim encoding
+pub const CLAIM_DATA_DOMAIN: &str = "xp-bridge:claim-data";
+pub const CLAIM_DATA_ENCODING_VERSION: u8 = 1;
+
+fn push_length_prefixed(out: &mut Vec<u8>, bytes: &[u8]) {
+    out.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+    out.extend_from_slice(bytes);
+}
+
+/// Messages the validators sign to govern the bridge
+pub trait GovernanceMessage: Serialize {
+    /// Tells the kinds of signed messages apart
+    const DOMAIN: &'static str;
+
+    /// Validators sign the sha256 of this encoding: the length-prefixed
+    /// domain, the length-prefixed bridge address and self chain, then the
+    /// JSON encoding of the message. A signature only applies to one kind of
+    /// message on one bridge.
+    fn governance_encoding(&self, bridge: &str, self_chain: &str) -> StdResult<Vec<u8>> {
+        let mut out = Vec::new();
+        push_length_prefixed(&mut out, Self::DOMAIN.as_bytes());
+        push_length_prefixed(&mut out, bridge.as_bytes());
+        push_length_prefixed(&mut out, self_chain.as_bytes());
+        out.extend(to_json_vec(self)?);
+        Ok(out)
+    }
+}
+
+impl GovernanceMessage for ValidatorAddition {
+    const DOMAIN: &'static str = "xp-bridge:add-validator";
+}
+
+impl GovernanceMessage for ValidatorBlacklisting {
+    const DOMAIN: &'static str = "xp-bridge:blacklist-validator";
+}
+
+impl GovernanceMessage for ThresholdPolicyUpdate {
+    const DOMAIN: &'static str = "xp-bridge:threshold-policy";
+}
+
+impl GovernanceMessage for PauseUpdate {
+    const DOMAIN: &'static str = "xp-bridge:pause";
+}
+
+impl GovernanceMessage for SupportedChainUpdate {
+    const DOMAIN: &'static str = "xp-bridge:supported-chain";
+}
+
+impl GovernanceMessage for ChildCodeUpgrade {
+    const DOMAIN: &'static str = "xp-bridge:child-code-upgrade";
+}
+
+impl GovernanceMessage for KeyRotation {
+    const DOMAIN: &'static str = "xp-bridge:key-rotation";
+}
+
+impl GovernanceMessage for RewardWithdrawal {
+    const DOMAIN: &'static str = "xp-bridge:reward-withdrawal";
+}
+
+impl GovernanceMessage for EmergencyReturnData {
+    const DOMAIN: &'static str = "xp-bridge:emergency-return";
 }
 
 impl ClaimData {
+    /// Validators sign the sha256 of this encoding: the length-prefixed
+    /// domain, the encoding version, the length-prefixed bridge address and
+    /// self chain, then every field in declaration order. Strings are
+    /// prefixed with their u32 big-endian length, integers are fixed-width
+    /// big-endian.
+    pub fn canonical_encoding(&self, bridge: &str, self_chain: &str) -> Vec<u8> {
+        let mut out = Vec::new();
+        push_length_prefixed(&mut out, CLAIM_DATA_DOMAIN.as_bytes());
+        out.push(CLAIM_DATA_ENCODING_VERSION);
+        push_length_prefixed(&mut out, bridge.as_bytes());
+        push_length_prefixed(&mut out, self_chain.as_bytes());
+
+        push_length_prefixed(&mut out, self.token_id.as_bytes());
+        push_length_prefixed(&mut out, self.source_chain.as_bytes());
+        push_length_prefixed(&mut out, self.destination_chain.as_bytes());
+        push_length_prefixed(&mut out, self.destination_user_address.as_bytes());
+        push_length_prefixed(&mut out, self.source_nft_contract_address.as_bytes());
+        push_length_prefixed(&mut out, self.name.as_bytes());
+        push_length_prefixed(&mut out, self.symbol.as_bytes());
+        out.extend_from_slice(&self.royalty.to_be_bytes());
+        push_length_prefixed(&mut out, self.royalty_receiver.as_bytes());
+        push_length_prefixed(&mut out, self.metadata.as_bytes());
+        push_length_prefixed(&mut out, self.transaction_hash.as_bytes());
+        out.extend_from_slice(&self.token_amount.to_be_bytes());
+        push_length_prefixed(&mut out, self.nft_type.as_bytes());
+        out.extend_from_slice(&self.fee.to_be_bytes());
+        push_length_prefixed(&mut out, self.lock_tx_chain.as_bytes());
+        out
+    }
+
+    /// Legacy signing input, ambiguous since fields are not delimited
     pub fn concat_all_fields(&self) -> String {
         format!(
             "{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}",
@@ -179,6 +597,17 @@ pub struct ReplyStorageInfo {
     pub token_amount: u128,
 }
 
+#[cw_serde]
+pub struct ReplyStorage1155Info {
+    /// label used when initializing offspring
+    pub label: String,
+    pub address: Addr,
+    pub is_original: bool,
+    pub token_id: String,
+    pub token_amount: u128,
+    pub from: Addr,
+}
+
 #[cw_serde]
 pub struct ReplyStorageDeployerInfo {
     pub address: Addr,
@@ -199,7 +628,9 @@ pub struct ReplyCollectionInfo {
     pub royalty_receiver: Addr,
     pub metadata: String,
     pub transaction_hash: String,
-    pub lock_tx_chain: String
+    pub lock_tx_chain: String,
+    #[serde(default)]
+    pub burn_on_return: bool,
 }
 
 #[cw_serde]
@@ -7,8 +7,13 @@ mod tests {
     };
 
     use cosm_nft::{init::InstantiateMsg, royalty::RoyaltyData};
-    use cosmwasm_std::{from_json, Addr, Binary, Coin};
-    use cw_multi_test::{App, ContractWrapper, Executor};
+    use cosm_nft1155::msg::{Nft1155ExecuteMsg, Nft1155QueryMsg};
+    use cosmwasm_std::{
+        from_json, to_json_binary, Addr, BankMsg, Binary, Coin, Deps, DepsMut, Empty, Env,
+        MessageInfo, Response, StdResult, SubMsg, Uint128,
+    };
+    use cw1155::BalanceResponse;
+    use cw_multi_test::{AddressGenerator, App, AppBuilder, ContractWrapper, Executor, WasmKeeper};
     use nft_store::{
         error::StorageContractError,
         msg::{NftStoreExecuteMsg, NftStoreInstantiateMsg, NftStoreQueryMsg},
@@ -17,11 +22,58 @@ mod tests {
     use store_deployer::msg::{StoreFactoryExecuteMsg, StoreFactoryInstantiateMsg};
 
     use crate::{
-        events::ClaimedEventInfo,
-        msg::{BridgeExecuteMsg, GetValidatorCountResponse},
-        structs::{ClaimData, ClaimMsg, Lock721Msg, SignerAndSignature},
+        error::ContractError,
+        events::{
+            BlacklistValidatorEventInfo, Claimed1155EventInfo, ClaimedEventInfo, PausedEventInfo,
+            SkippedSignersEventInfo, UnpausedEventInfo, ValidatorKeyRotatedEventInfo,
+        },
+        msg::{
+            BridgeExecuteMsg, BridgeQueryMsg, ComputeClaimHashResponse,
+            GetChildCodeUpgradeNonceResponse, GetClaimStatusResponse,
+            GetOriginalToDuplicateResponse, GetPrepaidLockFeesResponse,
+            GetRetiredValidatorResponse, GetRewardDustResponse, GetStorageResponse,
+            GetValidatorCountResponse, GetValidatorResponse, GetValidatorRewardsResponse,
+            ListBlacklistedResponse, ListClaimsResponse, ListMappingsResponse,
+            ListStoragesResponse, ListSupportedChainsResponse, ListValidatorsResponse, MappingKind,
+            StorageKind,
+        },
+        state::CONTRACT_NAME,
+        structs::{
+            AddValidatorMsg, AddressFormat, BlacklistValidatorMsg, BridgeMigrateMsg,
+            ChildCodeUpgrade, ChildContract, ClaimData, ClaimMsg, ClaimValidatorRewardsMsg,
+            EmergencyReturnData, EmergencyReturnMsg, GovernanceMessage, KeyRotation, Lock1155Msg,
+            Lock721Msg, PauseMatrix, PauseUpdate, RewardWithdrawal, RotateValidatorKeyMsg,
+            SetPausedMsg, SetSupportedChainMsg, SetThresholdPolicyMsg, SignerAndSignature, State,
+            SupportedChain, SupportedChainUpdate, ThresholdPolicy, ThresholdPolicyUpdate,
+            UpgradeChildCodeMsg, ValidatorAddition, ValidatorBlacklisting, VerifyMsg,
+        },
     };
 
+    /// Gives instantiate2 contracts the same `contract{n}` addresses as plain
+    /// ones, the mock api can't humanize real instantiate2 addresses
+    struct InstanceAddressGenerator;
+
+    impl AddressGenerator for InstanceAddressGenerator {
+        fn predictable_contract_address(
+            &self,
+            api: &dyn cosmwasm_std::Api,
+            storage: &mut dyn cosmwasm_std::Storage,
+            code_id: u64,
+            instance_id: u64,
+            _checksum: &[u8],
+            _creator: &cosmwasm_std::CanonicalAddr,
+            _salt: &[u8],
+        ) -> anyhow::Result<Addr> {
+            self.contract_address(api, storage, code_id, instance_id)
+        }
+    }
+
+    fn mock_app() -> App {
+        AppBuilder::new()
+            .with_wasm(WasmKeeper::new().with_address_generator(InstanceAddressGenerator))
+            .build(|_, _, _| {})
+    }
+
     fn collection_code_wrapper() -> ContractWrapper<
         cosm_nft::NftExecuteMsg,
         InstantiateMsg,
@@ -52,10 +104,40 @@ mod tests {
         )
     }
 
+    fn collection_1155_code_wrapper() -> ContractWrapper<
+        Nft1155ExecuteMsg,
+        cosm_nft1155::init::InstantiateMsg,
+        Nft1155QueryMsg,
+        cosm_nft1155::error::ContractError,
+        cosm_nft1155::error::ContractError,
+        cosmwasm_std::StdError,
+    > {
+        ContractWrapper::new(
+            cosm_nft1155::entry::execute,
+            cosm_nft1155::entry::instantiate,
+            cosm_nft1155::entry::query,
+        )
+    }
+
+    fn store_1155_code_wrapper() -> ContractWrapper<
+        nft_store1155::msg::NftStoreExecuteMsg,
+        nft_store1155::msg::NftStoreInstantiateMsg,
+        nft_store1155::msg::NftStoreQueryMsg,
+        nft_store1155::error::StorageContractError,
+        nft_store1155::error::StorageContractError,
+        nft_store1155::error::StorageContractError,
+    > {
+        ContractWrapper::new(
+            nft_store1155::entry::execute,
+            nft_store1155::entry::instantiate,
+            nft_store1155::entry::query,
+        )
+    }
+
     fn storage_factory_contracts_wrapper() -> ContractWrapper<
         StoreFactoryExecuteMsg,
         StoreFactoryInstantiateMsg,
-        cosmwasm_std::Empty,
+        store_deployer::msg::StoreFactoryQueryMsg,
         store_deployer::error::StorageFactoryContractError,
         store_deployer::error::StorageFactoryContractError,
         store_deployer::error::StorageFactoryContractError,
@@ -76,7 +158,7 @@ mod tests {
     fn collection_factory_contracts_wrapper() -> ContractWrapper<
         CollectionDeployerExecuteMsg,
         CollectionDeployerInstantiateMsg,
-        cosmwasm_std::Empty,
+        collection_deployer::msg::CollectionDeployerQueryMsg,
         CollectionFactoryContractError,
         CollectionFactoryContractError,
         CollectionFactoryContractError,
@@ -91,9 +173,7 @@ mod tests {
             collection_deployer::entry::instantiate,
             collection_deployer::entry::query,
         );
-        let collection_factory_code =
-            collection_factory_code.with_reply(collection_deployer::entry::reply);
-        collection_factory_code
+        collection_factory_code.with_reply(collection_deployer::entry::reply)
     }
 
     fn save_factory_contracts(app: &mut App) -> (u64, u64) {
@@ -103,23 +183,44 @@ mod tests {
         );
         let sfc_id = app.store_code(Box::new(sfc));
         let cfc_id = app.store_code(Box::new(cfc));
-        return (sfc_id, cfc_id);
+        (sfc_id, cfc_id)
     }
 
     fn save_collection_and_store_contracts(app: &mut App) -> (u64, u64) {
         let collection_code_id = app.store_code(Box::new(collection_code_wrapper()));
         let store_code_id = app.store_code(Box::new(store_code_wrapper()));
-        return (collection_code_id, store_code_id);
+        (collection_code_id, store_code_id)
+    }
+
+    fn save_collection_and_store_1155_contracts(app: &mut App) -> (u64, u64) {
+        let collection_code_id = app.store_code(Box::new(collection_1155_code_wrapper()));
+        let store_code_id = app.store_code(Box::new(store_1155_code_wrapper()));
+        (collection_code_id, store_code_id)
+    }
+
+    const BSC_USER: &str = "0x5B38Da6a701c568545dCfcB03FcB875f56beddC4";
+
+    fn supported_chains() -> Vec<(String, SupportedChain)> {
+        vec![(
+            "BSC".to_string(),
+            SupportedChain {
+                address_format: AddressFormat::Hex20,
+                lock_fee: None,
+                enabled: true,
+            },
+        )]
     }
 
     #[test]
     fn initializes_correctly() {
-        let mut app = App::default();
+        let mut app = mock_app();
         let deployer = Addr::unchecked("deployer");
 
         let (sfc_id, cfc_id) = save_factory_contracts(&mut app);
         let (collection_code_id, storage721_code_id) =
             save_collection_and_store_contracts(&mut app);
+        let (collection1155_code_id, storage1155_code_id) =
+            save_collection_and_store_1155_contracts(&mut app);
 
         let bridge_contract_wrapper = ContractWrapper::new(
             crate::contract::execute,
@@ -133,10 +234,18 @@ mod tests {
         let msg = crate::structs::BridgeInstantiateMsg {
             validators: vec![],
             chain_type: "cosmos".to_string(),
+            fee_denom: "uscrt".to_string(),
+            legacy_claim_hash_until: None,
+            supported_chains: supported_chains(),
+            max_batch_size: None,
+            burn_on_return: false,
+            threshold: None,
             storage_label: "xp-nft-store-factory".to_string(),
             collection_label: "xp-nft-collection-factory".to_string(),
             collection721_code_id: collection_code_id,
             storage721_code_id,
+            collection1155_code_id,
+            storage1155_code_id,
             collection_deployer_code_id: cfc_id,
             storage_deployer_code_id: sfc_id,
         };
@@ -162,13 +271,15 @@ mod tests {
 
     #[test]
     fn test_lock_nft() {
-        let mut app = App::default();
+        let mut app = mock_app();
         let deployer = Addr::unchecked("deployer");
         let nft_minter = Addr::unchecked("nft_minter");
 
         let (sfc_id, cfc_id) = save_factory_contracts(&mut app);
         let (collection_code_id, storage721_code_id) =
             save_collection_and_store_contracts(&mut app);
+        let (collection1155_code_id, storage1155_code_id) =
+            save_collection_and_store_1155_contracts(&mut app);
 
         let bridge_contract_wrapper = ContractWrapper::new(
             crate::contract::execute,
@@ -182,10 +293,18 @@ mod tests {
         let msg = crate::structs::BridgeInstantiateMsg {
             validators: vec![],
             chain_type: "cosmos".to_string(),
+            fee_denom: "uscrt".to_string(),
+            legacy_claim_hash_until: None,
+            supported_chains: supported_chains(),
+            max_batch_size: None,
+            burn_on_return: false,
+            threshold: None,
             storage_label: "xp-nft-store-factory".to_string(),
             collection_label: "xp-nft-collection-factory".to_string(),
             collection721_code_id: collection_code_id,
             storage721_code_id,
+            collection1155_code_id,
+            storage1155_code_id,
             collection_deployer_code_id: cfc_id,
             storage_deployer_code_id: sfc_id,
         };
@@ -217,7 +336,8 @@ mod tests {
                 royalty_receiver: Addr::unchecked("rr"),
                 token_amount: Default::default(),
                 transaction_hash: "tx_hash".to_string(),
-                lock_tx_chain: "BRUH".to_string()
+                lock_tx_chain: "BRUH".to_string(),
+                burn_on_return: false,
             },
             &[],
             "test-nft-contract",
@@ -252,13 +372,13 @@ mod tests {
         assert!(approve.is_ok(), "approve failed: {:?}", approve);
         assert!(mint.is_ok(), "mint failed: {:?}", mint);
         let lock = app.execute_contract(
-            deployer.clone(),
+            nft_minter.clone(),
             bridge_addr,
             &crate::msg::BridgeExecuteMsg::Lock721 {
                 data: Lock721Msg {
                     collection_code_id,
                     destination_chain: "BSC".to_string(),
-                    destination_user_address: "0xabc123".to_string(),
+                    destination_user_address: BSC_USER.to_string(),
                     source_nft_contract_address: nft_contract.to_string(),
                     token_id: "1".to_string(),
                 },
@@ -272,13 +392,14 @@ mod tests {
     fn test_claim_nft() {
         use secp256k1::rand::rngs::OsRng;
         use secp256k1::Message;
-        use sha2::Digest;
-        let mut app = App::default();
+        let mut app = mock_app();
         let deployer = Addr::unchecked("deployer");
 
         let (sfc_id, cfc_id) = save_factory_contracts(&mut app);
         let (collection_code_id, storage721_code_id) =
             save_collection_and_store_contracts(&mut app);
+        let (collection1155_code_id, storage1155_code_id) =
+            save_collection_and_store_1155_contracts(&mut app);
 
         let bridge_contract_wrapper = ContractWrapper::new(
             crate::contract::execute,
@@ -295,10 +416,18 @@ mod tests {
         let msg = crate::structs::BridgeInstantiateMsg {
             validators: vec![(public_key.clone(), Addr::unchecked("validator1"))],
             chain_type: "cosmos".to_string(),
+            fee_denom: "uscrt".to_string(),
+            legacy_claim_hash_until: None,
+            supported_chains: supported_chains(),
+            max_batch_size: None,
+            burn_on_return: false,
+            threshold: None,
             storage_label: "xp-nft-store-factory".to_string(),
             collection_label: "xp-nft-collection-factory".to_string(),
             collection721_code_id: collection_code_id,
             storage721_code_id,
+            collection1155_code_id,
+            storage1155_code_id,
             collection_deployer_code_id: cfc_id,
             storage_deployer_code_id: sfc_id,
         };
@@ -329,7 +458,7 @@ mod tests {
             symbol: "BRUH".to_string(),
             token_amount: 1,
             transaction_hash: "0xabc123".to_string(),
-            lock_tx_chain: "APTOS".to_string()
+            lock_tx_chain: "APTOS".to_string(),
         };
 
         app.sudo(cw_multi_test::SudoMsg::Bank(
@@ -339,9 +468,7 @@ mod tests {
             },
         ))
         .unwrap();
-        let mut hasher = sha2::Sha256::new();
-        hasher.update(&cd.concat_all_fields());
-        let data: [u8; 32] = hasher.finalize().into();
+        let data = claim_data_hash(&bridge_addr, &cd);
         let msg = Message::from_digest(data);
         println!("{:x?}", msg);
 
@@ -382,7 +509,7 @@ mod tests {
         let json = from_json::<ClaimedEventInfo>(cei.value.clone());
         assert!(json.is_ok(), "json is not ok");
         let ev = json.unwrap();
-        assert!(ev.lock_tx_chain == "APTOS".to_string(), "Invalid lock tx chain");
+        assert!(ev.lock_tx_chain == "APTOS", "Invalid lock tx chain");
 
         let approve = app.execute_contract(
             Addr::unchecked("claimer"),
@@ -403,7 +530,7 @@ mod tests {
                 data: Lock721Msg {
                     collection_code_id,
                     destination_chain: "BSC".to_string(),
-                    destination_user_address: "0xabc123".to_string(),
+                    destination_user_address: BSC_USER.to_string(),
                     source_nft_contract_address: ev.contract.to_string(),
                     token_id: ev.token_id,
                 },
@@ -412,4 +539,3670 @@ mod tests {
         );
         println!("{:#?}", lock)
     }
+
+    #[test]
+    fn test_claim_and_lock_1155() {
+        use secp256k1::rand::rngs::OsRng;
+        use secp256k1::Message;
+        let mut app = mock_app();
+        let deployer = Addr::unchecked("deployer");
+        let claimer = Addr::unchecked("claimer");
+
+        let (sfc_id, cfc_id) = save_factory_contracts(&mut app);
+        let (collection_code_id, storage721_code_id) =
+            save_collection_and_store_contracts(&mut app);
+        let (collection1155_code_id, storage1155_code_id) =
+            save_collection_and_store_1155_contracts(&mut app);
+
+        let bridge_contract_wrapper = ContractWrapper::new(
+            crate::contract::execute,
+            crate::contract::instantiate,
+            crate::contract::query,
+        )
+        .with_reply(crate::contract::reply);
+
+        let bridge_code_id = app.store_code(Box::new(bridge_contract_wrapper));
+        let secp = secp256k1::Secp256k1::new();
+        let (sk, pubk) = secp.generate_keypair(&mut OsRng);
+        let public_key = Binary::from(pubk.serialize());
+
+        let msg = crate::structs::BridgeInstantiateMsg {
+            validators: vec![(public_key.clone(), Addr::unchecked("validator1"))],
+            chain_type: "cosmos".to_string(),
+            fee_denom: "uscrt".to_string(),
+            legacy_claim_hash_until: None,
+            supported_chains: supported_chains(),
+            max_batch_size: None,
+            burn_on_return: false,
+            threshold: None,
+            storage_label: "xp-nft-store-factory".to_string(),
+            collection_label: "xp-nft-collection-factory".to_string(),
+            collection721_code_id: collection_code_id,
+            storage721_code_id,
+            collection1155_code_id,
+            storage1155_code_id,
+            collection_deployer_code_id: cfc_id,
+            storage_deployer_code_id: sfc_id,
+        };
+
+        let bridge_addr = app
+            .instantiate_contract(
+                bridge_code_id,
+                deployer.clone(),
+                &msg,
+                &[],
+                "xp_bridge".to_string(),
+                None,
+            )
+            .unwrap();
+
+        app.sudo(cw_multi_test::SudoMsg::Bank(
+            cw_multi_test::BankSudo::Mint {
+                to_address: "deployer".to_string(),
+                amount: vec![Coin::new(3000, "uscrt")],
+            },
+        ))
+        .unwrap();
+
+        let claim = |app: &mut App, token_amount: u128, transaction_hash: &str| {
+            let cd = ClaimData {
+                destination_chain: "cosmos".to_string(),
+                destination_user_address: claimer.clone(),
+                fee: 1000,
+                metadata: "metadata".to_string(),
+                name: "name".to_string(),
+                nft_type: "multiple".to_string(),
+                royalty: 1,
+                royalty_receiver: Addr::unchecked("royalty_receiver"),
+                source_chain: "BSC".to_string(),
+                source_nft_contract_address: "bruh".to_string(),
+                token_id: "1".to_string(),
+                symbol: "BRUH".to_string(),
+                token_amount,
+                transaction_hash: transaction_hash.to_string(),
+                lock_tx_chain: "BSC".to_string(),
+            };
+            let data = claim_data_hash(&bridge_addr, &cd);
+            let signed = Binary::from(
+                secp.sign_ecdsa(&Message::from_digest(data), &sk)
+                    .serialize_compact(),
+            );
+            app.execute_contract(
+                deployer.clone(),
+                bridge_addr.clone(),
+                &BridgeExecuteMsg::Claim1155 {
+                    data: ClaimMsg {
+                        data: cd,
+                        signatures: vec![SignerAndSignature {
+                            signature: signed,
+                            signer_address: public_key.clone(),
+                        }],
+                    },
+                },
+                &[Coin::new(1000, "uscrt")],
+            )
+        };
+
+        // first claim deploys the duplicate collection and mints to the claimer
+        let res = claim(&mut app, 5, "0x01");
+        assert!(res.is_ok(), "claim failed: {:?}", res);
+        let res = res.unwrap();
+        let claimed = res
+            .events
+            .iter()
+            .flat_map(|e| e.attributes.iter())
+            .find(|a| a.key == "Claimed1155EventInfo")
+            .expect("Not found Claimed1155EventInfo");
+        let ev = serde_json_wasm::from_str::<Claimed1155EventInfo>(&claimed.value).unwrap();
+        assert_eq!(ev.amount, 5);
+
+        let balance_of = |app: &App, owner: &Addr| -> Uint128 {
+            app.wrap()
+                .query_wasm_smart::<BalanceResponse>(
+                    ev.contract.clone(),
+                    &Nft1155QueryMsg::Balance {
+                        owner: owner.to_string(),
+                        token_id: "1".to_string(),
+                    },
+                )
+                .unwrap()
+                .balance
+        };
+        assert_eq!(balance_of(&app, &claimer), Uint128::new(5));
+
+        app.execute_contract(
+            claimer.clone(),
+            ev.contract.clone(),
+            &Nft1155ExecuteMsg::ApproveAll {
+                operator: bridge_addr.to_string(),
+                expires: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        let lock = app.execute_contract(
+            claimer.clone(),
+            bridge_addr.clone(),
+            &BridgeExecuteMsg::Lock1155 {
+                data: Lock1155Msg {
+                    collection_code_id: collection1155_code_id,
+                    destination_chain: "BSC".to_string(),
+                    destination_user_address: BSC_USER.to_string(),
+                    source_nft_contract_address: ev.contract.clone(),
+                    token_id: "1".to_string(),
+                    token_amount: 3,
+                },
+            },
+            &[],
+        );
+        assert!(lock.is_ok(), "lock failed: {:?}", lock);
+        assert_eq!(balance_of(&app, &claimer), Uint128::new(2));
+
+        // the storage covers the whole claim
+        let res = claim(&mut app, 2, "0x02");
+        assert!(res.is_ok(), "claim failed: {:?}", res);
+        assert_eq!(balance_of(&app, &claimer), Uint128::new(4));
+
+        // one edition left in storage, the rest is minted
+        let res = claim(&mut app, 5, "0x03");
+        assert!(res.is_ok(), "claim failed: {:?}", res);
+        assert_eq!(balance_of(&app, &claimer), Uint128::new(9));
+
+        let replay = claim(&mut app, 5, "0x03");
+        assert!(replay.is_err(), "replayed claim should fail");
+    }
+
+    #[test]
+    fn test_claim_counts_only_registered_signers() {
+        use secp256k1::rand::rngs::OsRng;
+        use secp256k1::Message;
+        let mut app = mock_app();
+        let deployer = Addr::unchecked("deployer");
+
+        let (sfc_id, cfc_id) = save_factory_contracts(&mut app);
+        let (collection_code_id, storage721_code_id) =
+            save_collection_and_store_contracts(&mut app);
+        let (collection1155_code_id, storage1155_code_id) =
+            save_collection_and_store_1155_contracts(&mut app);
+
+        let bridge_contract_wrapper = ContractWrapper::new(
+            crate::contract::execute,
+            crate::contract::instantiate,
+            crate::contract::query,
+        )
+        .with_reply(crate::contract::reply);
+
+        let bridge_code_id = app.store_code(Box::new(bridge_contract_wrapper));
+        let secp = secp256k1::Secp256k1::new();
+        let (sk, pubk) = secp.generate_keypair(&mut OsRng);
+        let public_key = Binary::from(pubk.serialize());
+        let (outsider_sk, outsider_pubk) = secp.generate_keypair(&mut OsRng);
+        let outsider_key = Binary::from(outsider_pubk.serialize());
+
+        let msg = crate::structs::BridgeInstantiateMsg {
+            validators: vec![(public_key.clone(), Addr::unchecked("validator1"))],
+            chain_type: "cosmos".to_string(),
+            fee_denom: "uscrt".to_string(),
+            legacy_claim_hash_until: None,
+            supported_chains: supported_chains(),
+            max_batch_size: None,
+            burn_on_return: false,
+            threshold: None,
+            storage_label: "xp-nft-store-factory".to_string(),
+            collection_label: "xp-nft-collection-factory".to_string(),
+            collection721_code_id: collection_code_id,
+            storage721_code_id,
+            collection1155_code_id,
+            storage1155_code_id,
+            collection_deployer_code_id: cfc_id,
+            storage_deployer_code_id: sfc_id,
+        };
+
+        let bridge_addr = app
+            .instantiate_contract(
+                bridge_code_id,
+                deployer.clone(),
+                &msg,
+                &[],
+                "xp_bridge".to_string(),
+                None,
+            )
+            .unwrap();
+
+        app.sudo(cw_multi_test::SudoMsg::Bank(
+            cw_multi_test::BankSudo::Mint {
+                to_address: "deployer".to_string(),
+                amount: vec![Coin::new(2000, "uscrt")],
+            },
+        ))
+        .unwrap();
+
+        let sign = |sk: &secp256k1::SecretKey, data: [u8; 32]| {
+            Binary::from(
+                secp.sign_ecdsa(&Message::from_digest(data), sk)
+                    .serialize_compact(),
+            )
+        };
+        let claim_data = |transaction_hash: &str| ClaimData {
+            destination_chain: "cosmos".to_string(),
+            destination_user_address: Addr::unchecked("claimer"),
+            fee: 1000,
+            metadata: "metadata".to_string(),
+            name: "name".to_string(),
+            nft_type: "singular".to_string(),
+            royalty: 1,
+            royalty_receiver: Addr::unchecked("royalty_receiver"),
+            source_chain: "BSC".to_string(),
+            source_nft_contract_address: "bruh".to_string(),
+            token_id: "1".to_string(),
+            symbol: "BRUH".to_string(),
+            token_amount: 1,
+            transaction_hash: transaction_hash.to_string(),
+            lock_tx_chain: "BSC".to_string(),
+        };
+        let hash_of = |cd: &ClaimData| claim_data_hash(&bridge_addr, cd);
+
+        // an outsider alone cannot reach the threshold
+        let cd = claim_data("0x01");
+        let hash = hash_of(&cd);
+        let claim = app.execute_contract(
+            deployer.clone(),
+            bridge_addr.clone(),
+            &BridgeExecuteMsg::Claim721 {
+                data: ClaimMsg {
+                    data: cd,
+                    signatures: vec![SignerAndSignature {
+                        signature: sign(&outsider_sk, hash),
+                        signer_address: outsider_key.clone(),
+                    }],
+                },
+            },
+            &[Coin::new(1000, "uscrt")],
+        );
+        assert!(claim.is_err(), "outsider signature was counted");
+
+        let cd = claim_data("0x02");
+        let hash = hash_of(&cd);
+        let validator_signature = SignerAndSignature {
+            signature: sign(&sk, hash),
+            signer_address: public_key.clone(),
+        };
+        let claim = app.execute_contract(
+            deployer.clone(),
+            bridge_addr.clone(),
+            &BridgeExecuteMsg::Claim721 {
+                data: ClaimMsg {
+                    data: cd,
+                    signatures: vec![
+                        SignerAndSignature {
+                            signature: sign(&outsider_sk, hash),
+                            signer_address: outsider_key.clone(),
+                        },
+                        validator_signature.clone(),
+                        validator_signature,
+                    ],
+                },
+            },
+            &[Coin::new(1000, "uscrt")],
+        );
+        assert!(claim.is_ok(), "claim failed: {:?}", claim);
+        let skipped = claim
+            .unwrap()
+            .events
+            .iter()
+            .flat_map(|e| e.attributes.iter())
+            .find(|a| a.key == "SkippedSignersEventInfo")
+            .map(|a| from_json::<SkippedSignersEventInfo>(a.value.clone()).unwrap())
+            .expect("Not found SkippedSignersEventInfo");
+        assert_eq!(skipped.unknown, vec![outsider_key.clone()]);
+        assert_eq!(skipped.duplicate, vec![public_key.clone()]);
+        assert!(skipped.blacklisted.is_empty());
+        assert!(skipped.invalid.is_empty());
+
+        // the whole fee goes to the one counted signer
+        let rewards = validator_rewards(&app, &bridge_addr, &public_key);
+        assert_eq!(rewards.data, vec![Coin::new(1000, "uscrt")]);
+
+        // add-validator aggregation ignores unregistered signers too
+        let add = app.execute_contract(
+            deployer,
+            bridge_addr.clone(),
+            &BridgeExecuteMsg::AddValidator {
+                data: AddValidatorMsg {
+                    validator: (outsider_key.clone(), Addr::unchecked("outsider")),
+                    signatures: vec![sign_addition(
+                        &bridge_addr,
+                        &outsider_sk,
+                        &outsider_key,
+                        (&outsider_key, "outsider"),
+                    )],
+                },
+            },
+            &[],
+        );
+        assert!(add.is_err(), "self-signed validator was added");
+    }
+
+    fn instantiate_bridge_with_validator(
+        app: &mut App,
+        fee_denom: &str,
+    ) -> (Addr, secp256k1::SecretKey, Binary) {
+        instantiate_bridge(app, fee_denom, None)
+    }
+
+    fn instantiate_bridge(
+        app: &mut App,
+        fee_denom: &str,
+        legacy_claim_hash_until: Option<u64>,
+    ) -> (Addr, secp256k1::SecretKey, Binary) {
+        instantiate_bridge_with_config(app, fee_denom, |msg| {
+            msg.legacy_claim_hash_until = legacy_claim_hash_until
+        })
+    }
+
+    fn instantiate_bridge_with_config(
+        app: &mut App,
+        fee_denom: &str,
+        configure: impl FnOnce(&mut crate::structs::BridgeInstantiateMsg),
+    ) -> (Addr, secp256k1::SecretKey, Binary) {
+        use secp256k1::rand::rngs::OsRng;
+
+        let (sfc_id, cfc_id) = save_factory_contracts(app);
+        let (collection_code_id, storage721_code_id) = save_collection_and_store_contracts(app);
+        let (collection1155_code_id, storage1155_code_id) =
+            save_collection_and_store_1155_contracts(app);
+
+        let bridge_contract_wrapper = ContractWrapper::new(
+            crate::contract::execute,
+            crate::contract::instantiate,
+            crate::contract::query,
+        )
+        .with_reply(crate::contract::reply);
+        let bridge_code_id = app.store_code(Box::new(bridge_contract_wrapper));
+
+        let secp = secp256k1::Secp256k1::new();
+        let (sk, pubk) = secp.generate_keypair(&mut OsRng);
+        let public_key = Binary::from(pubk.serialize());
+
+        let mut msg = crate::structs::BridgeInstantiateMsg {
+            validators: vec![(public_key.clone(), Addr::unchecked("validator1"))],
+            chain_type: "cosmos".to_string(),
+            fee_denom: fee_denom.to_string(),
+            legacy_claim_hash_until: None,
+            supported_chains: supported_chains(),
+            max_batch_size: None,
+            burn_on_return: false,
+            threshold: None,
+            storage_label: "xp-nft-store-factory".to_string(),
+            collection_label: "xp-nft-collection-factory".to_string(),
+            collection721_code_id: collection_code_id,
+            storage721_code_id,
+            collection1155_code_id,
+            storage1155_code_id,
+            collection_deployer_code_id: cfc_id,
+            storage_deployer_code_id: sfc_id,
+        };
+        configure(&mut msg);
+        let bridge_addr = app
+            .instantiate_contract(
+                bridge_code_id,
+                Addr::unchecked("deployer"),
+                &msg,
+                &[],
+                "xp_bridge".to_string(),
+                Some("deployer".to_string()),
+            )
+            .unwrap();
+
+        app.sudo(cw_multi_test::SudoMsg::Bank(
+            cw_multi_test::BankSudo::Mint {
+                to_address: "deployer".to_string(),
+                amount: vec![Coin::new(10_000, "uscrt"), Coin::new(10_000, "uluna")],
+            },
+        ))
+        .unwrap();
+
+        (bridge_addr, sk, public_key)
+    }
+
+    /// Instantiates a cw721 collection and mints token "1" to `owner`, approved
+    /// for the bridge
+    fn mint_approved_nft(
+        app: &mut App,
+        collection_code_id: u64,
+        bridge: &Addr,
+        owner: &Addr,
+    ) -> Addr {
+        let nft_contract = app
+            .instantiate_contract(
+                collection_code_id,
+                Addr::unchecked("deployer"),
+                &cosm_nft::init::InstantiateMsg {
+                    name: "test-nft-contract".to_string(),
+                    symbol: "TNC".to_string(),
+                    minter: owner.to_string(),
+                    destination_user_address: Addr::unchecked("input"),
+                    metadata: Default::default(),
+                    royalty: Default::default(),
+                    source_chain: Default::default(),
+                    source_nft_contract_address: Default::default(),
+                    token_id: Default::default(),
+                    royalty_receiver: Addr::unchecked("rr"),
+                    token_amount: Default::default(),
+                    transaction_hash: "tx_hash".to_string(),
+                    lock_tx_chain: "BRUH".to_string(),
+                    burn_on_return: false,
+                },
+                &[],
+                "test-nft-contract",
+                None,
+            )
+            .unwrap();
+        app.execute_contract(
+            owner.clone(),
+            nft_contract.clone(),
+            &cosm_nft::NftExecuteMsg::Mint {
+                token_id: "1".to_owned(),
+                owner: owner.to_string(),
+                token_uri: None,
+                extension: RoyaltyData::default(),
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            owner.clone(),
+            nft_contract.clone(),
+            &cosm_nft::NftExecuteMsg::Approve {
+                spender: bridge.to_string(),
+                token_id: "1".to_string(),
+                expires: None,
+            },
+            &[],
+        )
+        .unwrap();
+        nft_contract
+    }
+
+    fn default_claim_data() -> ClaimData {
+        ClaimData {
+            destination_chain: "cosmos".to_string(),
+            destination_user_address: Addr::unchecked("claimer"),
+            fee: 1000,
+            metadata: "metadata".to_string(),
+            name: "name".to_string(),
+            nft_type: "singular".to_string(),
+            royalty: 1,
+            royalty_receiver: Addr::unchecked("royalty_receiver"),
+            source_chain: "BSC".to_string(),
+            source_nft_contract_address: "bruh".to_string(),
+            token_id: "1".to_string(),
+            symbol: "BRUH".to_string(),
+            token_amount: 1,
+            transaction_hash: "0xabc123".to_string(),
+            lock_tx_chain: "BSC".to_string(),
+        }
+    }
+
+    fn claim_data_hash(bridge: &Addr, data: &ClaimData) -> [u8; 32] {
+        use sha2::Digest;
+        let mut hasher = sha2::Sha256::new();
+        hasher.update(data.canonical_encoding(bridge.as_str(), "cosmos"));
+        hasher.finalize().into()
+    }
+
+    fn signed_claim(
+        bridge: &Addr,
+        sk: &secp256k1::SecretKey,
+        public_key: &Binary,
+        data: ClaimData,
+    ) -> ClaimMsg {
+        let hash = claim_data_hash(bridge, &data);
+        let signature = secp256k1::Secp256k1::new()
+            .sign_ecdsa(&secp256k1::Message::from_digest(hash), sk)
+            .serialize_compact();
+        ClaimMsg {
+            data,
+            signatures: vec![SignerAndSignature {
+                signature: Binary::from(signature),
+                signer_address: public_key.clone(),
+            }],
+        }
+    }
+
+    /// Signs `update` for `bridge` like validators sign governance messages
+    fn governance_signatures<T: GovernanceMessage>(
+        bridge: &Addr,
+        sk: &secp256k1::SecretKey,
+        public_key: &Binary,
+        update: &T,
+    ) -> Vec<SignerAndSignature> {
+        vec![governance_signature(bridge, sk, public_key, update)]
+    }
+
+    fn governance_signature<T: GovernanceMessage>(
+        bridge: &Addr,
+        sk: &secp256k1::SecretKey,
+        public_key: &Binary,
+        update: &T,
+    ) -> SignerAndSignature {
+        use sha2::Digest;
+        let mut hasher = sha2::Sha256::new();
+        hasher.update(
+            update
+                .governance_encoding(bridge.as_str(), "cosmos")
+                .unwrap(),
+        );
+        let hash: [u8; 32] = hasher.finalize().into();
+        let signature = secp256k1::Secp256k1::new()
+            .sign_ecdsa(&secp256k1::Message::from_digest(hash), sk)
+            .serialize_compact();
+        SignerAndSignature {
+            signature: Binary::from(signature),
+            signer_address: public_key.clone(),
+        }
+    }
+
+    fn claim_721_error(
+        app: &mut App,
+        bridge: &Addr,
+        msg: ClaimMsg,
+        funds: &[Coin],
+    ) -> ContractError {
+        app.execute_contract(
+            Addr::unchecked("deployer"),
+            bridge.clone(),
+            &BridgeExecuteMsg::Claim721 { data: msg },
+            funds,
+        )
+        .expect_err("claim should fail")
+        .downcast::<ContractError>()
+        .expect("not a bridge error")
+    }
+
+    #[test]
+    fn test_claim_rejects_other_destination_chain() {
+        let mut app = mock_app();
+        let (bridge_addr, sk, public_key) = instantiate_bridge_with_validator(&mut app, "uscrt");
+
+        let data = ClaimData {
+            destination_chain: "BSC".to_string(),
+            ..default_claim_data()
+        };
+        let err = claim_721_error(
+            &mut app,
+            &bridge_addr,
+            signed_claim(&bridge_addr, &sk, &public_key, data.clone()),
+            &[Coin::new(1000, "uscrt")],
+        );
+        assert!(
+            matches!(err, ContractError::InvalidDestinationChain {}),
+            "{:?}",
+            err
+        );
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("deployer"),
+                bridge_addr.clone(),
+                &BridgeExecuteMsg::Claim1155 {
+                    data: signed_claim(
+                        &bridge_addr,
+                        &sk,
+                        &public_key,
+                        ClaimData {
+                            nft_type: "multiple".to_string(),
+                            ..data
+                        },
+                    ),
+                },
+                &[Coin::new(1000, "uscrt")],
+            )
+            .expect_err("claim should fail")
+            .downcast::<ContractError>()
+            .unwrap();
+        assert!(
+            matches!(err, ContractError::InvalidDestinationChain {}),
+            "{:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_claim_rejects_underpaid_fee() {
+        let mut app = mock_app();
+        let (bridge_addr, sk, public_key) = instantiate_bridge_with_validator(&mut app, "uscrt");
+
+        let err = claim_721_error(
+            &mut app,
+            &bridge_addr,
+            signed_claim(&bridge_addr, &sk, &public_key, default_claim_data()),
+            &[Coin::new(999, "uscrt")],
+        );
+        assert!(
+            matches!(
+                err,
+                ContractError::FeeTooLow { sent, required }
+                    if sent.u128() == 999 && required.u128() == 1000
+            ),
+            "{:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_claim_rejects_missing_fee() {
+        let mut app = mock_app();
+        let (bridge_addr, sk, public_key) = instantiate_bridge_with_validator(&mut app, "uscrt");
+
+        let err = claim_721_error(
+            &mut app,
+            &bridge_addr,
+            signed_claim(&bridge_addr, &sk, &public_key, default_claim_data()),
+            &[],
+        );
+        assert!(
+            matches!(err, ContractError::FeeTooLow { sent, .. } if sent.is_zero()),
+            "{:?}",
+            err
+        );
+
+        // funds in another denom do not pay the fee
+        let err = claim_721_error(
+            &mut app,
+            &bridge_addr,
+            signed_claim(&bridge_addr, &sk, &public_key, default_claim_data()),
+            &[Coin::new(1000, "uluna")],
+        );
+        assert!(
+            matches!(err, ContractError::FeeTooLow { sent, .. } if sent.is_zero()),
+            "{:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_claim_rejects_zero_fee() {
+        let mut app = mock_app();
+        let (bridge_addr, sk, public_key) = instantiate_bridge_with_validator(&mut app, "uscrt");
+
+        let data = ClaimData {
+            fee: 0,
+            ..default_claim_data()
+        };
+        let err = claim_721_error(
+            &mut app,
+            &bridge_addr,
+            signed_claim(&bridge_addr, &sk, &public_key, data),
+            &[],
+        );
+        assert!(matches!(err, ContractError::InvalidFee {}), "{:?}", err);
+    }
+
+    #[test]
+    fn test_fees_use_configured_denom() {
+        let mut app = mock_app();
+        let (bridge_addr, sk, public_key) = instantiate_bridge_with_validator(&mut app, "uluna");
+
+        let err = claim_721_error(
+            &mut app,
+            &bridge_addr,
+            signed_claim(&bridge_addr, &sk, &public_key, default_claim_data()),
+            &[Coin::new(1000, "uscrt")],
+        );
+        assert!(
+            matches!(err, ContractError::FeeTooLow { sent, .. } if sent.is_zero()),
+            "{:?}",
+            err
+        );
+
+        let claim = app.execute_contract(
+            Addr::unchecked("deployer"),
+            bridge_addr.clone(),
+            &BridgeExecuteMsg::Claim721 {
+                data: signed_claim(&bridge_addr, &sk, &public_key, default_claim_data()),
+            },
+            &[Coin::new(1000, "uluna")],
+        );
+        assert!(claim.is_ok(), "claim failed: {:?}", claim);
+
+        let withdrawal = RewardWithdrawal {
+            denom: "uluna".to_string(),
+            amount: None,
+            payout_address: "validator1".to_string(),
+            nonce: 0,
+        };
+        let rewards = app.execute_contract(
+            Addr::unchecked("validator1"),
+            bridge_addr.clone(),
+            &withdraw_rewards_msg(&bridge_addr, &sk, &public_key, withdrawal),
+            &[],
+        );
+        assert!(rewards.is_ok(), "rewards failed: {:?}", rewards);
+        let balance = app.wrap().query_balance("validator1", "uluna").unwrap();
+        assert_eq!(balance.amount.u128(), 1000);
+    }
+
+    fn validator_rewards(
+        app: &App,
+        bridge: &Addr,
+        public_key: &Binary,
+    ) -> GetValidatorRewardsResponse {
+        app.wrap()
+            .query_wasm_smart(
+                bridge.clone(),
+                &BridgeQueryMsg::GetValidatorRewards {
+                    validator: public_key.clone(),
+                },
+            )
+            .unwrap()
+    }
+
+    fn withdraw_rewards_msg(
+        bridge: &Addr,
+        sk: &secp256k1::SecretKey,
+        public_key: &Binary,
+        withdrawal: RewardWithdrawal,
+    ) -> BridgeExecuteMsg {
+        let signature = governance_signatures(bridge, sk, public_key, &withdrawal)
+            .remove(0)
+            .signature;
+        BridgeExecuteMsg::ClaimValidatorRewards {
+            data: ClaimValidatorRewardsMsg {
+                validator: public_key.clone(),
+                withdrawal,
+                signature,
+            },
+        }
+    }
+
+    #[test]
+    fn test_set_threshold_policy() {
+        use secp256k1::rand::rngs::OsRng;
+        let mut app = mock_app();
+        let (sk2, pk2) = secp256k1::Secp256k1::new().generate_keypair(&mut OsRng);
+        let public_key2 = Binary::from(pk2.serialize());
+        let (bridge_addr, sk, public_key) = instantiate_bridge_with_config(&mut app, "uscrt", {
+            let public_key2 = public_key2.clone();
+            |msg| {
+                msg.validators
+                    .push((public_key2, Addr::unchecked("validator2")))
+            }
+        });
+        let config = |app: &App| -> State {
+            app.wrap()
+                .query_wasm_smart(bridge_addr.clone(), &BridgeQueryMsg::Config {})
+                .unwrap()
+        };
+        assert_eq!(config(&app).threshold, ThresholdPolicy::default());
+
+        let update = |numerator: u64, minimum: u64, nonce: u64| ThresholdPolicyUpdate {
+            policy: ThresholdPolicy {
+                numerator,
+                denominator: 3,
+                minimum,
+            },
+            nonce,
+        };
+        let set_policy = |app: &mut App,
+                          update: ThresholdPolicyUpdate,
+                          signers: &[(&secp256k1::SecretKey, &Binary)]| {
+            let signatures = signers
+                .iter()
+                .flat_map(|(sk, key)| governance_signatures(&bridge_addr, sk, key, &update))
+                .collect();
+            app.execute_contract(
+                Addr::unchecked("anyone"),
+                bridge_addr.clone(),
+                &BridgeExecuteMsg::SetThresholdPolicy {
+                    data: SetThresholdPolicyMsg { update, signatures },
+                },
+                &[],
+            )
+        };
+        let set_policy_error =
+            |app: &mut App,
+             update: ThresholdPolicyUpdate,
+             signers: &[(&secp256k1::SecretKey, &Binary)]| {
+                set_policy(app, update, signers)
+                    .expect_err("policy update should fail")
+                    .downcast::<ContractError>()
+                    .expect("not a bridge error")
+            };
+        let both = [(&sk, &public_key), (&sk2, &public_key2)];
+
+        // 2 of 2 validators have to sign under the default policy
+        let err = set_policy_error(&mut app, update(0, 1, 0), &[(&sk, &public_key)]);
+        assert!(
+            matches!(err, ContractError::ThresholdNotReached { got: 1, need: 2 }),
+            "{:?}",
+            err
+        );
+        let err = set_policy_error(&mut app, update(3, 1, 0), &both);
+        assert!(
+            matches!(err, ContractError::InvalidThresholdPolicy {}),
+            "{:?}",
+            err
+        );
+        // more signatures than there are validators
+        let err = set_policy_error(&mut app, update(0, 3, 0), &both);
+        assert!(
+            matches!(err, ContractError::InvalidThresholdPolicy {}),
+            "{:?}",
+            err
+        );
+
+        let res = set_policy(&mut app, update(0, 1, 0), &both);
+        assert!(res.is_ok(), "policy update failed: {:?}", res);
+        let state = config(&app);
+        assert_eq!(state.threshold, update(0, 1, 0).policy);
+        assert_eq!(state.threshold_nonce, 1);
+        let err = set_policy_error(&mut app, update(0, 1, 0), &both);
+        assert!(
+            matches!(
+                err,
+                ContractError::InvalidThresholdPolicyNonce {
+                    expected: 1,
+                    found: 0
+                }
+            ),
+            "{:?}",
+            err
+        );
+
+        // 1 of 2 is now enough for claims and governance alike
+        let claim = app.execute_contract(
+            Addr::unchecked("deployer"),
+            bridge_addr.clone(),
+            &BridgeExecuteMsg::Claim721 {
+                data: signed_claim(&bridge_addr, &sk, &public_key, default_claim_data()),
+            },
+            &[Coin::new(1000, "uscrt")],
+        );
+        assert!(claim.is_ok(), "claim failed: {:?}", claim);
+        let res = set_policy(&mut app, update(2, 2, 1), &[(&sk2, &public_key2)]);
+        assert!(res.is_ok(), "policy update failed: {:?}", res);
+        assert_eq!(config(&app).threshold.required(2), 2);
+
+        // blacklisting below the minimum leaves the last validator able to sign
+        let res = app.execute_contract(
+            Addr::unchecked("anyone"),
+            bridge_addr.clone(),
+            &BridgeExecuteMsg::BlacklistValidator {
+                data: BlacklistValidatorMsg {
+                    validator: (public_key2.clone(), Addr::unchecked("validator2")),
+                    signatures: vec![
+                        sign_blacklisting(&bridge_addr, &sk, &public_key, &public_key2),
+                        sign_blacklisting(&bridge_addr, &sk2, &public_key2, &public_key2),
+                    ],
+                },
+            },
+            &[],
+        );
+        assert!(res.is_ok(), "blacklist failed: {:?}", res);
+        let state = config(&app);
+        assert_eq!(state.validators_count, 1);
+        assert_eq!(state.threshold.required(state.validators_count), 1);
+        let claim = app.execute_contract(
+            Addr::unchecked("deployer"),
+            bridge_addr.clone(),
+            &BridgeExecuteMsg::Claim721 {
+                data: signed_claim(
+                    &bridge_addr,
+                    &sk,
+                    &public_key,
+                    ClaimData {
+                        token_id: "2".to_string(),
+                        transaction_hash: "other_tx_hash".to_string(),
+                        ..default_claim_data()
+                    },
+                ),
+            },
+            &[Coin::new(1000, "uscrt")],
+        );
+        assert!(claim.is_ok(), "claim failed: {:?}", claim);
+    }
+
+    #[test]
+    fn test_rotate_validator_key() {
+        use secp256k1::rand::rngs::OsRng;
+        let mut app = mock_app();
+        let secp = secp256k1::Secp256k1::new();
+        let (sk2, pk2) = secp.generate_keypair(&mut OsRng);
+        let public_key2 = Binary::from(pk2.serialize());
+        let (bridge_addr, sk, public_key) = instantiate_bridge_with_config(&mut app, "uscrt", {
+            let public_key2 = public_key2.clone();
+            |msg| {
+                msg.validators
+                    .push((public_key2, Addr::unchecked("validator2")))
+            }
+        });
+        let (new_sk, new_pk) = secp.generate_keypair(&mut OsRng);
+        let new_key = Binary::from(new_pk.serialize());
+
+        let claim = |app: &mut App, signers: &[(&secp256k1::SecretKey, &Binary)], tx: &str| {
+            let data = ClaimData {
+                token_id: tx.to_string(),
+                transaction_hash: tx.to_string(),
+                ..default_claim_data()
+            };
+            let signatures = signers
+                .iter()
+                .flat_map(|(sk, key)| signed_claim(&bridge_addr, sk, key, data.clone()).signatures)
+                .collect();
+            app.execute_contract(
+                Addr::unchecked("deployer"),
+                bridge_addr.clone(),
+                &BridgeExecuteMsg::Claim721 {
+                    data: ClaimMsg { data, signatures },
+                },
+                &[Coin::new(1000, "uscrt")],
+            )
+        };
+        claim(&mut app, &[(&sk, &public_key), (&sk2, &public_key2)], "0x1").unwrap();
+        let withdrawal = RewardWithdrawal {
+            denom: "uscrt".to_string(),
+            amount: Some(Uint128::new(100)),
+            payout_address: "payout".to_string(),
+            nonce: 0,
+        };
+        app.execute_contract(
+            Addr::unchecked("anyone"),
+            bridge_addr.clone(),
+            &withdraw_rewards_msg(&bridge_addr, &sk, &public_key, withdrawal),
+            &[],
+        )
+        .unwrap();
+
+        let rotate = |app: &mut App, new: &Binary, signers: &[(&secp256k1::SecretKey, &Binary)]| {
+            let rotation = KeyRotation {
+                old: public_key.clone(),
+                new: new.clone(),
+            };
+            let signatures = signers
+                .iter()
+                .flat_map(|(sk, key)| governance_signatures(&bridge_addr, sk, key, &rotation))
+                .collect();
+            app.execute_contract(
+                Addr::unchecked("anyone"),
+                bridge_addr.clone(),
+                &BridgeExecuteMsg::RotateValidatorKey {
+                    data: RotateValidatorKeyMsg {
+                        rotation,
+                        signatures,
+                    },
+                },
+                &[],
+            )
+        };
+        let rotate_error =
+            |app: &mut App, new: &Binary, signers: &[(&secp256k1::SecretKey, &Binary)]| {
+                rotate(app, new, signers)
+                    .expect_err("rotation should fail")
+                    .downcast::<ContractError>()
+                    .expect("not a bridge error")
+            };
+
+        let err = rotate_error(&mut app, &new_key, &[(&sk2, &public_key2)]);
+        assert!(
+            matches!(err, ContractError::MissingRotatedKeySignature {}),
+            "{:?}",
+            err
+        );
+        let err = rotate_error(&mut app, &new_key, &[(&sk, &public_key)]);
+        assert!(
+            matches!(err, ContractError::ThresholdNotReached { got: 1, need: 2 }),
+            "{:?}",
+            err
+        );
+        let both = [(&sk, &public_key), (&sk2, &public_key2)];
+        let err = rotate_error(&mut app, &public_key2, &both);
+        assert!(
+            matches!(err, ContractError::ValidatorKeyInUse {}),
+            "{:?}",
+            err
+        );
+        let err = rotate_error(&mut app, &Binary::from(vec![2; 32]), &both);
+        assert!(
+            matches!(err, ContractError::InvalidValidatorKey {}),
+            "{:?}",
+            err
+        );
+
+        let res = rotate(&mut app, &new_key, &both).unwrap();
+        let rotated = res
+            .events
+            .iter()
+            .flat_map(|e| e.attributes.iter())
+            .find(|a| a.key == "ValidatorKeyRotatedEventInfo")
+            .map(|a| from_json::<ValidatorKeyRotatedEventInfo>(a.value.clone()).unwrap())
+            .expect("Not found ValidatorKeyRotatedEventInfo");
+        assert_eq!(rotated.validator, Addr::unchecked("validator1"));
+        assert_eq!(rotated.old, public_key);
+        assert_eq!(rotated.new, new_key);
+
+        // the validator, its rewards and withdrawal nonce moved to the new key
+        let validator = |app: &App, key: &Binary| -> GetValidatorResponse {
+            app.wrap()
+                .query_wasm_smart(
+                    bridge_addr.clone(),
+                    &BridgeQueryMsg::GetValidator {
+                        address: key.clone(),
+                    },
+                )
+                .unwrap()
+        };
+        assert!(validator(&app, &public_key).data.is_none());
+        assert_eq!(
+            validator(&app, &new_key).data.unwrap().address,
+            Addr::unchecked("validator1")
+        );
+        let rewards = validator_rewards(&app, &bridge_addr, &new_key);
+        assert_eq!(rewards.data, vec![Coin::new(400, "uscrt")]);
+        assert_eq!(rewards.nonce, 1);
+        assert!(validator_rewards(&app, &bridge_addr, &public_key)
+            .data
+            .is_empty());
+        let count: GetValidatorCountResponse = app
+            .wrap()
+            .query_wasm_smart(bridge_addr.clone(), &BridgeQueryMsg::GetValidatorsCount {})
+            .unwrap();
+        assert_eq!(count.count, 2);
+
+        // the old key is retired, not blacklisted
+        let retired: GetRetiredValidatorResponse = app
+            .wrap()
+            .query_wasm_smart(
+                bridge_addr.clone(),
+                &BridgeQueryMsg::GetRetiredValidator {
+                    address: public_key.clone(),
+                },
+            )
+            .unwrap();
+        assert_eq!(retired.data, Some(new_key.clone()));
+        let blacklisted: ListBlacklistedResponse = app
+            .wrap()
+            .query_wasm_smart(
+                bridge_addr.clone(),
+                &BridgeQueryMsg::ListBlacklisted {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert!(blacklisted.data.is_empty());
+
+        // only the new key counts towards the threshold
+        assert!(claim(&mut app, &[(&sk, &public_key), (&sk2, &public_key2)], "0x2").is_err());
+        claim(
+            &mut app,
+            &[(&new_sk, &new_key), (&sk2, &public_key2)],
+            "0x2",
+        )
+        .unwrap();
+        let err = rotate_error(&mut app, &public_key2, &both);
+        assert!(
+            matches!(err, ContractError::UnknownValidator {}),
+            "{:?}",
+            err
+        );
+
+        // a retired key can't come back as a validator
+        let err = app
+            .execute_contract(
+                Addr::unchecked("deployer"),
+                bridge_addr.clone(),
+                &BridgeExecuteMsg::AddValidator {
+                    data: AddValidatorMsg {
+                        validator: (public_key.clone(), Addr::unchecked("validator1")),
+                        signatures: vec![
+                            sign_addition(
+                                &bridge_addr,
+                                &new_sk,
+                                &new_key,
+                                (&public_key, "validator1"),
+                            ),
+                            sign_addition(
+                                &bridge_addr,
+                                &sk2,
+                                &public_key2,
+                                (&public_key, "validator1"),
+                            ),
+                        ],
+                    },
+                },
+                &[],
+            )
+            .unwrap_err()
+            .downcast::<ContractError>()
+            .unwrap();
+        assert!(
+            matches!(err, ContractError::ValidatorRetired {}),
+            "{:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_validator_reward_withdrawals() {
+        use secp256k1::rand::rngs::OsRng;
+        let mut app = mock_app();
+        let (bridge_addr, sk, public_key) = instantiate_bridge_with_validator(&mut app, "uscrt");
+
+        let claim = app.execute_contract(
+            Addr::unchecked("deployer"),
+            bridge_addr.clone(),
+            &BridgeExecuteMsg::Claim721 {
+                data: signed_claim(&bridge_addr, &sk, &public_key, default_claim_data()),
+            },
+            &[Coin::new(1000, "uscrt")],
+        );
+        assert!(claim.is_ok(), "claim failed: {:?}", claim);
+        let rewards = validator_rewards(&app, &bridge_addr, &public_key);
+        assert_eq!(rewards.data, vec![Coin::new(1000, "uscrt")]);
+        assert_eq!(rewards.nonce, 0);
+
+        let withdrawal = |amount: Option<u128>, nonce: u64| RewardWithdrawal {
+            denom: "uscrt".to_string(),
+            amount: amount.map(Uint128::from),
+            payout_address: "payout".to_string(),
+            nonce,
+        };
+        let withdraw_error = |app: &mut App, msg: BridgeExecuteMsg| {
+            app.execute_contract(Addr::unchecked("anyone"), bridge_addr.clone(), &msg, &[])
+                .expect_err("withdrawal should fail")
+                .downcast::<ContractError>()
+                .expect("not a bridge error")
+        };
+
+        // only the validator key authorises a payout address
+        let (other_sk, _) = secp256k1::Secp256k1::new().generate_keypair(&mut OsRng);
+        let mut forged = withdraw_rewards_msg(
+            &bridge_addr,
+            &other_sk,
+            &public_key,
+            withdrawal(Some(400), 0),
+        );
+        let err = withdraw_error(&mut app, forged.clone());
+        assert!(matches!(err, ContractError::Unauthorized {}), "{:?}", err);
+        if let BridgeExecuteMsg::ClaimValidatorRewards { data } = &mut forged {
+            data.validator = Binary::from(vec![2; 33]);
+        }
+        let err = withdraw_error(&mut app, forged);
+        assert!(
+            matches!(err, ContractError::UnknownValidator {}),
+            "{:?}",
+            err
+        );
+
+        app.execute_contract(
+            Addr::unchecked("anyone"),
+            bridge_addr.clone(),
+            &withdraw_rewards_msg(&bridge_addr, &sk, &public_key, withdrawal(Some(400), 0)),
+            &[],
+        )
+        .unwrap();
+        let balance = app.wrap().query_balance("payout", "uscrt").unwrap();
+        assert_eq!(balance.amount.u128(), 400);
+        let rewards = validator_rewards(&app, &bridge_addr, &public_key);
+        assert_eq!(rewards.data, vec![Coin::new(600, "uscrt")]);
+        assert_eq!(rewards.nonce, 1);
+
+        // a withdrawal can't be replayed
+        let err = withdraw_error(
+            &mut app,
+            withdraw_rewards_msg(&bridge_addr, &sk, &public_key, withdrawal(Some(400), 0)),
+        );
+        assert!(
+            matches!(
+                err,
+                ContractError::InvalidRewardNonce {
+                    expected: 1,
+                    found: 0
+                }
+            ),
+            "{:?}",
+            err
+        );
+
+        let err = withdraw_error(
+            &mut app,
+            withdraw_rewards_msg(&bridge_addr, &sk, &public_key, withdrawal(Some(601), 1)),
+        );
+        assert!(
+            matches!(
+                err,
+                ContractError::InsufficientRewards { pending, requested }
+                    if pending.u128() == 600 && requested.u128() == 601
+            ),
+            "{:?}",
+            err
+        );
+
+        app.execute_contract(
+            Addr::unchecked("anyone"),
+            bridge_addr.clone(),
+            &withdraw_rewards_msg(&bridge_addr, &sk, &public_key, withdrawal(None, 1)),
+            &[],
+        )
+        .unwrap();
+        let balance = app.wrap().query_balance("payout", "uscrt").unwrap();
+        assert_eq!(balance.amount.u128(), 1000);
+        let rewards = validator_rewards(&app, &bridge_addr, &public_key);
+        assert!(rewards.data.is_empty());
+
+        let err = withdraw_error(
+            &mut app,
+            withdraw_rewards_msg(&bridge_addr, &sk, &public_key, withdrawal(None, 2)),
+        );
+        assert!(
+            matches!(err, ContractError::NothingToWithdraw {}),
+            "{:?}",
+            err
+        );
+
+        let dust: GetRewardDustResponse = app
+            .wrap()
+            .query_wasm_smart(bridge_addr, &BridgeQueryMsg::GetRewardDust {})
+            .unwrap();
+        assert!(dust.data.is_empty());
+    }
+
+    #[test]
+    fn test_blacklist_forfeits_rewards() {
+        use secp256k1::rand::rngs::OsRng;
+        let mut app = mock_app();
+        let (sk2, pk2) = secp256k1::Secp256k1::new().generate_keypair(&mut OsRng);
+        let public_key2 = Binary::from(pk2.serialize());
+        let (bridge_addr, sk, public_key) = instantiate_bridge_with_config(&mut app, "uscrt", {
+            let public_key2 = public_key2.clone();
+            |msg| {
+                msg.validators
+                    .push((public_key2, Addr::unchecked("validator2")))
+            }
+        });
+        let claim = |app: &mut App,
+                     token_id: &str,
+                     signers: &[(&secp256k1::SecretKey, &Binary)]| {
+            let data = ClaimData {
+                token_id: token_id.to_string(),
+                ..default_claim_data()
+            };
+            let signatures = signers
+                .iter()
+                .flat_map(|(sk, key)| signed_claim(&bridge_addr, sk, key, data.clone()).signatures)
+                .collect();
+            app.execute_contract(
+                Addr::unchecked("deployer"),
+                bridge_addr.clone(),
+                &BridgeExecuteMsg::Claim721 {
+                    data: ClaimMsg { data, signatures },
+                },
+                &[Coin::new(1000, "uscrt")],
+            )
+        };
+
+        let res = claim(&mut app, "1", &[(&sk, &public_key), (&sk2, &public_key2)]);
+        assert!(res.is_ok(), "claim failed: {:?}", res);
+        let rewards = validator_rewards(&app, &bridge_addr, &public_key2);
+        assert_eq!(rewards.data, vec![Coin::new(500, "uscrt")]);
+
+        let res = app
+            .execute_contract(
+                Addr::unchecked("anyone"),
+                bridge_addr.clone(),
+                &BridgeExecuteMsg::BlacklistValidator {
+                    data: BlacklistValidatorMsg {
+                        validator: (public_key2.clone(), Addr::unchecked("validator2")),
+                        signatures: vec![
+                            sign_blacklisting(&bridge_addr, &sk, &public_key, &public_key2),
+                            sign_blacklisting(&bridge_addr, &sk2, &public_key2, &public_key2),
+                        ],
+                    },
+                },
+                &[],
+            )
+            .unwrap();
+        let event = res
+            .events
+            .iter()
+            .flat_map(|e| e.attributes.iter())
+            .find(|a| a.key == "BlacklistValidatorEventInfo")
+            .unwrap();
+        let event: BlacklistValidatorEventInfo = cosmwasm_std::from_json(&event.value).unwrap();
+        assert_eq!(event.forfeited_rewards, vec![Coin::new(500, "uscrt")]);
+        assert!(validator_rewards(&app, &bridge_addr, &public_key2)
+            .data
+            .is_empty());
+        let dust: GetRewardDustResponse = app
+            .wrap()
+            .query_wasm_smart(bridge_addr.clone(), &BridgeQueryMsg::GetRewardDust {})
+            .unwrap();
+        assert_eq!(dust.data, vec![Coin::new(500, "uscrt")]);
+
+        // the next fee carries the forfeited rewards to its signers
+        let res = claim(&mut app, "2", &[(&sk, &public_key)]);
+        assert!(res.is_ok(), "claim failed: {:?}", res);
+        let rewards = validator_rewards(&app, &bridge_addr, &public_key);
+        assert_eq!(rewards.data, vec![Coin::new(2000, "uscrt")]);
+    }
+
+    #[test]
+    fn test_upgrade_child_code() {
+        let mut app = mock_app();
+        let (bridge_addr, sk, public_key) = instantiate_bridge_with_validator(&mut app, "uscrt");
+        let collection_code_id = app.store_code(Box::new(collection_code_wrapper()));
+        let new_storage_code_id = app.store_code(Box::new(store_code_wrapper()));
+
+        let upgrade_to = |code_id: u64, nonce: u64| ChildCodeUpgrade {
+            child: ChildContract::Storage721,
+            code_id,
+            nonce,
+        };
+        let upgrade = upgrade_to(new_storage_code_id, 0);
+        let upgrade_msg =
+            |signatures: Vec<SignerAndSignature>| BridgeExecuteMsg::UpgradeChildCode {
+                data: UpgradeChildCodeMsg {
+                    upgrade: upgrade.clone(),
+                    signatures,
+                },
+            };
+        let signed_upgrade = |app: &mut App, upgrade: ChildCodeUpgrade| {
+            let signatures = governance_signatures(&bridge_addr, &sk, &public_key, &upgrade);
+            app.execute_contract(
+                Addr::unchecked("deployer"),
+                bridge_addr.clone(),
+                &BridgeExecuteMsg::UpgradeChildCode {
+                    data: UpgradeChildCodeMsg {
+                        upgrade,
+                        signatures,
+                    },
+                },
+                &[],
+            )
+        };
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("deployer"),
+                bridge_addr.clone(),
+                &upgrade_msg(vec![]),
+                &[],
+            )
+            .unwrap_err()
+            .downcast::<ContractError>()
+            .unwrap();
+        assert!(
+            matches!(err, ContractError::ThresholdNotReached { got: 0, need: 1 }),
+            "{:?}",
+            err
+        );
+
+        // signed for another bridge
+        let other_bridge = Addr::unchecked("other_bridge");
+        let err = app
+            .execute_contract(
+                Addr::unchecked("deployer"),
+                bridge_addr.clone(),
+                &upgrade_msg(governance_signatures(
+                    &other_bridge,
+                    &sk,
+                    &public_key,
+                    &upgrade,
+                )),
+                &[],
+            )
+            .unwrap_err()
+            .downcast::<ContractError>()
+            .unwrap();
+        assert!(
+            matches!(err, ContractError::ThresholdNotReached { got: 0, need: 1 }),
+            "{:?}",
+            err
+        );
+
+        let signatures = governance_signatures(&bridge_addr, &sk, &public_key, &upgrade);
+        let res = app.execute_contract(
+            Addr::unchecked("deployer"),
+            bridge_addr.clone(),
+            &upgrade_msg(signatures.clone()),
+            &[],
+        );
+        assert!(res.is_ok(), "upgrade failed: {:?}", res);
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("deployer"),
+                bridge_addr.clone(),
+                &upgrade_msg(signatures),
+                &[],
+            )
+            .unwrap_err()
+            .downcast::<ContractError>()
+            .unwrap();
+        assert!(
+            matches!(
+                err,
+                ContractError::InvalidUpgradeNonce {
+                    expected: 1,
+                    found: 0
+                }
+            ),
+            "{:?}",
+            err
+        );
+
+        // an upgrade can be rolled back to an earlier code id
+        let other_storage_code_id = app.store_code(Box::new(store_code_wrapper()));
+        let res = signed_upgrade(&mut app, upgrade_to(other_storage_code_id, 1));
+        assert!(res.is_ok(), "upgrade failed: {:?}", res);
+        let res = signed_upgrade(&mut app, upgrade_to(new_storage_code_id, 2));
+        assert!(res.is_ok(), "rollback failed: {:?}", res);
+        let nonce: GetChildCodeUpgradeNonceResponse = app
+            .wrap()
+            .query_wasm_smart(
+                bridge_addr.clone(),
+                &BridgeQueryMsg::GetChildCodeUpgradeNonce {},
+            )
+            .unwrap();
+        assert_eq!(nonce.nonce, 3);
+
+        // storages deployed from now on use the new code
+        let nft_minter = Addr::unchecked("nft_minter");
+        let nft_contract =
+            mint_approved_nft(&mut app, collection_code_id, &bridge_addr, &nft_minter);
+        let lock = app.execute_contract(
+            nft_minter,
+            bridge_addr.clone(),
+            &BridgeExecuteMsg::Lock721 {
+                data: Lock721Msg {
+                    collection_code_id,
+                    destination_chain: "BSC".to_string(),
+                    destination_user_address: BSC_USER.to_string(),
+                    source_nft_contract_address: nft_contract.to_string(),
+                    token_id: "1".to_string(),
+                },
+            },
+            &[],
+        );
+        assert!(lock.is_ok(), "lock failed: {:?}", lock);
+
+        let storage: GetStorageResponse = app
+            .wrap()
+            .query_wasm_smart(
+                bridge_addr.clone(),
+                &BridgeQueryMsg::GetOriginalStorage721 {
+                    contract_address: nft_contract.to_string(),
+                    chain: "cosmos".to_string(),
+                },
+            )
+            .unwrap();
+        let storage = app.contract_data(&storage.data.unwrap()).unwrap();
+        assert_eq!(storage.code_id, new_storage_code_id);
+        assert_eq!(storage.admin, Some(bridge_addr));
+    }
+
+    #[test]
+    fn test_list_queries() {
+        let mut app = mock_app();
+        let (bridge_addr, sk, public_key) = instantiate_bridge_with_validator(&mut app, "uscrt");
+
+        let config: State = app
+            .wrap()
+            .query_wasm_smart(bridge_addr.clone(), &BridgeQueryMsg::Config {})
+            .unwrap();
+        assert_eq!(config.self_chain, "cosmos");
+        assert_eq!(config.fee_denom, "uscrt");
+        assert_eq!(config.validators_count, 1);
+
+        let validators: ListValidatorsResponse = app
+            .wrap()
+            .query_wasm_smart(
+                bridge_addr.clone(),
+                &BridgeQueryMsg::ListValidators {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(validators.data.len(), 1);
+        assert_eq!(validators.data[0].public_key, public_key);
+        assert!(validators.data[0].validator.added);
+
+        let validators: ListValidatorsResponse = app
+            .wrap()
+            .query_wasm_smart(
+                bridge_addr.clone(),
+                &BridgeQueryMsg::ListValidators {
+                    start_after: Some(public_key.clone()),
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert!(validators.data.is_empty());
+
+        let blacklisted: ListBlacklistedResponse = app
+            .wrap()
+            .query_wasm_smart(
+                bridge_addr.clone(),
+                &BridgeQueryMsg::ListBlacklisted {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert!(blacklisted.data.is_empty());
+
+        for (contract, tx) in [("bruh", "0xabc123"), ("bruh2", "0xabc456")] {
+            let data = ClaimData {
+                source_nft_contract_address: contract.to_string(),
+                transaction_hash: tx.to_string(),
+                ..default_claim_data()
+            };
+            let claim = app.execute_contract(
+                Addr::unchecked("deployer"),
+                bridge_addr.clone(),
+                &BridgeExecuteMsg::Claim721 {
+                    data: signed_claim(&bridge_addr, &sk, &public_key, data),
+                },
+                &[Coin::new(1000, "uscrt")],
+            );
+            assert!(claim.is_ok(), "claim failed: {:?}", claim);
+        }
+
+        let list_mappings = |app: &App, kind: MappingKind, start_after| {
+            let response: ListMappingsResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    bridge_addr.clone(),
+                    &BridgeQueryMsg::ListMappings {
+                        kind,
+                        start_after,
+                        limit: Some(1),
+                    },
+                )
+                .unwrap();
+            response.data
+        };
+
+        let first = list_mappings(&app, MappingKind::OriginalToDuplicate, None);
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].contract_address, "bruh");
+        assert_eq!(first[0].chain, "BSC");
+        assert_eq!(first[0].mapped_chain, "cosmos");
+
+        let second = list_mappings(
+            &app,
+            MappingKind::OriginalToDuplicate,
+            Some((first[0].contract_address.clone(), first[0].chain.clone())),
+        );
+        assert_eq!(second.len(), 1);
+        assert_eq!(second[0].contract_address, "bruh2");
+
+        let reverse = list_mappings(
+            &app,
+            MappingKind::DuplicateToOriginal,
+            Some((
+                first[0].mapped_contract_address.clone(),
+                "cosmos".to_string(),
+            )),
+        );
+        assert_eq!(reverse.len(), 1);
+        assert_eq!(reverse[0].mapped_chain, "BSC");
+        assert_eq!(reverse[0].mapped_contract_address, "bruh2");
+
+        let storages: ListStoragesResponse = app
+            .wrap()
+            .query_wasm_smart(
+                bridge_addr,
+                &BridgeQueryMsg::ListStorages {
+                    kind: StorageKind::Duplicate721,
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert!(storages.data.is_empty());
+    }
+
+    #[test]
+    fn test_claim_status() {
+        let mut app = mock_app();
+        let (bridge_addr, sk, public_key) = instantiate_bridge_with_validator(&mut app, "uscrt");
+
+        let claim_status = |app: &App, transaction_hash: &str| {
+            let response: GetClaimStatusResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    bridge_addr.clone(),
+                    &BridgeQueryMsg::GetClaimStatus {
+                        lock_tx_chain: "BSC".to_string(),
+                        transaction_hash: transaction_hash.to_string(),
+                    },
+                )
+                .unwrap();
+            response.data
+        };
+        assert!(claim_status(&app, "0xabc123").is_empty());
+
+        // both tokens were locked in the same transaction
+        for (token_id, tx) in [("1", "0xabc123"), ("2", "0xabc123")] {
+            let data = ClaimData {
+                token_id: token_id.to_string(),
+                transaction_hash: tx.to_string(),
+                ..default_claim_data()
+            };
+            let claim = app.execute_contract(
+                Addr::unchecked("deployer"),
+                bridge_addr.clone(),
+                &BridgeExecuteMsg::Claim721 {
+                    data: signed_claim(&bridge_addr, &sk, &public_key, data),
+                },
+                &[Coin::new(1000, "uscrt")],
+            );
+            assert!(claim.is_ok(), "claim failed: {:?}", claim);
+        }
+
+        let duplicate: GetOriginalToDuplicateResponse = app
+            .wrap()
+            .query_wasm_smart(
+                bridge_addr.clone(),
+                &BridgeQueryMsg::GetOriginalToDuplicate {
+                    contract_address: "bruh".to_string(),
+                    chain: "BSC".to_string(),
+                },
+            )
+            .unwrap();
+        let collection = duplicate.data.unwrap().contract_address;
+
+        // first claim deployed the collection, second minted on it. The lock
+        // transaction alone answers for both.
+        let records = claim_status(&app, "0xabc123");
+        assert_eq!(records.len(), 2);
+        for (record, token_id) in records.into_iter().zip(["1", "2"]) {
+            assert_eq!(record.lock_tx_chain, "BSC");
+            assert_eq!(record.transaction_hash, "0xabc123");
+            assert_eq!(record.source_nft_contract_address, "bruh");
+            assert_eq!(record.recipient, Addr::unchecked("claimer"));
+            assert_eq!(record.contract, Some(collection.clone()));
+            assert_eq!(record.token_id, token_id);
+            assert_eq!(record.height, app.block_info().height);
+        }
+
+        let claims: ListClaimsResponse = app
+            .wrap()
+            .query_wasm_smart(
+                bridge_addr,
+                &BridgeQueryMsg::ListClaims {
+                    start_after: Some((
+                        "BSC".to_string(),
+                        "0xabc123".to_string(),
+                        ("bruh".to_string(), "1".to_string()),
+                    )),
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(claims.data.len(), 1);
+        assert_eq!(claims.data[0].token_id, "2");
+    }
+
+    #[test]
+    fn test_claim_hash_transition() {
+        use sha2::Digest;
+        let mut app = mock_app();
+        let height = app.block_info().height;
+        let (bridge_addr, sk, public_key) = instantiate_bridge(&mut app, "uscrt", Some(height));
+
+        let legacy_hash_of = |data: &ClaimData| -> [u8; 32] {
+            let mut hasher = sha2::Sha256::new();
+            hasher.update(data.concat_all_fields());
+            hasher.finalize().into()
+        };
+        let legacy_signed_claim = |data: ClaimData| {
+            let signature = secp256k1::Secp256k1::new()
+                .sign_ecdsa(&secp256k1::Message::from_digest(legacy_hash_of(&data)), &sk)
+                .serialize_compact();
+            ClaimMsg {
+                data,
+                signatures: vec![SignerAndSignature {
+                    signature: Binary::from(signature),
+                    signer_address: public_key.clone(),
+                }],
+            }
+        };
+        let compute = |app: &App, data: &ClaimData| -> ComputeClaimHashResponse {
+            app.wrap()
+                .query_wasm_smart(
+                    bridge_addr.clone(),
+                    &BridgeQueryMsg::ComputeClaimHash { data: data.clone() },
+                )
+                .unwrap()
+        };
+
+        // the legacy concatenation is ambiguous, the canonical encoding is not
+        let collides = ClaimData {
+            token_id: "12".to_string(),
+            source_chain: "3BSC".to_string(),
+            ..default_claim_data()
+        };
+        let collides_with = ClaimData {
+            token_id: "123".to_string(),
+            source_chain: "BSC".to_string(),
+            ..default_claim_data()
+        };
+        assert_eq!(legacy_hash_of(&collides), legacy_hash_of(&collides_with));
+        assert_ne!(
+            claim_data_hash(&bridge_addr, &collides),
+            claim_data_hash(&bridge_addr, &collides_with)
+        );
+
+        let data = default_claim_data();
+        let computed = compute(&app, &data);
+        assert_eq!(
+            computed.encoding,
+            Binary::from(data.canonical_encoding(bridge_addr.as_str(), "cosmos"))
+        );
+        assert_eq!(
+            computed.hash,
+            Binary::from(claim_data_hash(&bridge_addr, &data))
+        );
+        assert_eq!(computed.legacy_hash, Binary::from(legacy_hash_of(&data)));
+        assert!(computed.legacy_accepted);
+
+        let claim = app.execute_contract(
+            Addr::unchecked("deployer"),
+            bridge_addr.clone(),
+            &BridgeExecuteMsg::Claim721 {
+                data: legacy_signed_claim(data.clone()),
+            },
+            &[Coin::new(1000, "uscrt")],
+        );
+        assert!(claim.is_ok(), "legacy claim failed: {:?}", claim);
+
+        // the same claim can't be replayed under the canonical encoding
+        let err = claim_721_error(
+            &mut app,
+            &bridge_addr,
+            signed_claim(&bridge_addr, &sk, &public_key, data),
+            &[Coin::new(1000, "uscrt")],
+        );
+        assert!(
+            matches!(err, ContractError::AlreadyProcessed { .. }),
+            "{:?}",
+            err
+        );
+        let claim = app.execute_contract(
+            Addr::unchecked("deployer"),
+            bridge_addr.clone(),
+            &BridgeExecuteMsg::Claim721 {
+                data: legacy_signed_claim(collides.clone()),
+            },
+            &[Coin::new(1000, "uscrt")],
+        );
+        assert!(claim.is_ok(), "legacy claim failed: {:?}", claim);
+
+        app.update_block(|block| block.height += 1);
+        // once the window closed, a legacy collision no longer blocks a claim
+        let claim = app.execute_contract(
+            Addr::unchecked("deployer"),
+            bridge_addr.clone(),
+            &BridgeExecuteMsg::Claim721 {
+                data: signed_claim(&bridge_addr, &sk, &public_key, collides_with.clone()),
+            },
+            &[Coin::new(1000, "uscrt")],
+        );
+        assert!(claim.is_ok(), "canonical claim failed: {:?}", claim);
+
+        let data = ClaimData {
+            token_id: "2".to_string(),
+            transaction_hash: "0xdef456".to_string(),
+            ..default_claim_data()
+        };
+        assert!(!compute(&app, &data).legacy_accepted);
+
+        let err = claim_721_error(
+            &mut app,
+            &bridge_addr,
+            legacy_signed_claim(data.clone()),
+            &[Coin::new(1000, "uscrt")],
+        );
+        assert!(
+            matches!(err, ContractError::ThresholdNotReached { got: 0, need: 1 }),
+            "{:?}",
+            err
+        );
+
+        let claim = app.execute_contract(
+            Addr::unchecked("deployer"),
+            bridge_addr.clone(),
+            &BridgeExecuteMsg::Claim721 {
+                data: signed_claim(&bridge_addr, &sk, &public_key, data),
+            },
+            &[Coin::new(1000, "uscrt")],
+        );
+        assert!(claim.is_ok(), "canonical claim failed: {:?}", claim);
+    }
+
+    #[test]
+    fn test_pause_matrix() {
+        let mut app = mock_app();
+        let (bridge_addr, sk, public_key) = instantiate_bridge_with_validator(&mut app, "uscrt");
+
+        let set_paused = |app: &mut App, update: PauseUpdate, signed: bool| {
+            let signatures = if signed {
+                governance_signatures(&bridge_addr, &sk, &public_key, &update)
+            } else {
+                vec![]
+            };
+            app.execute_contract(
+                Addr::unchecked("deployer"),
+                bridge_addr.clone(),
+                &BridgeExecuteMsg::SetPaused {
+                    data: SetPausedMsg { update, signatures },
+                },
+                &[],
+            )
+        };
+        let lock = |app: &mut App, destination_chain: &str| -> ContractError {
+            app.execute_contract(
+                Addr::unchecked("deployer"),
+                bridge_addr.clone(),
+                &BridgeExecuteMsg::Lock721 {
+                    data: Lock721Msg {
+                        collection_code_id: 1,
+                        destination_chain: destination_chain.to_string(),
+                        destination_user_address: BSC_USER.to_string(),
+                        source_nft_contract_address: "nft".to_string(),
+                        token_id: "1".to_string(),
+                    },
+                },
+                &[],
+            )
+            .unwrap_err()
+            .downcast()
+            .unwrap()
+        };
+        let event = |res: &cw_multi_test::AppResponse, key: &str| {
+            res.events
+                .iter()
+                .flat_map(|e| e.attributes.iter())
+                .find(|a| a.key == key)
+                .map(|a| a.value.clone())
+        };
+
+        let update = PauseUpdate {
+            locks: true,
+            claims: false,
+            disabled_destination_chains: vec!["BSC".to_string()],
+            disabled_source_chains: vec!["ETH".to_string()],
+            nonce: 0,
+        };
+        let err = set_paused(&mut app, update.clone(), false)
+            .unwrap_err()
+            .downcast::<ContractError>()
+            .unwrap();
+        assert!(
+            matches!(err, ContractError::ThresholdNotReached { got: 0, need: 1 }),
+            "{:?}",
+            err
+        );
+
+        let res = set_paused(&mut app, update.clone(), true).unwrap();
+        let paused: PausedEventInfo =
+            from_json(event(&res, "PausedEventInfo").expect("Not found PausedEventInfo")).unwrap();
+        assert!(paused.locks);
+        assert!(!paused.claims);
+        assert_eq!(paused.destination_chains, vec!["BSC".to_string()]);
+        assert_eq!(paused.source_chains, vec!["ETH".to_string()]);
+        assert!(event(&res, "UnpausedEventInfo").is_none());
+
+        // an applied update can't be replayed
+        let err = set_paused(&mut app, update, true)
+            .unwrap_err()
+            .downcast::<ContractError>()
+            .unwrap();
+        assert!(
+            matches!(
+                err,
+                ContractError::InvalidPauseNonce {
+                    expected: 1,
+                    found: 0
+                }
+            ),
+            "{:?}",
+            err
+        );
+
+        let pause: PauseMatrix = app
+            .wrap()
+            .query_wasm_smart(bridge_addr.clone(), &BridgeQueryMsg::GetPauseMatrix {})
+            .unwrap();
+        assert!(pause.locks);
+        assert_eq!(pause.nonce, 1);
+
+        let err = lock(&mut app, "ETH");
+        assert!(matches!(err, ContractError::LocksPaused {}), "{:?}", err);
+
+        let err = claim_721_error(
+            &mut app,
+            &bridge_addr,
+            signed_claim(
+                &bridge_addr,
+                &sk,
+                &public_key,
+                ClaimData {
+                    source_chain: "ETH".to_string(),
+                    ..default_claim_data()
+                },
+            ),
+            &[Coin::new(1000, "uscrt")],
+        );
+        assert!(
+            matches!(&err, ContractError::SourceChainDisabled { chain } if chain == "ETH"),
+            "{:?}",
+            err
+        );
+        let claim = app.execute_contract(
+            Addr::unchecked("deployer"),
+            bridge_addr.clone(),
+            &BridgeExecuteMsg::Claim721 {
+                data: signed_claim(&bridge_addr, &sk, &public_key, default_claim_data()),
+            },
+            &[Coin::new(1000, "uscrt")],
+        );
+        assert!(claim.is_ok(), "claim failed: {:?}", claim);
+
+        let res = set_paused(
+            &mut app,
+            PauseUpdate {
+                locks: false,
+                claims: true,
+                disabled_destination_chains: vec!["BSC".to_string()],
+                disabled_source_chains: vec![],
+                nonce: 1,
+            },
+            true,
+        )
+        .unwrap();
+        let unpaused: UnpausedEventInfo =
+            from_json(event(&res, "UnpausedEventInfo").expect("Not found UnpausedEventInfo"))
+                .unwrap();
+        assert!(unpaused.locks);
+        assert!(unpaused.destination_chains.is_empty());
+        assert_eq!(unpaused.source_chains, vec!["ETH".to_string()]);
+        let paused: PausedEventInfo =
+            from_json(event(&res, "PausedEventInfo").expect("Not found PausedEventInfo")).unwrap();
+        assert!(paused.claims);
+
+        let err = lock(&mut app, "BSC");
+        assert!(
+            matches!(&err, ContractError::DestinationChainDisabled { chain } if chain == "BSC"),
+            "{:?}",
+            err
+        );
+
+        let err = claim_721_error(
+            &mut app,
+            &bridge_addr,
+            signed_claim(
+                &bridge_addr,
+                &sk,
+                &public_key,
+                ClaimData {
+                    transaction_hash: "0xdef456".to_string(),
+                    ..default_claim_data()
+                },
+            ),
+            &[Coin::new(1000, "uscrt")],
+        );
+        assert!(matches!(err, ContractError::ClaimsPaused {}), "{:?}", err);
+    }
+
+    #[test]
+    fn test_supported_chains() {
+        let mut app = mock_app();
+        let (bridge_addr, sk, public_key) = instantiate_bridge_with_validator(&mut app, "uscrt");
+        let collection_code_id = app.store_code(Box::new(collection_code_wrapper()));
+        let nft_minter = Addr::unchecked("nft_minter");
+        let nft_contract =
+            mint_approved_nft(&mut app, collection_code_id, &bridge_addr, &nft_minter);
+        app.sudo(cw_multi_test::SudoMsg::Bank(
+            cw_multi_test::BankSudo::Mint {
+                to_address: nft_minter.to_string(),
+                amount: vec![Coin::new(100, "uscrt")],
+            },
+        ))
+        .unwrap();
+
+        let set_supported_chain = |app: &mut App, update: SupportedChainUpdate| {
+            let signatures = governance_signatures(&bridge_addr, &sk, &public_key, &update);
+            app.execute_contract(
+                Addr::unchecked("deployer"),
+                bridge_addr.clone(),
+                &BridgeExecuteMsg::SetSupportedChain {
+                    data: SetSupportedChainMsg { update, signatures },
+                },
+                &[],
+            )
+        };
+        let lock = |app: &mut App, chain: &str, address: &str, funds: &[Coin]| {
+            app.execute_contract(
+                nft_minter.clone(),
+                bridge_addr.clone(),
+                &BridgeExecuteMsg::Lock721 {
+                    data: Lock721Msg {
+                        collection_code_id,
+                        destination_chain: chain.to_string(),
+                        destination_user_address: address.to_string(),
+                        source_nft_contract_address: nft_contract.to_string(),
+                        token_id: "1".to_string(),
+                    },
+                },
+                funds,
+            )
+        };
+        let lock_error = |app: &mut App, chain: &str, address: &str| -> ContractError {
+            lock(app, chain, address, &[])
+                .unwrap_err()
+                .downcast()
+                .unwrap()
+        };
+
+        let err = lock_error(&mut app, "ETHEREM", BSC_USER);
+        assert!(
+            matches!(&err, ContractError::UnsupportedChain { chain } if chain == "ETHEREM"),
+            "{:?}",
+            err
+        );
+        let err = lock_error(&mut app, "BSC", "0xabc123");
+        assert!(
+            matches!(err, ContractError::InvalidDestinationAddress { .. }),
+            "{:?}",
+            err
+        );
+
+        let eth = SupportedChain {
+            address_format: AddressFormat::Hex20,
+            lock_fee: Some(Uint128::new(100)),
+            enabled: true,
+        };
+        let res = set_supported_chain(
+            &mut app,
+            SupportedChainUpdate {
+                chain: "ETH".to_string(),
+                config: eth.clone(),
+                nonce: 0,
+            },
+        )
+        .unwrap();
+        assert!(res
+            .events
+            .iter()
+            .flat_map(|e| e.attributes.iter())
+            .any(|a| a.key == "SupportedChainUpdatedEventInfo"));
+
+        let err = set_supported_chain(
+            &mut app,
+            SupportedChainUpdate {
+                chain: "ETH".to_string(),
+                config: eth,
+                nonce: 0,
+            },
+        )
+        .unwrap_err()
+        .downcast::<ContractError>()
+        .unwrap();
+        assert!(
+            matches!(
+                err,
+                ContractError::InvalidSupportedChainNonce {
+                    expected: 1,
+                    found: 0
+                }
+            ),
+            "{:?}",
+            err
+        );
+
+        let chains: ListSupportedChainsResponse = app
+            .wrap()
+            .query_wasm_smart(
+                bridge_addr.clone(),
+                &BridgeQueryMsg::ListSupportedChains {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(chains.nonce, 1);
+        let names: Vec<_> = chains.data.iter().map(|e| e.chain.as_str()).collect();
+        assert_eq!(names, vec!["BSC", "ETH"]);
+
+        let err = lock_error(&mut app, "ETH", BSC_USER);
+        assert!(matches!(err, ContractError::FeeTooLow { .. }), "{:?}", err);
+        let res = lock(&mut app, "ETH", BSC_USER, &[Coin::new(100, "uscrt")]);
+        assert!(res.is_ok(), "lock failed: {:?}", res);
+
+        set_supported_chain(
+            &mut app,
+            SupportedChainUpdate {
+                chain: "BSC".to_string(),
+                config: SupportedChain {
+                    address_format: AddressFormat::Hex20,
+                    lock_fee: None,
+                    enabled: false,
+                },
+                nonce: 1,
+            },
+        )
+        .unwrap();
+        let err = lock_error(&mut app, "BSC", BSC_USER);
+        assert!(
+            matches!(err, ContractError::ChainNotEnabled { .. }),
+            "{:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_address_formats() {
+        let secret = AddressFormat::Bech32 {
+            prefix: "secret".to_string(),
+        };
+        assert!(secret.is_valid("secret1w5fw0m5cad30lsu8x65m57ad5s80f0fmg3jfal"));
+        assert!(!secret.is_valid("secret1w5fw0m5cad30lsu8x65m57ad5s80f0fmg3jfam"));
+        assert!(!secret.is_valid("cosmos1w5fw0m5cad30lsu8x65m57ad5s80f0fmg3jfal"));
+
+        assert!(AddressFormat::Hex20.is_valid(BSC_USER));
+        assert!(!AddressFormat::Hex20.is_valid(&BSC_USER[2..]));
+        assert!(!AddressFormat::Hex20.is_valid("0x5B38Da6a701c568545dCfcB03FcB875f56beddCG"));
+
+        assert!(AddressFormat::Base58.is_valid("7EcDhSYGxXyscszYEp35KHN8vvw3svAuLKTzXwCFLtV"));
+        assert!(!AddressFormat::Base58.is_valid("0EcDhSYGxXyscszYEp35KHN8vvw3svAuLKTzXwCFLtV"));
+
+        let near = AddressFormat::NearAccountId;
+        assert!(near.is_valid("alice.near"));
+        assert!(near.is_valid("xp_bridge-1.testnet"));
+        assert!(!near.is_valid("Alice.near"));
+        assert!(!near.is_valid("alice..near"));
+        assert!(!near.is_valid(".alice"));
+        assert!(!near.is_valid("a"));
+    }
+
+    #[test]
+    fn test_receive_nft() {
+        let mut app = mock_app();
+        let (bridge_addr, _, _) = instantiate_bridge_with_config(&mut app, "uscrt", |msg| {
+            msg.supported_chains[0].1.lock_fee = Some(Uint128::new(100))
+        });
+        let collection_code_id = app.store_code(Box::new(collection_code_wrapper()));
+        let nft_minter = Addr::unchecked("nft_minter");
+        let nft_contract =
+            mint_approved_nft(&mut app, collection_code_id, &bridge_addr, &nft_minter);
+        app.sudo(cw_multi_test::SudoMsg::Bank(
+            cw_multi_test::BankSudo::Mint {
+                to_address: nft_minter.to_string(),
+                amount: vec![Coin::new(300, "uscrt")],
+            },
+        ))
+        .unwrap();
+        // no approval for this one, the hook must not need it
+        app.execute_contract(
+            nft_minter.clone(),
+            nft_contract.clone(),
+            &cosm_nft::NftExecuteMsg::Mint {
+                token_id: "2".to_owned(),
+                owner: nft_minter.to_string(),
+                token_uri: None,
+                extension: RoyaltyData::default(),
+            },
+            &[],
+        )
+        .unwrap();
+
+        let lock_msg = |token_id: &str| Lock721Msg {
+            collection_code_id,
+            destination_chain: "BSC".to_string(),
+            destination_user_address: BSC_USER.to_string(),
+            source_nft_contract_address: nft_contract.to_string(),
+            token_id: token_id.to_string(),
+        };
+        let send_nft = |app: &mut App, token_id: &str, payload: &Lock721Msg| {
+            app.execute_contract(
+                nft_minter.clone(),
+                nft_contract.clone(),
+                &cosm_nft::NftExecuteMsg::SendNft {
+                    contract: bridge_addr.to_string(),
+                    token_id: token_id.to_string(),
+                    msg: cosmwasm_std::to_json_binary(payload).unwrap(),
+                },
+                &[],
+            )
+        };
+        let owner_of = |app: &App, token_id: &str| -> String {
+            let res: cw721::OwnerOfResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    nft_contract.clone(),
+                    &cosm_nft::NftQueryMsg::OwnerOf {
+                        token_id: token_id.to_string(),
+                        include_expired: None,
+                    },
+                )
+                .unwrap();
+            res.owner
+        };
+
+        let err = send_nft(&mut app, "2", &lock_msg("1"))
+            .unwrap_err()
+            .downcast::<ContractError>()
+            .unwrap();
+        assert!(
+            matches!(err, ContractError::ReceivedNftMismatch { .. }),
+            "{:?}",
+            err
+        );
+        assert_eq!(owner_of(&app, "2"), nft_minter.to_string());
+
+        // hooks can only be called by the collection that holds the token
+        let err = app
+            .execute_contract(
+                nft_minter.clone(),
+                bridge_addr.clone(),
+                &BridgeExecuteMsg::ReceiveNft(cw721::Cw721ReceiveMsg {
+                    sender: nft_minter.to_string(),
+                    token_id: "2".to_string(),
+                    msg: cosmwasm_std::to_json_binary(&lock_msg("2")).unwrap(),
+                }),
+                &[],
+            )
+            .unwrap_err()
+            .downcast::<ContractError>()
+            .unwrap();
+        assert!(
+            matches!(err, ContractError::ReceivedNftMismatch { .. }),
+            "{:?}",
+            err
+        );
+
+        // the collection sends no funds, the fee has to be prepaid
+        let err = send_nft(&mut app, "2", &lock_msg("2"))
+            .unwrap_err()
+            .downcast::<ContractError>()
+            .unwrap();
+        assert!(
+            matches!(err, ContractError::FeeTooLow { sent, required }
+                if sent.is_zero() && required == Uint128::new(100)),
+            "{:?}",
+            err
+        );
+        assert_eq!(owner_of(&app, "2"), nft_minter.to_string());
+
+        let deposit = |app: &mut App, funds: &[Coin]| {
+            app.execute_contract(
+                nft_minter.clone(),
+                bridge_addr.clone(),
+                &BridgeExecuteMsg::DepositLockFees {},
+                funds,
+            )
+        };
+        let err = deposit(&mut app, &[])
+            .unwrap_err()
+            .downcast::<ContractError>()
+            .unwrap();
+        assert!(matches!(err, ContractError::NoFundsSent {}), "{:?}", err);
+        deposit(&mut app, &[Coin::new(250, "uscrt")]).unwrap();
+
+        // first lock deploys the storage, the second reuses it
+        let res = send_nft(&mut app, "2", &lock_msg("2"));
+        assert!(res.is_ok(), "lock failed: {:?}", res);
+        let res = send_nft(&mut app, "1", &lock_msg("1"));
+        assert!(res.is_ok(), "lock failed: {:?}", res);
+        let prepaid: GetPrepaidLockFeesResponse = app
+            .wrap()
+            .query_wasm_smart(
+                bridge_addr.clone(),
+                &BridgeQueryMsg::GetPrepaidLockFees {
+                    address: nft_minter.to_string(),
+                },
+            )
+            .unwrap();
+        assert_eq!(prepaid.data, vec![Coin::new(50, "uscrt")]);
+
+        let withdraw = |app: &mut App| {
+            app.execute_contract(
+                nft_minter.clone(),
+                bridge_addr.clone(),
+                &BridgeExecuteMsg::WithdrawLockFees {},
+                &[],
+            )
+        };
+        withdraw(&mut app).unwrap();
+        let balance = app.wrap().query_balance(&nft_minter, "uscrt").unwrap();
+        assert_eq!(balance.amount, Uint128::new(100));
+        let err = withdraw(&mut app)
+            .unwrap_err()
+            .downcast::<ContractError>()
+            .unwrap();
+        assert!(
+            matches!(err, ContractError::NothingToWithdraw {}),
+            "{:?}",
+            err
+        );
+
+        let storage: GetStorageResponse = app
+            .wrap()
+            .query_wasm_smart(
+                bridge_addr.clone(),
+                &BridgeQueryMsg::GetOriginalStorage721 {
+                    contract_address: nft_contract.to_string(),
+                    chain: "cosmos".to_string(),
+                },
+            )
+            .unwrap();
+        let storage = storage.data.unwrap();
+        assert_eq!(owner_of(&app, "1"), storage.to_string());
+        assert_eq!(owner_of(&app, "2"), storage.to_string());
+    }
+
+    fn count_attributes(res: &cw_multi_test::AppResponse, key: &str) -> usize {
+        res.events
+            .iter()
+            .flat_map(|e| e.attributes.iter())
+            .filter(|a| a.key == key)
+            .count()
+    }
+
+    #[test]
+    fn test_batch_lock() {
+        let mut app = mock_app();
+        let (bridge_addr, _, _) = instantiate_bridge_with_validator(&mut app, "uscrt");
+        let collection_code_id = app.store_code(Box::new(collection_code_wrapper()));
+        let nft_minter = Addr::unchecked("nft_minter");
+        let nft_contract =
+            mint_approved_nft(&mut app, collection_code_id, &bridge_addr, &nft_minter);
+        for token_id in ["2", "3", "4"] {
+            app.execute_contract(
+                nft_minter.clone(),
+                nft_contract.clone(),
+                &cosm_nft::NftExecuteMsg::Mint {
+                    token_id: token_id.to_owned(),
+                    owner: nft_minter.to_string(),
+                    token_uri: None,
+                    extension: RoyaltyData::default(),
+                },
+                &[],
+            )
+            .unwrap();
+        }
+        app.execute_contract(
+            nft_minter.clone(),
+            nft_contract.clone(),
+            &cosm_nft::NftExecuteMsg::ApproveAll {
+                operator: bridge_addr.to_string(),
+                expires: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        let item = |token_id: &str| Lock721Msg {
+            collection_code_id,
+            destination_chain: "BSC".to_string(),
+            destination_user_address: BSC_USER.to_string(),
+            source_nft_contract_address: nft_contract.to_string(),
+            token_id: token_id.to_string(),
+        };
+        let batch_lock = |app: &mut App, items: Vec<Lock721Msg>| {
+            app.execute_contract(
+                nft_minter.clone(),
+                bridge_addr.clone(),
+                &BridgeExecuteMsg::BatchLock721 { items },
+                &[],
+            )
+        };
+        let owner_of = |app: &App, token_id: &str| -> String {
+            let res: cw721::OwnerOfResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    nft_contract.clone(),
+                    &cosm_nft::NftQueryMsg::OwnerOf {
+                        token_id: token_id.to_string(),
+                        include_expired: None,
+                    },
+                )
+                .unwrap();
+            res.owner
+        };
+
+        let config: State = app
+            .wrap()
+            .query_wasm_smart(bridge_addr.clone(), &BridgeQueryMsg::Config {})
+            .unwrap();
+        assert_eq!(config.max_batch_size, 25);
+        let err = batch_lock(&mut app, vec![])
+            .unwrap_err()
+            .downcast::<ContractError>()
+            .unwrap();
+        assert!(matches!(err, ContractError::EmptyBatch {}), "{:?}", err);
+        let err = batch_lock(&mut app, (0..26).map(|i| item(&i.to_string())).collect())
+            .unwrap_err()
+            .downcast::<ContractError>()
+            .unwrap();
+        assert!(
+            matches!(err, ContractError::BatchTooLarge { size: 26, max: 25 }),
+            "{:?}",
+            err
+        );
+
+        // the last item can't be transferred, so none of them are locked
+        let res = batch_lock(&mut app, vec![item("3"), item("missing")]);
+        assert!(res.is_err());
+        assert_eq!(owner_of(&app, "3"), nft_minter.to_string());
+
+        // the first item deploys the storage, the others wait for it
+        let res = batch_lock(&mut app, vec![item("1"), item("2"), item("3")]).unwrap();
+        assert_eq!(count_attributes(&res, "LockedEventInfo"), 3);
+
+        let storage: GetStorageResponse = app
+            .wrap()
+            .query_wasm_smart(
+                bridge_addr.clone(),
+                &BridgeQueryMsg::GetOriginalStorage721 {
+                    contract_address: nft_contract.to_string(),
+                    chain: "cosmos".to_string(),
+                },
+            )
+            .unwrap();
+        let storage = storage.data.unwrap();
+        for token_id in ["1", "2", "3"] {
+            assert_eq!(owner_of(&app, token_id), storage.to_string());
+        }
+        let storages: ListStoragesResponse = app
+            .wrap()
+            .query_wasm_smart(
+                bridge_addr.clone(),
+                &BridgeQueryMsg::ListStorages {
+                    kind: StorageKind::Original721,
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(storages.data.len(), 1);
+
+        // the storage exists now
+        batch_lock(&mut app, vec![item("4")]).unwrap();
+        assert_eq!(owner_of(&app, "4"), storage.to_string());
+    }
+
+    #[test]
+    fn test_batch_claim() {
+        let mut app = mock_app();
+        let (bridge_addr, sk, public_key) = instantiate_bridge_with_validator(&mut app, "uscrt");
+
+        let claim = |token_id: &str, transaction_hash: &str| {
+            signed_claim(
+                &bridge_addr,
+                &sk,
+                &public_key,
+                ClaimData {
+                    token_id: token_id.to_string(),
+                    transaction_hash: transaction_hash.to_string(),
+                    ..default_claim_data()
+                },
+            )
+        };
+        let batch_claim = |app: &mut App, claims: Vec<ClaimMsg>, funds: u128| {
+            app.execute_contract(
+                Addr::unchecked("deployer"),
+                bridge_addr.clone(),
+                &BridgeExecuteMsg::BatchClaim721 { claims },
+                &[Coin::new(funds, "uscrt")],
+            )
+        };
+        let claim_status = |app: &App, transaction_hash: &str| {
+            let response: GetClaimStatusResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    bridge_addr.clone(),
+                    &BridgeQueryMsg::GetClaimStatus {
+                        lock_tx_chain: "BSC".to_string(),
+                        transaction_hash: transaction_hash.to_string(),
+                    },
+                )
+                .unwrap();
+            response.data
+        };
+
+        let err = batch_claim(&mut app, vec![claim("1", "0x1"), claim("2", "0x2")], 1000)
+            .unwrap_err()
+            .downcast::<ContractError>()
+            .unwrap();
+        assert!(matches!(err, ContractError::FeeTooLow { .. }), "{:?}", err);
+
+        // the first claim deploys the collection, the others wait for it. The
+        // tokens were locked in one transaction, each keeps its own record.
+        let res = batch_claim(
+            &mut app,
+            vec![claim("1", "0x1"), claim("2", "0x1"), claim("3", "0x1")],
+            3000,
+        )
+        .unwrap();
+        assert_eq!(count_attributes(&res, "ClaimedEventInfo"), 3);
+
+        let duplicate: GetOriginalToDuplicateResponse = app
+            .wrap()
+            .query_wasm_smart(
+                bridge_addr.clone(),
+                &BridgeQueryMsg::GetOriginalToDuplicate {
+                    contract_address: "bruh".to_string(),
+                    chain: "BSC".to_string(),
+                },
+            )
+            .unwrap();
+        let collection = duplicate.data.unwrap().contract_address;
+        let records = claim_status(&app, "0x1");
+        assert_eq!(records.len(), 3);
+        for (record, token_id) in records.into_iter().zip(["1", "2", "3"]) {
+            assert_eq!(record.token_id, token_id);
+            assert_eq!(record.contract, Some(collection.clone()));
+            let owner: cw721::OwnerOfResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    collection.clone(),
+                    &cosm_nft::NftQueryMsg::OwnerOf {
+                        token_id: token_id.to_string(),
+                        include_expired: None,
+                    },
+                )
+                .unwrap();
+            assert_eq!(owner.owner, "claimer");
+        }
+        let rewards = validator_rewards(&app, &bridge_addr, &public_key);
+        assert_eq!(rewards.data, vec![Coin::new(3000, "uscrt")]);
+
+        // a replayed claim fails the whole batch
+        let res = batch_claim(&mut app, vec![claim("4", "0x4"), claim("1", "0x1")], 2000);
+        assert!(res.is_err());
+        assert!(claim_status(&app, "0x4").is_empty());
+    }
+
+    #[test]
+    fn test_claim_royalties() {
+        use cosm_nft::msg::{CW2981QueryMsg, RoyaltiesInfoResponse};
+        use cosm_nft::royalty::{Royalty, RoyaltyInfo};
+
+        let mut app = mock_app();
+        let (bridge_addr, sk, public_key) = instantiate_bridge_with_validator(&mut app, "uscrt");
+        let royalty_info = |app: &App, collection: &Addr, token_id: &str| {
+            let res: RoyaltiesInfoResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    collection.clone(),
+                    &cosm_nft::NftQueryMsg::Extension {
+                        msg: CW2981QueryMsg::RoyaltyInfo {
+                            token_id: token_id.to_string(),
+                            sale_price: Uint128::new(10_000),
+                        },
+                    },
+                )
+                .unwrap();
+            res
+        };
+
+        // 2.5%, in basis points like on the source chains
+        let data = ClaimData {
+            royalty: 250,
+            royalty_receiver: Addr::unchecked("creator"),
+            ..default_claim_data()
+        };
+        app.execute_contract(
+            Addr::unchecked("deployer"),
+            bridge_addr.clone(),
+            &BridgeExecuteMsg::Claim721 {
+                data: signed_claim(&bridge_addr, &sk, &public_key, data),
+            },
+            &[Coin::new(1000, "uscrt")],
+        )
+        .unwrap();
+        let duplicate: GetOriginalToDuplicateResponse = app
+            .wrap()
+            .query_wasm_smart(
+                bridge_addr.clone(),
+                &BridgeQueryMsg::GetOriginalToDuplicate {
+                    contract_address: "bruh".to_string(),
+                    chain: "BSC".to_string(),
+                },
+            )
+            .unwrap();
+        let collection = duplicate.data.unwrap().contract_address;
+        let res = royalty_info(&app, &collection, "1");
+        assert_eq!(res.address, "creator");
+        assert_eq!(res.royalty_amount, Uint128::new(250));
+        assert_eq!(res.royalties.len(), 1);
+
+        // several recipients minted directly on a collection
+        let collection_code_id = app.store_code(Box::new(collection_code_wrapper()));
+        let minter = Addr::unchecked("nft_minter");
+        let collection = mint_approved_nft(&mut app, collection_code_id, &bridge_addr, &minter);
+        let mint = |app: &mut App, token_id: &str, royalty_info: RoyaltyInfo| {
+            app.execute_contract(
+                minter.clone(),
+                collection.clone(),
+                &cosm_nft::NftExecuteMsg::Mint {
+                    token_id: token_id.to_string(),
+                    owner: minter.to_string(),
+                    token_uri: None,
+                    extension: RoyaltyData {
+                        royalty_info: Some(royalty_info),
+                        ..RoyaltyData::default()
+                    },
+                },
+                &[],
+            )
+        };
+        let royalties = |rates: &[(&str, u16)]| {
+            rates
+                .iter()
+                .map(|(recipient, rate)| Royalty {
+                    recipient: recipient.to_string(),
+                    rate: *rate,
+                })
+                .collect::<Vec<_>>()
+        };
+
+        mint(
+            &mut app,
+            "2",
+            RoyaltyInfo {
+                decimal_places_in_rates: 4,
+                royalties: royalties(&[("creator", 500), ("curator", 125)]),
+            },
+        )
+        .unwrap();
+        let res = royalty_info(&app, &collection, "2");
+        assert_eq!(res.address, "creator");
+        assert_eq!(res.royalty_amount, Uint128::new(625));
+        let split: Vec<_> = res
+            .royalties
+            .iter()
+            .map(|r| (r.address.as_str(), r.royalty_amount.u128()))
+            .collect();
+        assert_eq!(split, vec![("creator", 500), ("curator", 125)]);
+
+        let err = mint(
+            &mut app,
+            "3",
+            RoyaltyInfo {
+                decimal_places_in_rates: 2,
+                royalties: royalties(&[("creator", 60), ("curator", 41)]),
+            },
+        )
+        .unwrap_err()
+        .downcast::<cosm_nft::error::ContractError>()
+        .unwrap();
+        assert_eq!(err, cosm_nft::error::ContractError::InvalidRoyaltyRates);
+        let err = mint(
+            &mut app,
+            "3",
+            RoyaltyInfo {
+                decimal_places_in_rates: 19,
+                royalties: royalties(&[("creator", 1)]),
+            },
+        )
+        .unwrap_err()
+        .downcast::<cosm_nft::error::ContractError>()
+        .unwrap();
+        assert_eq!(
+            err,
+            cosm_nft::error::ContractError::InvalidRoyaltyDecimalPlaces
+        );
+    }
+
+    #[test]
+    fn test_burn_on_return() {
+        use cosm_nft::msg::{CW2981QueryMsg, NftExtensionMsg, TokenStatus, TokenStatusResponse};
+
+        let mut app = mock_app();
+        let (bridge_addr, sk, public_key) =
+            instantiate_bridge_with_config(&mut app, "uscrt", |msg| msg.burn_on_return = true);
+        let claimer = Addr::unchecked("claimer");
+        let claim = |app: &mut App, transaction_hash: &str| {
+            let data = ClaimData {
+                transaction_hash: transaction_hash.to_string(),
+                ..default_claim_data()
+            };
+            app.execute_contract(
+                Addr::unchecked("deployer"),
+                bridge_addr.clone(),
+                &BridgeExecuteMsg::Claim721 {
+                    data: signed_claim(&bridge_addr, &sk, &public_key, data),
+                },
+                &[Coin::new(1000, "uscrt")],
+            )
+            .unwrap()
+        };
+
+        claim(&mut app, "0xabc123");
+        let duplicate: GetOriginalToDuplicateResponse = app
+            .wrap()
+            .query_wasm_smart(
+                bridge_addr.clone(),
+                &BridgeQueryMsg::GetOriginalToDuplicate {
+                    contract_address: "bruh".to_string(),
+                    chain: "BSC".to_string(),
+                },
+            )
+            .unwrap();
+        let collection = duplicate.data.unwrap().contract_address;
+        let status = |app: &App| -> TokenStatus {
+            let res: TokenStatusResponse = app
+                .wrap()
+                .query_wasm_smart(
+                    collection.clone(),
+                    &cosm_nft::NftQueryMsg::Extension {
+                        msg: CW2981QueryMsg::TokenStatus {
+                            token_id: "1".to_string(),
+                        },
+                    },
+                )
+                .unwrap();
+            res.status
+        };
+        assert_eq!(status(&app), TokenStatus::Live);
+
+        // only the bridge, as minter, may burn on the way out
+        let err = app
+            .execute_contract(
+                claimer.clone(),
+                collection.clone(),
+                &cosm_nft::NftExecuteMsg::Extension {
+                    msg: NftExtensionMsg::BurnFrom {
+                        from: claimer.to_string(),
+                        token_id: "1".to_string(),
+                    },
+                },
+                &[],
+            )
+            .unwrap_err()
+            .downcast::<cosm_nft::error::ContractError>()
+            .unwrap();
+        assert_eq!(err, cosm_nft::error::ContractError::Unauthorized);
+
+        app.execute_contract(
+            claimer.clone(),
+            bridge_addr.clone(),
+            &BridgeExecuteMsg::Lock721 {
+                data: Lock721Msg {
+                    collection_code_id: 0,
+                    destination_chain: "BSC".to_string(),
+                    destination_user_address: BSC_USER.to_string(),
+                    source_nft_contract_address: collection.to_string(),
+                    token_id: "1".to_string(),
+                },
+            },
+            &[],
+        )
+        .unwrap();
+        assert_eq!(status(&app), TokenStatus::BurnedInTransit);
+        let storage: GetStorageResponse = app
+            .wrap()
+            .query_wasm_smart(
+                bridge_addr.clone(),
+                &BridgeQueryMsg::GetDuplicateStorage721 {
+                    contract_address: collection.to_string(),
+                    chain: "cosmos".to_string(),
+                },
+            )
+            .unwrap();
+        assert!(storage.data.is_none());
+
+        // coming back mints the token again
+        claim(&mut app, "0xdef456");
+        assert_eq!(status(&app), TokenStatus::Live);
+        let owner: cw721::OwnerOfResponse = app
+            .wrap()
+            .query_wasm_smart(
+                collection.clone(),
+                &cosm_nft::NftQueryMsg::OwnerOf {
+                    token_id: "1".to_string(),
+                    include_expired: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(owner.owner, claimer.to_string());
+    }
+
+    #[test]
+    fn test_emergency_return() {
+        use nft_store::msg::{GetTokenCustodyResponse, ListCustodiedTokensResponse};
+
+        let mut app = mock_app();
+        let (bridge_addr, sk, public_key) = instantiate_bridge_with_validator(&mut app, "uscrt");
+        let collection_code_id = app.store_code(Box::new(collection_code_wrapper()));
+        let nft_minter = Addr::unchecked("nft_minter");
+        let nft_contract =
+            mint_approved_nft(&mut app, collection_code_id, &bridge_addr, &nft_minter);
+
+        app.execute_contract(
+            nft_minter.clone(),
+            bridge_addr.clone(),
+            &BridgeExecuteMsg::Lock721 {
+                data: Lock721Msg {
+                    collection_code_id,
+                    destination_chain: "BSC".to_string(),
+                    destination_user_address: BSC_USER.to_string(),
+                    source_nft_contract_address: nft_contract.to_string(),
+                    token_id: "1".to_string(),
+                },
+            },
+            &[],
+        )
+        .unwrap();
+        let storage: GetStorageResponse = app
+            .wrap()
+            .query_wasm_smart(
+                bridge_addr.clone(),
+                &BridgeQueryMsg::GetOriginalStorage721 {
+                    contract_address: nft_contract.to_string(),
+                    chain: "cosmos".to_string(),
+                },
+            )
+            .unwrap();
+        let storage = storage.data.unwrap();
+
+        let custody: GetTokenCustodyResponse = app
+            .wrap()
+            .query_wasm_smart(
+                storage.clone(),
+                &NftStoreQueryMsg::GetTokenCustody {
+                    token_id: "1".to_string(),
+                },
+            )
+            .unwrap();
+        let custody = custody.data.unwrap();
+        assert_eq!(custody.locker, nft_minter);
+        assert_eq!(custody.destination_chain, "BSC");
+        assert_eq!(custody.destination_user_address, BSC_USER);
+        assert_eq!(custody.locked_at, app.block_info().time);
+        let owner: Addr = app
+            .wrap()
+            .query_wasm_smart(storage.clone(), &NftStoreQueryMsg::GetOwner)
+            .unwrap();
+        assert_eq!(owner, bridge_addr);
+
+        let emergency_return = |app: &mut App, data: EmergencyReturnData, signed: bool| {
+            let signatures = if signed {
+                governance_signatures(&bridge_addr, &sk, &public_key, &data)
+            } else {
+                vec![]
+            };
+            app.execute_contract(
+                Addr::unchecked("anyone"),
+                bridge_addr.clone(),
+                &BridgeExecuteMsg::EmergencyReturn721 {
+                    data: EmergencyReturnMsg { data, signatures },
+                },
+                &[],
+            )
+        };
+        let data = EmergencyReturnData {
+            source_nft_contract_address: nft_contract.to_string(),
+            token_id: "1".to_string(),
+            locker: nft_minter.clone(),
+            locked_at: custody.locked_at,
+        };
+
+        let err = emergency_return(&mut app, data.clone(), false)
+            .unwrap_err()
+            .downcast::<ContractError>()
+            .unwrap();
+        assert!(
+            matches!(err, ContractError::ThresholdNotReached { got: 0, need: 1 }),
+            "{:?}",
+            err
+        );
+        // signed for an earlier lock of the same token
+        let stale = EmergencyReturnData {
+            locked_at: custody.locked_at.minus_seconds(60),
+            ..data.clone()
+        };
+        let err = emergency_return(&mut app, stale, true)
+            .unwrap_err()
+            .downcast::<ContractError>()
+            .unwrap();
+        assert!(
+            matches!(err, ContractError::CustodyMismatch { .. }),
+            "{:?}",
+            err
+        );
+
+        let res = emergency_return(&mut app, data.clone(), true).unwrap();
+        assert_eq!(count_attributes(&res, "EmergencyReturnedEventInfo"), 1);
+        let owner: cw721::OwnerOfResponse = app
+            .wrap()
+            .query_wasm_smart(
+                nft_contract.clone(),
+                &cosm_nft::NftQueryMsg::OwnerOf {
+                    token_id: "1".to_string(),
+                    include_expired: None,
+                },
+            )
+            .unwrap();
+        assert_eq!(owner.owner, nft_minter.to_string());
+        let custodied: ListCustodiedTokensResponse = app
+            .wrap()
+            .query_wasm_smart(
+                storage,
+                &NftStoreQueryMsg::ListCustodiedTokens {
+                    start_after: None,
+                    limit: None,
+                },
+            )
+            .unwrap();
+        assert!(custodied.data.is_empty());
+
+        // the same signatures can't return it twice
+        let err = emergency_return(&mut app, data, true)
+            .unwrap_err()
+            .downcast::<ContractError>()
+            .unwrap();
+        assert!(
+            matches!(err, ContractError::NotInCustody { .. }),
+            "{:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_lock_requires_token_owner() {
+        use nft_store::msg::GetTokenCustodyResponse;
+
+        let mut app = mock_app();
+        let (bridge_addr, _, _) = instantiate_bridge_with_validator(&mut app, "uscrt");
+        let collection_code_id = app.store_code(Box::new(collection_code_wrapper()));
+        let nft_owner = Addr::unchecked("nft_owner");
+        let nft_contract =
+            mint_approved_nft(&mut app, collection_code_id, &bridge_addr, &nft_owner);
+        let lock = BridgeExecuteMsg::Lock721 {
+            data: Lock721Msg {
+                collection_code_id,
+                destination_chain: "BSC".to_string(),
+                destination_user_address: BSC_USER.to_string(),
+                source_nft_contract_address: nft_contract.to_string(),
+                token_id: "1".to_string(),
+            },
+        };
+
+        // the bridge is approved for the token, but that doesn't let anyone lock it
+        let err = app
+            .execute_contract(Addr::unchecked("attacker"), bridge_addr.clone(), &lock, &[])
+            .unwrap_err()
+            .downcast::<ContractError>()
+            .unwrap();
+        assert!(
+            matches!(&err, ContractError::NotTokenOwner { sender, .. } if sender == "attacker"),
+            "{:?}",
+            err
+        );
+
+        let operator = Addr::unchecked("operator");
+        app.execute_contract(
+            nft_owner.clone(),
+            nft_contract.clone(),
+            &cosm_nft::NftExecuteMsg::ApproveAll {
+                operator: operator.to_string(),
+                expires: None,
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(operator, bridge_addr.clone(), &lock, &[])
+            .unwrap();
+
+        let storage: GetStorageResponse = app
+            .wrap()
+            .query_wasm_smart(
+                bridge_addr,
+                &BridgeQueryMsg::GetOriginalStorage721 {
+                    contract_address: nft_contract.to_string(),
+                    chain: "cosmos".to_string(),
+                },
+            )
+            .unwrap();
+        let custody: GetTokenCustodyResponse = app
+            .wrap()
+            .query_wasm_smart(
+                storage.data.unwrap(),
+                &NftStoreQueryMsg::GetTokenCustody {
+                    token_id: "1".to_string(),
+                },
+            )
+            .unwrap();
+        // emergency returns go back to the owner, not to its operator
+        assert_eq!(custody.data.unwrap().locker, nft_owner);
+    }
+
+    fn sign_addition(
+        bridge: &Addr,
+        sk: &secp256k1::SecretKey,
+        signer: &Binary,
+        validator: (&Binary, &str),
+    ) -> SignerAndSignature {
+        let addition = ValidatorAddition {
+            public_key: validator.0.clone(),
+            address: Addr::unchecked(validator.1),
+        };
+        governance_signature(bridge, sk, signer, &addition)
+    }
+
+    fn sign_blacklisting(
+        bridge: &Addr,
+        sk: &secp256k1::SecretKey,
+        signer: &Binary,
+        key: &Binary,
+    ) -> SignerAndSignature {
+        let blacklisting = ValidatorBlacklisting {
+            public_key: key.clone(),
+        };
+        governance_signature(bridge, sk, signer, &blacklisting)
+    }
+
+    #[test]
+    fn test_validator_management_errors() {
+        use secp256k1::rand::rngs::OsRng;
+        let mut app = mock_app();
+        let secp = secp256k1::Secp256k1::new();
+        let (sk2, pk2) = secp.generate_keypair(&mut OsRng);
+        let public_key2 = Binary::from(pk2.serialize());
+        let (bridge_addr, sk, public_key) = instantiate_bridge_with_config(&mut app, "uscrt", {
+            let public_key2 = public_key2.clone();
+            |msg| {
+                msg.validators
+                    .push((public_key2, Addr::unchecked("validator2")))
+            }
+        });
+        let (_, outsider) = secp.generate_keypair(&mut OsRng);
+        let outsider_key = Binary::from(outsider.serialize());
+
+        let execute_error = |app: &mut App, msg: BridgeExecuteMsg| -> ContractError {
+            app.execute_contract(Addr::unchecked("deployer"), bridge_addr.clone(), &msg, &[])
+                .expect_err("execute should fail")
+                .downcast::<ContractError>()
+                .expect("not a bridge error")
+        };
+        let add =
+            |key: &Binary, signatures: Vec<SignerAndSignature>| BridgeExecuteMsg::AddValidator {
+                data: AddValidatorMsg {
+                    validator: (key.clone(), Addr::unchecked("validator")),
+                    signatures,
+                },
+            };
+        let blacklist = |key: &Binary, signatures: Vec<SignerAndSignature>| {
+            BridgeExecuteMsg::BlacklistValidator {
+                data: BlacklistValidatorMsg {
+                    validator: (key.clone(), Addr::unchecked("validator")),
+                    signatures,
+                },
+            }
+        };
+
+        let err = execute_error(&mut app, add(&outsider_key, vec![]));
+        assert!(
+            matches!(err, ContractError::MissingSignatures {}),
+            "{:?}",
+            err
+        );
+
+        // approvals signed for another bridge don't count here
+        let other_bridge = Addr::unchecked("other_bridge");
+        let err = execute_error(
+            &mut app,
+            add(
+                &outsider_key,
+                vec![
+                    sign_addition(
+                        &other_bridge,
+                        &sk,
+                        &public_key,
+                        (&outsider_key, "validator"),
+                    ),
+                    sign_addition(
+                        &other_bridge,
+                        &sk2,
+                        &public_key2,
+                        (&outsider_key, "validator"),
+                    ),
+                ],
+            ),
+        );
+        assert!(
+            matches!(err, ContractError::ThresholdNotReached { got: 0, need: 2 }),
+            "{:?}",
+            err
+        );
+
+        let err = execute_error(
+            &mut app,
+            add(
+                &outsider_key,
+                vec![sign_addition(
+                    &bridge_addr,
+                    &sk,
+                    &public_key,
+                    (&outsider_key, "validator"),
+                )],
+            ),
+        );
+        assert!(
+            matches!(err, ContractError::ThresholdNotReached { got: 1, need: 2 }),
+            "{:?}",
+            err
+        );
+
+        let err = execute_error(
+            &mut app,
+            add(
+                &public_key2,
+                vec![sign_addition(
+                    &bridge_addr,
+                    &sk,
+                    &public_key,
+                    (&public_key2, "validator"),
+                )],
+            ),
+        );
+        assert!(
+            matches!(err, ContractError::ValidatorAlreadyAdded {}),
+            "{:?}",
+            err
+        );
+
+        let err = execute_error(
+            &mut app,
+            blacklist(
+                &outsider_key,
+                vec![sign_blacklisting(
+                    &bridge_addr,
+                    &sk,
+                    &public_key,
+                    &outsider_key,
+                )],
+            ),
+        );
+        assert!(
+            matches!(err, ContractError::UnknownValidator {}),
+            "{:?}",
+            err
+        );
+
+        let err = execute_error(&mut app, blacklist(&public_key2, vec![]));
+        assert!(
+            matches!(err, ContractError::MissingSignatures {}),
+            "{:?}",
+            err
+        );
+
+        app.execute_contract(
+            Addr::unchecked("deployer"),
+            bridge_addr.clone(),
+            &blacklist(
+                &public_key2,
+                vec![
+                    sign_blacklisting(&bridge_addr, &sk, &public_key, &public_key2),
+                    sign_blacklisting(&bridge_addr, &sk2, &public_key2, &public_key2),
+                ],
+            ),
+            &[],
+        )
+        .unwrap();
+        let err = execute_error(
+            &mut app,
+            add(
+                &public_key2,
+                vec![sign_addition(
+                    &bridge_addr,
+                    &sk,
+                    &public_key,
+                    (&public_key2, "validator"),
+                )],
+            ),
+        );
+        assert!(
+            matches!(err, ContractError::ValidatorBlacklisted {}),
+            "{:?}",
+            err
+        );
+
+        let claim_data = default_claim_data();
+        let err = execute_error(
+            &mut app,
+            BridgeExecuteMsg::VerifySig {
+                data: VerifyMsg {
+                    user: public_key.clone(),
+                    message: [0; 32],
+                    signature: sign_blacklisting(&bridge_addr, &sk, &public_key, &public_key)
+                        .signature,
+                    msg_as_bindary: Binary::default(),
+                    claim_data: claim_data_hash(&bridge_addr, &claim_data),
+                    claim_data_as_binary: claim_data,
+                },
+            },
+        );
+        assert!(
+            matches!(err, ContractError::InvalidSignature {}),
+            "{:?}",
+            err
+        );
+    }
+
+    /// Stands in for the code a bridge is migrated from, recording the
+    /// `(contract, version)` it is instantiated with as its cw2 version
+    fn versioned_code_wrapper() -> ContractWrapper<
+        Empty,
+        (String, String),
+        Empty,
+        ContractError,
+        cosmwasm_std::StdError,
+        cosmwasm_std::StdError,
+    > {
+        ContractWrapper::new(
+            |_: DepsMut, _: Env, _: MessageInfo, _: Empty| -> Result<Response, ContractError> {
+                Ok(Response::new())
+            },
+            |deps: DepsMut, _: Env, _: MessageInfo, msg: (String, String)| -> StdResult<Response> {
+                cw2::set_contract_version(deps.storage, msg.0, msg.1)?;
+                Ok(Response::new())
+            },
+            |_: Deps, _: Env, _: Empty| -> StdResult<Binary> { to_json_binary(&Empty {}) },
+        )
+    }
+
+    fn no_migrate_changes() -> BridgeMigrateMsg {
+        BridgeMigrateMsg {
+            legacy_claim_hash_until: None,
+            max_batch_size: None,
+            burn_on_return: None,
+            fee_denom: None,
+        }
+    }
+
+    #[test]
+    fn test_migrate_errors() {
+        let mut app = mock_app();
+        let deployer = Addr::unchecked("deployer");
+        let versioned_code_id = app.store_code(Box::new(versioned_code_wrapper()));
+        let bridge_code_id = app.store_code(Box::new(
+            ContractWrapper::new(
+                crate::contract::execute,
+                crate::contract::instantiate,
+                crate::contract::query,
+            )
+            .with_reply(crate::contract::reply)
+            .with_migrate(crate::contract::migrate),
+        ));
+
+        let mut migrate_error = |contract: &str, version: &str| -> ContractError {
+            let addr = app
+                .instantiate_contract(
+                    versioned_code_id,
+                    deployer.clone(),
+                    &(contract.to_string(), version.to_string()),
+                    &[],
+                    "versioned",
+                    Some(deployer.to_string()),
+                )
+                .unwrap();
+            app.migrate_contract(
+                deployer.clone(),
+                addr,
+                &no_migrate_changes(),
+                bridge_code_id,
+            )
+            .expect_err("migrate should fail")
+            .downcast::<ContractError>()
+            .expect("not a bridge error")
+        };
+
+        let err = migrate_error("crates.io:other", "0.1.0");
+        assert!(
+            matches!(
+                err,
+                ContractError::InvalidMigrationContract { ref found, .. } if found == "crates.io:other"
+            ),
+            "{:?}",
+            err
+        );
+
+        let err = migrate_error(CONTRACT_NAME, "99.0.0");
+        assert!(
+            matches!(
+                err,
+                ContractError::CannotMigrateFromNewerVersion { ref stored, .. } if stored == "99.0.0"
+            ),
+            "{:?}",
+            err
+        );
+
+        let err = migrate_error(CONTRACT_NAME, "not-a-version");
+        assert!(
+            matches!(
+                err,
+                ContractError::InvalidContractVersion { ref version } if version == "not-a-version"
+            ),
+            "{:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_unexpected_reply_id() {
+        let mut app = mock_app();
+        let deployer = Addr::unchecked("deployer");
+        // dispatches a failing bank send the bridge reply handler doesn't know
+        let code_id = app.store_code(Box::new(
+            ContractWrapper::new(
+                |_: DepsMut, _: Env, _: MessageInfo, _: Empty| -> Result<Response, ContractError> {
+                    Ok(Response::new().add_submessage(SubMsg::reply_on_error(
+                        BankMsg::Send {
+                            to_address: "anyone".to_string(),
+                            amount: vec![Coin::new(1, "uscrt")],
+                        },
+                        99,
+                    )))
+                },
+                |_: DepsMut, _: Env, _: MessageInfo, _: Empty| -> StdResult<Response> {
+                    Ok(Response::new())
+                },
+                |_: Deps, _: Env, _: Empty| -> StdResult<Binary> { to_json_binary(&Empty {}) },
+            )
+            .with_reply(crate::contract::reply),
+        ));
+        let addr = app
+            .instantiate_contract(code_id, deployer.clone(), &Empty {}, &[], "replier", None)
+            .unwrap();
+
+        let err = app
+            .execute_contract(deployer, addr, &Empty {}, &[])
+            .expect_err("execute should fail")
+            .downcast::<ContractError>()
+            .expect("not a bridge error");
+        assert!(
+            matches!(err, ContractError::UnexpectedReplyId { id: 99 }),
+            "{:?}",
+            err
+        );
+    }
+
+    /// Executes the bridge as if the fee of [default_claim_data] was sent,
+    /// without the bank moving it
+    fn execute_with_unbacked_fee(
+        deps: DepsMut,
+        env: Env,
+        mut info: MessageInfo,
+        msg: BridgeExecuteMsg,
+    ) -> Result<Response, ContractError> {
+        info.funds = vec![Coin::new(1000, "uscrt")];
+        crate::contract::execute(deps, env, info, msg)
+    }
+
+    #[test]
+    fn test_claim_insufficient_reward_balance() {
+        let mut app = mock_app();
+        let (bridge_addr, sk, public_key) = instantiate_bridge_with_validator(&mut app, "uscrt");
+        let unbacked_code_id = app.store_code(Box::new(
+            ContractWrapper::new(
+                execute_with_unbacked_fee,
+                crate::contract::instantiate,
+                crate::contract::query,
+            )
+            .with_reply(crate::contract::reply)
+            .with_migrate(crate::contract::migrate),
+        ));
+        app.migrate_contract(
+            Addr::unchecked("deployer"),
+            bridge_addr.clone(),
+            &no_migrate_changes(),
+            unbacked_code_id,
+        )
+        .unwrap();
+
+        let err = claim_721_error(
+            &mut app,
+            &bridge_addr,
+            signed_claim(&bridge_addr, &sk, &public_key, default_claim_data()),
+            &[],
+        );
+        assert!(
+            matches!(
+                err,
+                ContractError::InsufficientRewardBalance { balance, fee }
+                    if balance.is_zero() && fee == Uint128::new(1000)
+            ),
+            "{:?}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_lock_and_claim_errors() {
+        let mut app = mock_app();
+        let (bridge_addr, sk, public_key) = instantiate_bridge_with_validator(&mut app, "uscrt");
+        let (collection1155_code_id, _) = save_collection_and_store_1155_contracts(&mut app);
+        let owner = Addr::unchecked("owner");
+
+        let execute_error =
+            |app: &mut App, sender: &Addr, msg: BridgeExecuteMsg, funds: &[Coin]| {
+                app.execute_contract(sender.clone(), bridge_addr.clone(), &msg, funds)
+                    .expect_err("execute should fail")
+                    .downcast::<ContractError>()
+                    .expect("not a bridge error")
+            };
+        let claim_1155 = |data: ClaimData| BridgeExecuteMsg::Claim1155 {
+            data: signed_claim(&bridge_addr, &sk, &public_key, data),
+        };
+
+        let err = claim_721_error(
+            &mut app,
+            &bridge_addr,
+            signed_claim(
+                &bridge_addr,
+                &sk,
+                &public_key,
+                ClaimData {
+                    nft_type: "multiple".to_string(),
+                    ..default_claim_data()
+                },
+            ),
+            &[Coin::new(1000, "uscrt")],
+        );
+        assert!(
+            matches!(
+                &err,
+                ContractError::InvalidNftType { expected, found }
+                    if expected == "singular" && found == "multiple"
+            ),
+            "{:?}",
+            err
+        );
+
+        let err = execute_error(
+            &mut app,
+            &Addr::unchecked("deployer"),
+            claim_1155(default_claim_data()),
+            &[Coin::new(1000, "uscrt")],
+        );
+        assert!(
+            matches!(
+                &err,
+                ContractError::InvalidNftType { expected, found }
+                    if expected == "multiple" && found == "singular"
+            ),
+            "{:?}",
+            err
+        );
+
+        let err = execute_error(
+            &mut app,
+            &Addr::unchecked("deployer"),
+            claim_1155(ClaimData {
+                nft_type: "multiple".to_string(),
+                token_amount: 0,
+                ..default_claim_data()
+            }),
+            &[Coin::new(1000, "uscrt")],
+        );
+        assert!(
+            matches!(err, ContractError::InvalidTokenAmount {}),
+            "{:?}",
+            err
+        );
+
+        // a processed claim can't be replayed
+        app.execute_contract(
+            Addr::unchecked("deployer"),
+            bridge_addr.clone(),
+            &BridgeExecuteMsg::Claim721 {
+                data: signed_claim(&bridge_addr, &sk, &public_key, default_claim_data()),
+            },
+            &[Coin::new(1000, "uscrt")],
+        )
+        .unwrap();
+        let err = claim_721_error(
+            &mut app,
+            &bridge_addr,
+            signed_claim(&bridge_addr, &sk, &public_key, default_claim_data()),
+            &[Coin::new(1000, "uscrt")],
+        );
+        assert!(
+            matches!(err, ContractError::AlreadyProcessed { .. }),
+            "{:?}",
+            err
+        );
+
+        let err = execute_error(
+            &mut app,
+            &owner,
+            BridgeExecuteMsg::Lock721 {
+                data: Lock721Msg {
+                    destination_chain: "BSC".to_string(),
+                    destination_user_address: BSC_USER.to_string(),
+                    source_nft_contract_address: "".to_string(),
+                    collection_code_id: 0,
+                    token_id: "1".to_string(),
+                },
+            },
+            &[],
+        );
+        assert!(
+            matches!(&err, ContractError::InvalidSourceNftContract { address } if address.is_empty()),
+            "{:?}",
+            err
+        );
+
+        let lock_1155 = |source: &Addr, token_amount: u128| BridgeExecuteMsg::Lock1155 {
+            data: Lock1155Msg {
+                destination_chain: "BSC".to_string(),
+                destination_user_address: BSC_USER.to_string(),
+                source_nft_contract_address: source.clone(),
+                collection_code_id: collection1155_code_id,
+                token_id: "1".to_string(),
+                token_amount,
+            },
+        };
+        let err = execute_error(&mut app, &owner, lock_1155(&Addr::unchecked(""), 1), &[]);
+        assert!(
+            matches!(err, ContractError::InvalidSourceNftContract { .. }),
+            "{:?}",
+            err
+        );
+
+        // an original collection only releases what was locked into its storage
+        let collection = app
+            .instantiate_contract(
+                collection1155_code_id,
+                owner.clone(),
+                &cosm_nft1155::init::InstantiateMsg {
+                    name: "Original".to_string(),
+                    symbol: "ORIG".to_string(),
+                    minter: owner.to_string(),
+                    source_nft_contract_address: Default::default(),
+                    source_chain: Default::default(),
+                    destination_user_address: owner.clone(),
+                    token_id: Default::default(),
+                    token_amount: Default::default(),
+                    royalty: Default::default(),
+                    royalty_receiver: owner.clone(),
+                    metadata: Default::default(),
+                    transaction_hash: "tx_hash".to_string(),
+                    lock_tx_chain: "BSC".to_string(),
+                },
+                &[],
+                "original",
+                None,
+            )
+            .unwrap();
+        app.execute_contract(
+            owner.clone(),
+            collection.clone(),
+            &Nft1155ExecuteMsg::Mint {
+                to: owner.to_string(),
+                token_id: "1".to_string(),
+                value: Uint128::new(5),
+                token_uri: None,
+                msg: None,
+            },
+            &[],
+        )
+        .unwrap();
+        app.execute_contract(
+            owner.clone(),
+            collection.clone(),
+            &Nft1155ExecuteMsg::ApproveAll {
+                operator: bridge_addr.to_string(),
+                expires: None,
+            },
+            &[],
+        )
+        .unwrap();
+
+        let err = execute_error(&mut app, &owner, lock_1155(&collection, 0), &[]);
+        assert!(
+            matches!(err, ContractError::InvalidTokenAmount {}),
+            "{:?}",
+            err
+        );
+
+        app.execute_contract(
+            owner.clone(),
+            bridge_addr.clone(),
+            &lock_1155(&collection, 3),
+            &[],
+        )
+        .unwrap();
+        let err = execute_error(
+            &mut app,
+            &Addr::unchecked("deployer"),
+            claim_1155(ClaimData {
+                nft_type: "multiple".to_string(),
+                source_nft_contract_address: collection.to_string(),
+                token_amount: 5,
+                transaction_hash: "0x1155".to_string(),
+                ..default_claim_data()
+            }),
+            &[Coin::new(1000, "uscrt")],
+        );
+        assert!(
+            matches!(
+                &err,
+                ContractError::InsufficientLockedEditions { token_id, locked: 3, requested: 5 }
+                    if token_id == "1"
+            ),
+            "{:?}",
+            err
+        );
+    }
 }
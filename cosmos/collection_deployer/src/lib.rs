@@ -1,4 +1,8 @@
-use cosmwasm_std::{from_json, to_json_binary, DepsMut, Reply, Response};
+use cosmwasm_std::{
+    from_json, instantiate2_address, to_json_binary, to_json_vec, Addr, Binary, Deps, DepsMut, Env,
+    Reply, Response, StdError, StdResult,
+};
+use sha2::{Digest, Sha256};
 
 use error::CollectionFactoryContractError;
 use msg::ReplyCollectionInfo;
@@ -7,25 +11,36 @@ mod bridge_msg;
 pub mod error;
 pub mod msg;
 mod state;
+#[allow(dead_code)]
 mod storage_deployer_msg;
+#[allow(dead_code)]
 mod storage_info;
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod tests;
 
 #[cfg(not(feature = "library"))]
 pub mod entry {
 
     use cosmwasm_std::{
-        entry_point, to_json_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Empty, Env,
-        MessageInfo, Reply, Response, SubMsg,
+        entry_point, to_json_binary, Addr, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo,
+        Reply, Response, SubMsg,
     };
 
     use crate::{
         bridge_msg::BridgeInfo,
+        collection_salt,
         error::CollectionFactoryContractError,
-        handle_instantiate_reply_721,
-        msg::{CollectionDeployerExecuteMsg, CollectionDeployerInstantiateMsg},
-        state::{OWNER, SNIP721_CODE, SNIP721_INSTANTIATE_REPLY_ID},
+        handle_instantiate_reply_1155, handle_instantiate_reply_721,
+        msg::{
+            CollectionDeployerExecuteMsg, CollectionDeployerInstantiateMsg,
+            CollectionDeployerQueryMsg, CollectionKind, PredictCollectionAddressResponse,
+        },
+        predict_address,
+        state::{
+            OWNER, SNIP1155_CODE, SNIP1155_INSTANTIATE_REPLY_ID, SNIP721_CODE,
+            SNIP721_INSTANTIATE_REPLY_ID,
+        },
     };
 
     #[entry_point]
@@ -37,6 +52,7 @@ pub mod entry {
     ) -> Result<Response, CollectionFactoryContractError> {
         OWNER.save(deps.storage, &info.sender)?;
         SNIP721_CODE.save(deps.storage, &msg.collection721_code_id)?;
+        SNIP1155_CODE.save(deps.storage, &msg.collection1155_code_id)?;
 
         let offspring_info = BridgeInfo {
             address: env.contract.address,
@@ -69,7 +85,8 @@ pub mod entry {
                 royalty_receiver,
                 metadata,
                 transaction_hash,
-                lock_tx_chain
+                lock_tx_chain,
+                burn_on_return,
             } => try_create_collection_721(
                 deps,
                 env,
@@ -85,13 +102,59 @@ pub mod entry {
                 royalty_receiver,
                 metadata,
                 transaction_hash,
-                lock_tx_chain
+                lock_tx_chain,
+                burn_on_return,
             ),
+            CollectionDeployerExecuteMsg::CreateCollection1155 {
+                owner,
+                name,
+                symbol,
+                source_nft_contract_address,
+                source_chain,
+                destination_user_address,
+                token_id,
+                token_amount,
+                royalty,
+                royalty_receiver,
+                metadata,
+                transaction_hash,
+                lock_tx_chain,
+            } => {
+                let initmsg = cosm_nft1155::init::InstantiateMsg {
+                    name,
+                    symbol,
+                    minter: deps.api.addr_validate(&owner)?.into_string(),
+                    source_nft_contract_address,
+                    source_chain,
+                    destination_user_address,
+                    token_id,
+                    token_amount,
+                    royalty,
+                    royalty_receiver,
+                    metadata,
+                    transaction_hash,
+                    lock_tx_chain,
+                };
+                try_create_collection_1155(deps, initmsg)
+            }
+            CollectionDeployerExecuteMsg::UpdateCollectionCode {
+                collection721_code_id,
+                collection1155_code_id,
+            } => {
+                if let Some(code_id) = collection721_code_id {
+                    SNIP721_CODE.save(deps.storage, &code_id)?;
+                }
+                if let Some(code_id) = collection1155_code_id {
+                    SNIP1155_CODE.save(deps.storage, &code_id)?;
+                }
+                Ok(Response::new().add_attribute("action", "update_collection_code"))
+            }
         }?;
 
         Ok(response)
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn try_create_collection_721(
         deps: DepsMut,
         _env: Env,
@@ -107,9 +170,11 @@ pub mod entry {
         royalty_receiver: Addr,
         metadata: String,
         transaction_hash: String,
-        lock_tx_chain: String
+        lock_tx_chain: String,
+        burn_on_return: bool,
     ) -> Result<Response, CollectionFactoryContractError> {
         let owner_addr = deps.api.addr_validate(&owner)?;
+        let salt = collection_salt(&source_chain, &source_nft_contract_address)?;
 
         let initmsg = cosm_nft::init::InstantiateMsg {
             name: name.clone(),
@@ -124,24 +189,47 @@ pub mod entry {
             royalty_receiver,
             metadata,
             transaction_hash,
-            lock_tx_chain
+            lock_tx_chain,
+            burn_on_return,
         };
 
         let code_id = SNIP721_CODE.load(deps.storage)?;
 
         let init_submsg = SubMsg::reply_always(
-            CosmosMsg::Wasm(cosmwasm_std::WasmMsg::Instantiate {
-                admin: None,
+            CosmosMsg::Wasm(cosmwasm_std::WasmMsg::Instantiate2 {
+                admin: Some(OWNER.load(deps.storage)?.into_string()),
                 code_id,
                 msg: to_json_binary(&initmsg)?,
                 funds: vec![],
                 label: name,
+                salt,
             }),
             SNIP721_INSTANTIATE_REPLY_ID,
         );
         Ok(Response::new().add_submessage(init_submsg))
     }
 
+    fn try_create_collection_1155(
+        deps: DepsMut,
+        initmsg: cosm_nft1155::init::InstantiateMsg,
+    ) -> Result<Response, CollectionFactoryContractError> {
+        let code_id = SNIP1155_CODE.load(deps.storage)?;
+        let salt = collection_salt(&initmsg.source_chain, &initmsg.source_nft_contract_address)?;
+
+        let init_submsg = SubMsg::reply_always(
+            CosmosMsg::Wasm(cosmwasm_std::WasmMsg::Instantiate2 {
+                admin: Some(OWNER.load(deps.storage)?.into_string()),
+                code_id,
+                msg: to_json_binary(&initmsg)?,
+                funds: vec![],
+                label: initmsg.name,
+                salt,
+            }),
+            SNIP1155_INSTANTIATE_REPLY_ID,
+        );
+        Ok(Response::new().add_submessage(init_submsg))
+    }
+
     #[entry_point]
     pub fn reply(
         _deps: DepsMut,
@@ -150,20 +238,52 @@ pub mod entry {
     ) -> Result<Response, CollectionFactoryContractError> {
         match msg.id {
             SNIP721_INSTANTIATE_REPLY_ID => handle_instantiate_reply_721(msg, _deps),
+            SNIP1155_INSTANTIATE_REPLY_ID => handle_instantiate_reply_1155(msg),
             id => Err(CollectionFactoryContractError::UnexpectedReplyId { id }),
         }
     }
 
     #[entry_point]
     pub fn query(
-        _deps: Deps,
-        _env: Env,
-        _msg: Empty,
+        deps: Deps,
+        env: Env,
+        msg: CollectionDeployerQueryMsg,
     ) -> Result<Binary, CollectionFactoryContractError> {
-        Ok(Binary::default())
+        match msg {
+            CollectionDeployerQueryMsg::PredictCollectionAddress {
+                kind,
+                source_chain,
+                source_nft_contract_address,
+            } => {
+                let code_id = match kind {
+                    CollectionKind::Collection721 => SNIP721_CODE.load(deps.storage)?,
+                    CollectionKind::Collection1155 => SNIP1155_CODE.load(deps.storage)?,
+                };
+                let salt = collection_salt(&source_chain, &source_nft_contract_address)?;
+                Ok(to_json_binary(&PredictCollectionAddressResponse {
+                    address: predict_address(deps, &env, code_id, &salt)?,
+                })?)
+            }
+        }
     }
 }
 
+/// Collections are instantiated with the sha256 of the JSON encoding of
+/// `[source_chain, source_nft_contract_address]` as salt
+pub fn collection_salt(source_chain: &str, source_nft_contract_address: &str) -> StdResult<Binary> {
+    let mut hasher = Sha256::new();
+    hasher.update(to_json_vec(&(source_chain, source_nft_contract_address))?);
+    Ok(Binary::from(hasher.finalize().as_slice()))
+}
+
+fn predict_address(deps: Deps, env: &Env, code_id: u64, salt: &[u8]) -> StdResult<Addr> {
+    let checksum = deps.querier.query_wasm_code_info(code_id)?.checksum;
+    let creator = deps.api.addr_canonicalize(env.contract.address.as_str())?;
+    let address = instantiate2_address(&checksum, &creator, salt)
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+    deps.api.addr_humanize(&address)
+}
+
 fn handle_instantiate_reply_721(
     msg: Reply,
     _deps: DepsMut,
@@ -191,3 +311,22 @@ fn register_collection_721_impl(
         .add_attribute("collection_address_721", &reply_info.address)
         .set_data(to_json_binary(&reply_info)?))
 }
+
+fn handle_instantiate_reply_1155(msg: Reply) -> Result<Response, CollectionFactoryContractError> {
+    let result = cw0::parse_reply_instantiate_data(msg).map_err(|e| {
+        CollectionFactoryContractError::CustomError(format!(
+            "Failed to parse instantiate reply: {}",
+            e
+        ))
+    })?;
+    if let Some(bin) = result.data {
+        let reply_info: ReplyCollectionInfo = from_json(&bin)?;
+        Ok(Response::new()
+            .add_attribute("collection_address_1155", &reply_info.address)
+            .set_data(to_json_binary(&reply_info)?))
+    } else {
+        Err(CollectionFactoryContractError::CustomError(
+            "Init didn't response with contract address 1155".to_string(),
+        ))
+    }
+}
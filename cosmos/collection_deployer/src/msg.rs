@@ -1,4 +1,4 @@
-use cosmwasm_schema::cw_serde;
+use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::Addr;
 
 /// Instantiation message
@@ -6,6 +6,8 @@ use cosmwasm_std::Addr;
 pub struct CollectionDeployerInstantiateMsg {
     /// collection code info
     pub collection721_code_id: u64,
+    /// multi-edition collection code info
+    pub collection1155_code_id: u64,
 }
 
 #[cw_serde]
@@ -23,12 +25,57 @@ pub enum CollectionDeployerExecuteMsg {
         royalty_receiver: Addr,
         metadata: String,
         transaction_hash: String,
-        lock_tx_chain: String
+        lock_tx_chain: String,
+        /// See [cosm_nft::init::InstantiateMsg::burn_on_return]
+        #[serde(default)]
+        burn_on_return: bool,
+    },
+    CreateCollection1155 {
+        owner: String,
+        name: String,
+        symbol: String,
+        source_nft_contract_address: String,
+        source_chain: String,
+        destination_user_address: Addr,
+        token_id: String,
+        token_amount: u128,
+        royalty: u16,
+        royalty_receiver: Addr,
+        metadata: String,
+        transaction_hash: String,
+        lock_tx_chain: String,
+    },
+    /// Rotates the code ids used for collections deployed from now on
+    UpdateCollectionCode {
+        collection721_code_id: Option<u64>,
+        collection1155_code_id: Option<u64>,
+    },
+}
+
+#[cw_serde]
+pub enum CollectionKind {
+    Collection721,
+    Collection1155,
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum CollectionDeployerQueryMsg {
+    /// Address the duplicate of `source_nft_contract_address` from
+    /// `source_chain` is deployed at, as long as the code of `kind` is not
+    /// rotated in between
+    #[returns(PredictCollectionAddressResponse)]
+    PredictCollectionAddress {
+        kind: CollectionKind,
+        source_chain: String,
+        source_nft_contract_address: String,
     },
 }
 
 #[cw_serde]
-pub enum CollectionDeployerQueryMsg {}
+pub struct PredictCollectionAddressResponse {
+    pub address: Addr,
+}
 
 #[cw_serde]
 pub struct ReplyCollectionInfo {
@@ -44,5 +91,8 @@ pub struct ReplyCollectionInfo {
     pub royalty_receiver: Addr,
     pub metadata: String,
     pub transaction_hash: String,
-    pub lock_tx_chain: String
+    pub lock_tx_chain: String,
+    /// Wrapped tokens leaving through the bridge are burned instead of held
+    #[serde(default)]
+    pub burn_on_return: bool,
 }
@@ -6,4 +6,8 @@ pub const OWNER: Item<Addr> = Item::new("owner");
 
 pub const SNIP721_CODE: Item<u64> = Item::new("st721_v");
 
+pub const SNIP1155_CODE: Item<u64> = Item::new("st1155_v");
+
 pub const SNIP721_INSTANTIATE_REPLY_ID: u64 = 5;
+
+pub const SNIP1155_INSTANTIATE_REPLY_ID: u64 = 6;
@@ -4,16 +4,21 @@ mod tests {
 
     use cosmwasm_schema::cw_serde;
     use cosmwasm_std::{Addr, Empty};
-    use cw721_base::entry::query;
-    use cw_multi_test::{App, ContractWrapper, Executor};
+    use cw_multi_test::{
+        App, AppBuilder, BankKeeper, ContractWrapper, Executor, MockAddressGenerator,
+        MockApiBech32, WasmKeeper,
+    };
 
     use crate::{
-        entry::{execute, instantiate, reply},
-        msg::{CollectionDeployerExecuteMsg, CollectionDeployerInstantiateMsg},
+        entry::{execute, instantiate, query, reply},
+        msg::{
+            CollectionDeployerExecuteMsg, CollectionDeployerInstantiateMsg,
+            CollectionDeployerQueryMsg, CollectionKind, PredictCollectionAddressResponse,
+        },
     };
 
     fn prepare_collection() -> ContractWrapper<
-        cw721_base::ExecuteMsg<cosm_nft::royalty::RoyaltyData, cosmwasm_std::Empty>,
+        cosm_nft::NftExecuteMsg,
         cosm_nft::init::InstantiateMsg,
         cw721_base::QueryMsg<cosm_nft::msg::CW2981QueryMsg>,
         cosm_nft::error::ContractError,
@@ -27,9 +32,31 @@ mod tests {
         )
     }
 
+    fn prepare_collection_1155() -> ContractWrapper<
+        cosm_nft1155::msg::Nft1155ExecuteMsg,
+        cosm_nft1155::init::InstantiateMsg,
+        cosm_nft1155::msg::Nft1155QueryMsg,
+        cosm_nft1155::error::ContractError,
+        cosm_nft1155::error::ContractError,
+        cosmwasm_std::StdError,
+    > {
+        ContractWrapper::new(
+            cosm_nft1155::entry::execute,
+            cosm_nft1155::entry::instantiate,
+            cosm_nft1155::entry::query,
+        )
+    }
+
+    fn mock_app() -> App<BankKeeper, MockApiBech32> {
+        AppBuilder::new()
+            .with_api(MockApiBech32::new("cosmwasm"))
+            .with_wasm(WasmKeeper::default().with_address_generator(MockAddressGenerator))
+            .build(|_, _, _| {})
+    }
+
     #[test]
     fn test_contract() -> Result<(), Box<dyn Error>> {
-        let mut app = App::default();
+        let mut app = mock_app();
 
         let cc_code = prepare_collection();
         let cc_code_id = app.store_code(Box::new(cc_code));
@@ -39,10 +66,11 @@ mod tests {
         let cf_id = app.store_code(Box::new(cf_code));
 
         let addr = app.instantiate_contract(
-            cf_id.clone(),
-            Addr::unchecked("owner"),
+            cf_id,
+            app.api().addr_make("owner"),
             &CollectionDeployerInstantiateMsg {
                 collection721_code_id: cc_code_id,
+                collection1155_code_id: cc_code_id,
             },
             &[],
             "Collection Factory",
@@ -52,22 +80,23 @@ mod tests {
         let addr = addr.unwrap();
 
         let create = app.execute_contract(
-            Addr::unchecked("owner"),
+            app.api().addr_make("owner"),
             addr,
             &CollectionDeployerExecuteMsg::CreateCollection721 {
-                owner: Addr::unchecked("owner").to_string(),
+                owner: app.api().addr_make("owner").to_string(),
                 name: "Test Collection".to_string(),
                 symbol: "TEST".to_string(),
                 source_nft_contract_address: "".to_string(),
                 source_chain: "".to_string(),
-                destination_user_address: Addr::unchecked("receiver"),
+                destination_user_address: app.api().addr_make("receiver"),
                 token_id: "".to_string(),
                 token_amount: 1,
                 royalty: 1,
-                royalty_receiver: Addr::unchecked("receiver"),
+                royalty_receiver: app.api().addr_make("receiver"),
                 metadata: "".to_string(),
                 transaction_hash: "transaction_hash".to_string(),
-                lock_tx_chain: "BRUH".to_string()
+                lock_tx_chain: "BRUH".to_string(),
+                burn_on_return: false,
             },
             &[],
         );
@@ -79,7 +108,7 @@ mod tests {
             .unwrap()
             .attributes
             .iter()
-            .find(|a| a.key == "_contract_addr")
+            .find(|a| a.key == "_contract_address")
             .map(|attr| attr.value.clone());
         assert!(addr.is_some());
         let addr = addr.unwrap();
@@ -101,4 +130,212 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_create_collection_1155() -> Result<(), Box<dyn Error>> {
+        let mut app = mock_app();
+
+        let cc_code_id = app.store_code(Box::new(prepare_collection()));
+        let cc1155_code_id = app.store_code(Box::new(prepare_collection_1155()));
+
+        let cf_code = ContractWrapper::new(execute, instantiate, query);
+        let cf_code = cf_code.with_reply(reply);
+        let cf_id = app.store_code(Box::new(cf_code));
+
+        let addr = app.instantiate_contract(
+            cf_id,
+            app.api().addr_make("owner"),
+            &CollectionDeployerInstantiateMsg {
+                collection721_code_id: cc_code_id,
+                collection1155_code_id: cc1155_code_id,
+            },
+            &[],
+            "Collection Factory",
+            None,
+        )?;
+
+        let create = app.execute_contract(
+            app.api().addr_make("owner"),
+            addr,
+            &CollectionDeployerExecuteMsg::CreateCollection1155 {
+                owner: app.api().addr_make("owner").to_string(),
+                name: "Test Collection".to_string(),
+                symbol: "TEST".to_string(),
+                source_nft_contract_address: "".to_string(),
+                source_chain: "".to_string(),
+                destination_user_address: app.api().addr_make("receiver"),
+                token_id: "1".to_string(),
+                token_amount: 10,
+                royalty: 1,
+                royalty_receiver: app.api().addr_make("receiver"),
+                metadata: "".to_string(),
+                transaction_hash: "transaction_hash".to_string(),
+                lock_tx_chain: "BRUH".to_string(),
+            },
+            &[],
+        );
+        assert!(create.is_ok());
+        let create = create.unwrap();
+        let addr = create
+            .events
+            .iter()
+            .find(|e| e.ty == "instantiate")
+            .unwrap()
+            .attributes
+            .iter()
+            .find(|a| a.key == "_contract_address")
+            .map(|attr| attr.value.clone());
+        assert!(addr.is_some());
+
+        let result: cosm_nft1155::msg::ContractInfoResponse = app.wrap().query_wasm_smart(
+            Addr::unchecked(addr.unwrap()),
+            &cosm_nft1155::msg::Nft1155QueryMsg::ContractInfo {},
+        )?;
+        assert_eq!(result.name, "Test Collection");
+        assert_eq!(result.symbol, "TEST");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_collection_code() -> Result<(), Box<dyn Error>> {
+        let mut app = mock_app();
+
+        let cc_code_id = app.store_code(Box::new(prepare_collection()));
+        let new_cc_code_id = app.store_code(Box::new(prepare_collection()));
+
+        let cf_code = ContractWrapper::new(execute, instantiate, query).with_reply(reply);
+        let cf_id = app.store_code(Box::new(cf_code));
+
+        let addr = app.instantiate_contract(
+            cf_id,
+            app.api().addr_make("owner"),
+            &CollectionDeployerInstantiateMsg {
+                collection721_code_id: cc_code_id,
+                collection1155_code_id: cc_code_id,
+            },
+            &[],
+            "Collection Factory",
+            None,
+        )?;
+
+        let update = CollectionDeployerExecuteMsg::UpdateCollectionCode {
+            collection721_code_id: Some(new_cc_code_id),
+            collection1155_code_id: None,
+        };
+        let unauthorized =
+            app.execute_contract(app.api().addr_make("other"), addr.clone(), &update, &[]);
+        assert!(unauthorized.is_err());
+
+        app.execute_contract(app.api().addr_make("owner"), addr.clone(), &update, &[])?;
+
+        let create = app.execute_contract(
+            app.api().addr_make("owner"),
+            addr,
+            &CollectionDeployerExecuteMsg::CreateCollection721 {
+                owner: app.api().addr_make("owner").to_string(),
+                name: "Test Collection".to_string(),
+                symbol: "TEST".to_string(),
+                source_nft_contract_address: "".to_string(),
+                source_chain: "".to_string(),
+                destination_user_address: app.api().addr_make("receiver"),
+                token_id: "".to_string(),
+                token_amount: 1,
+                royalty: 1,
+                royalty_receiver: app.api().addr_make("receiver"),
+                metadata: "".to_string(),
+                transaction_hash: "transaction_hash".to_string(),
+                lock_tx_chain: "BRUH".to_string(),
+                burn_on_return: false,
+            },
+            &[],
+        )?;
+        let address = create
+            .events
+            .iter()
+            .find(|e| e.ty == "instantiate")
+            .and_then(|e| e.attributes.iter().find(|a| a.key == "_contract_address"))
+            .map(|attr| Addr::unchecked(attr.value.clone()))
+            .unwrap();
+
+        let collection = app.contract_data(&address)?;
+        assert_eq!(collection.code_id, new_cc_code_id);
+        assert_eq!(collection.admin, Some(app.api().addr_make("owner")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_predict_collection_address() -> Result<(), Box<dyn Error>> {
+        let mut app = mock_app();
+
+        let cc_code_id = app.store_code(Box::new(prepare_collection()));
+        let cc1155_code_id = app.store_code(Box::new(prepare_collection_1155()));
+        let cf_code = ContractWrapper::new(execute, instantiate, query).with_reply(reply);
+        let cf_id = app.store_code(Box::new(cf_code));
+
+        let owner = app.api().addr_make("owner");
+        let receiver = app.api().addr_make("receiver");
+        let addr = app.instantiate_contract(
+            cf_id,
+            owner.clone(),
+            &CollectionDeployerInstantiateMsg {
+                collection721_code_id: cc_code_id,
+                collection1155_code_id: cc1155_code_id,
+            },
+            &[],
+            "Collection Factory",
+            None,
+        )?;
+
+        let predicted: PredictCollectionAddressResponse = app.wrap().query_wasm_smart(
+            addr.clone(),
+            &CollectionDeployerQueryMsg::PredictCollectionAddress {
+                kind: CollectionKind::Collection721,
+                source_chain: "BSC".to_string(),
+                source_nft_contract_address: "0xabc".to_string(),
+            },
+        )?;
+        let other_chain: PredictCollectionAddressResponse = app.wrap().query_wasm_smart(
+            addr.clone(),
+            &CollectionDeployerQueryMsg::PredictCollectionAddress {
+                kind: CollectionKind::Collection721,
+                source_chain: "ETH".to_string(),
+                source_nft_contract_address: "0xabc".to_string(),
+            },
+        )?;
+        assert_ne!(predicted.address, other_chain.address);
+
+        let create = app.execute_contract(
+            owner.clone(),
+            addr,
+            &CollectionDeployerExecuteMsg::CreateCollection721 {
+                owner: owner.to_string(),
+                name: "Test Collection".to_string(),
+                symbol: "TEST".to_string(),
+                source_nft_contract_address: "0xabc".to_string(),
+                source_chain: "BSC".to_string(),
+                destination_user_address: receiver.clone(),
+                token_id: "1".to_string(),
+                token_amount: 1,
+                royalty: 1,
+                royalty_receiver: receiver,
+                metadata: "".to_string(),
+                transaction_hash: "transaction_hash".to_string(),
+                lock_tx_chain: "BSC".to_string(),
+                burn_on_return: false,
+            },
+            &[],
+        )?;
+        let address = create
+            .events
+            .iter()
+            .find(|e| e.ty == "instantiate")
+            .and_then(|e| e.attributes.iter().find(|a| a.key == "_contract_address"))
+            .map(|attr| Addr::unchecked(attr.value.clone()))
+            .unwrap();
+        assert_eq!(predicted.address, address);
+
+        Ok(())
+    }
 }
@@ -1,15 +1,18 @@
 use cosmwasm_std::{
-    to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
+    to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdError, StdResult,
 };
 
 use crate::{
     constants::{CONTRACT_NAME, CONTRACT_VERSION},
     error::ContractError,
     init::InstantiateMsg,
-    msg::CW2981QueryMsg,
+    msg::{
+        BurnOnReturnResponse, CW2981QueryMsg, NftExtensionMsg, TokenStatus, TokenStatusResponse,
+    },
     query::{check_royalties, query_royalties_info},
     reply::ReplyCollectionInfo,
     royalty::RoyaltyData,
+    state::{BURNED_IN_TRANSIT, BURN_ON_RETURN},
     NftContract, NftExecuteMsg, NftQueryMsg,
 };
 
@@ -24,6 +27,7 @@ pub mod methods {
         msg: InstantiateMsg,
     ) -> Result<Response, ContractError> {
         cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+        BURN_ON_RETURN.save(deps.storage, &msg.burn_on_return)?;
 
         let response = to_json_binary(&ReplyCollectionInfo {
             label: msg.name.clone(),
@@ -38,7 +42,8 @@ pub mod methods {
             token_amount: msg.token_amount,
             royalty: msg.royalty,
             transaction_hash: msg.transaction_hash,
-            lock_tx_chain: msg.lock_tx_chain
+            lock_tx_chain: msg.lock_tx_chain,
+            burn_on_return: msg.burn_on_return,
         })?;
         Ok(NftContract::default()
             .instantiate(
@@ -61,9 +66,12 @@ pub mod methods {
         msg: NftExecuteMsg,
     ) -> Result<Response, ContractError> {
         if let NftExecuteMsg::Mint {
-            extension: RoyaltyData {
-                royalty_percentage, ..
-            },
+            extension:
+                RoyaltyData {
+                    royalty_percentage,
+                    royalty_info,
+                    ..
+                },
             ..
         } = &msg
         {
@@ -72,13 +80,75 @@ pub mod methods {
             if *royalty_percentage > 100 {
                 return Err(ContractError::InvalidRoyaltyPercentage);
             }
+            if let Some(royalty_info) = royalty_info {
+                let denominator = royalty_info
+                    .denominator()
+                    .ok_or(ContractError::InvalidRoyaltyDecimalPlaces)?;
+                let total_rates: u128 = royalty_info
+                    .royalties
+                    .iter()
+                    .map(|royalty| royalty.rate as u128)
+                    .sum();
+                if total_rates > denominator {
+                    return Err(ContractError::InvalidRoyaltyRates);
+                }
+            }
         }
 
-        NftContract::default()
-            .execute(deps, env, info, msg)
+        match msg {
+            NftExecuteMsg::Extension {
+                msg: NftExtensionMsg::BurnFrom { from, token_id },
+            } => burn_from(deps, env, info, from, token_id),
+            NftExecuteMsg::Mint { ref token_id, .. } => {
+                // a token coming back through the bridge is live again
+                BURNED_IN_TRANSIT.remove(deps.storage, token_id);
+                NftContract::default()
+                    .execute(deps, env, info, msg)
+                    .map_err(Into::into)
+            }
+            _ => NftContract::default()
+                .execute(deps, env, info, msg)
+                .map_err(Into::into),
+        }
+    }
+
+    fn burn_from(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        from: String,
+        token_id: String,
+    ) -> Result<Response, ContractError> {
+        let contract = NftContract::default();
+        if contract.minter(deps.as_ref())?.minter != Some(info.sender.into_string()) {
+            return Err(ContractError::Unauthorized);
+        }
+        if !BURN_ON_RETURN.may_load(deps.storage)?.unwrap_or_default() {
+            return Err(ContractError::BurnOnReturnDisabled);
+        }
+
+        BURNED_IN_TRANSIT.save(deps.storage, &token_id, &true)?;
+        // burns as `from`, so the usual ownership and approval checks apply
+        let from = MessageInfo {
+            sender: deps.api.addr_validate(&from)?,
+            funds: vec![],
+        };
+        contract
+            .execute(deps, env, from, NftExecuteMsg::Burn { token_id })
             .map_err(Into::into)
     }
 
+    fn token_status(deps: Deps, token_id: String) -> StdResult<TokenStatusResponse> {
+        let status = if NftContract::default().tokens.has(deps.storage, &token_id) {
+            TokenStatus::Live
+        } else if BURNED_IN_TRANSIT.has(deps.storage, &token_id) {
+            TokenStatus::BurnedInTransit
+        } else {
+            return Err(StdError::not_found(format!("token {}", token_id)));
+        };
+        Ok(TokenStatusResponse { status })
+    }
+
     pub fn query(deps: Deps, env: Env, msg: NftQueryMsg) -> StdResult<Binary> {
         match msg {
             NftQueryMsg::Extension { msg } => match msg {
@@ -87,6 +157,12 @@ pub mod methods {
                     sale_price,
                 } => to_json_binary(&query_royalties_info(deps, token_id, sale_price)?),
                 CW2981QueryMsg::CheckRoyalties {} => to_json_binary(&check_royalties(deps)?),
+                CW2981QueryMsg::BurnOnReturn {} => to_json_binary(&BurnOnReturnResponse {
+                    enabled: BURN_ON_RETURN.may_load(deps.storage)?.unwrap_or_default(),
+                }),
+                CW2981QueryMsg::TokenStatus { token_id } => {
+                    to_json_binary(&token_status(deps, token_id)?)
+                }
             },
             _ => NftContract::default().query(deps, env, msg),
         }
@@ -11,4 +11,16 @@ pub enum ContractError {
 
     #[error("Royalty percentage must be between 0 and 100")]
     InvalidRoyaltyPercentage,
+
+    #[error("The number of decimal places used in the royalty rates is larger than supported")]
+    InvalidRoyaltyDecimalPlaces,
+
+    #[error("The sum of royalty rates must not exceed 100%")]
+    InvalidRoyaltyRates,
+
+    #[error("Only the minter can burn tokens leaving through the bridge")]
+    Unauthorized,
+
+    #[error("Collection does not burn tokens leaving through the bridge")]
+    BurnOnReturnDisabled,
 }
@@ -18,5 +18,8 @@ pub struct InstantiateMsg {
     pub royalty_receiver: Addr,
     pub metadata: String,
     pub transaction_hash: String,
-    pub lock_tx_chain: String
+    pub lock_tx_chain: String,
+    /// Wrapped tokens leaving through the bridge are burned instead of held
+    #[serde(default)]
+    pub burn_on_return: bool,
 }
@@ -5,7 +5,7 @@ use cosmwasm_std::{
 };
 use cw721_base::Cw721Contract;
 use init::InstantiateMsg;
-use msg::CW2981QueryMsg;
+use msg::{CW2981QueryMsg, NftExtensionMsg};
 use royalty::RoyaltyData;
 
 pub mod constants;
@@ -16,12 +16,13 @@ pub mod msg;
 pub mod query;
 pub mod reply;
 pub mod royalty;
+pub mod state;
 #[cfg(test)]
 pub mod tests;
 
 pub type MintExtension = RoyaltyData;
-pub type NftContract<'a> = Cw721Contract<'a, MintExtension, Empty, Empty, CW2981QueryMsg>;
-pub type NftExecuteMsg = cw721_base::ExecuteMsg<MintExtension, Empty>;
+pub type NftContract<'a> = Cw721Contract<'a, MintExtension, Empty, NftExtensionMsg, CW2981QueryMsg>;
+pub type NftExecuteMsg = cw721_base::ExecuteMsg<MintExtension, NftExtensionMsg>;
 pub type NftQueryMsg = cw721_base::QueryMsg<CW2981QueryMsg>;
 
 #[cfg(not(feature = "library"))]
@@ -24,6 +24,12 @@ pub enum CW2981QueryMsg {
     /// (i.e. always check on sale)
     #[returns(CheckRoyaltiesResponse)]
     CheckRoyalties {},
+    /// Whether wrapped tokens leaving through the bridge are burned
+    #[returns(BurnOnReturnResponse)]
+    BurnOnReturn {},
+    /// Whether a token is live or burned while it is on another chain
+    #[returns(TokenStatusResponse)]
+    TokenStatus { token_id: String },
 }
 
 impl Default for CW2981QueryMsg {
@@ -34,12 +40,33 @@ impl Default for CW2981QueryMsg {
 
 impl CustomMsg for CW2981QueryMsg {}
 
+/// Sent as [crate::NftExecuteMsg::Extension]
+#[cw_serde]
+pub enum NftExtensionMsg {
+    /// Burns a token leaving through the bridge on behalf of `from`, who must
+    /// be allowed to send it. Only the minter (the bridge) can call this, and
+    /// only on collections that burn on return.
+    BurnFrom { from: String, token_id: String },
+}
+
+impl CustomMsg for NftExtensionMsg {}
+
+/// `address` and `royalty_amount` are the first recipient and the total owed,
+/// so marketplaces that only read them pay everything to the original creator.
+/// `royalties` splits the total between all recipients.
 #[cw_serde]
 pub struct RoyaltiesInfoResponse {
     pub address: String,
     // Note that this must be the same denom as that passed in to RoyaltyInfo
     // rounding up or down is at the discretion of the implementer
     pub royalty_amount: Uint128,
+    pub royalties: Vec<RoyaltyPayment>,
+}
+
+#[cw_serde]
+pub struct RoyaltyPayment {
+    pub address: String,
+    pub royalty_amount: Uint128,
 }
 
 /// Shows if the contract implements royalties
@@ -48,3 +75,20 @@ pub struct RoyaltiesInfoResponse {
 pub struct CheckRoyaltiesResponse {
     pub royalty_payments: bool,
 }
+
+#[cw_serde]
+pub struct BurnOnReturnResponse {
+    pub enabled: bool,
+}
+
+#[cw_serde]
+pub enum TokenStatus {
+    Live,
+    /// Burned by the bridge, minted again when it comes back
+    BurnedInTransit,
+}
+
+#[cw_serde]
+pub struct TokenStatusResponse {
+    pub status: TokenStatus,
+}
@@ -1,6 +1,6 @@
-use crate::msg::{CheckRoyaltiesResponse, RoyaltiesInfoResponse};
+use crate::msg::{CheckRoyaltiesResponse, RoyaltiesInfoResponse, RoyaltyPayment};
 use crate::NftContract;
-use cosmwasm_std::{Decimal, Deps, StdResult, Uint128};
+use cosmwasm_std::{Decimal, Deps, StdError, StdResult, Uint128};
 
 /// NOTE: default behaviour here is to round down
 /// EIP2981 specifies that the rounding behaviour is at the discretion of the implementer
@@ -12,14 +12,36 @@ pub fn query_royalties_info(
     let contract = NftContract::default();
     let token_info = contract.tokens.load(deps.storage, &token_id)?;
 
-    let royalty_percentage = Decimal::percent(token_info.extension.royalty_percentage);
-    let royalty_from_sale_price = sale_price * royalty_percentage;
-
-    let royalty_address = token_info.extension.royalty_payment_address;
+    let royalties = match token_info.extension.royalty_info {
+        Some(royalty_info) => {
+            let denominator = royalty_info
+                .denominator()
+                .ok_or_else(|| StdError::generic_err("Unsupported royalty decimal places"))?;
+            royalty_info
+                .royalties
+                .into_iter()
+                .map(|royalty| RoyaltyPayment {
+                    address: royalty.recipient,
+                    royalty_amount: sale_price.multiply_ratio(royalty.rate, denominator),
+                })
+                .collect()
+        }
+        None => {
+            let royalty_percentage = Decimal::percent(token_info.extension.royalty_percentage);
+            vec![RoyaltyPayment {
+                address: token_info.extension.royalty_payment_address,
+                royalty_amount: sale_price * royalty_percentage,
+            }]
+        }
+    };
 
     Ok(RoyaltiesInfoResponse {
-        address: royalty_address,
-        royalty_amount: royalty_from_sale_price,
+        address: royalties
+            .first()
+            .map(|royalty| royalty.address.clone())
+            .unwrap_or_default(),
+        royalty_amount: royalties.iter().map(|royalty| royalty.royalty_amount).sum(),
+        royalties,
     })
 }
 
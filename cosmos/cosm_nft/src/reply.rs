@@ -15,5 +15,8 @@ pub struct ReplyCollectionInfo {
     pub royalty_receiver: Addr,
     pub metadata: String,
     pub transaction_hash: String,
-    pub lock_tx_chain: String
+    pub lock_tx_chain: String,
+    /// Wrapped tokens leaving through the bridge are burned instead of held
+    #[serde(default)]
+    pub burn_on_return: bool,
 }
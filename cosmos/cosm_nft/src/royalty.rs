@@ -9,4 +9,39 @@ pub struct RoyaltyData {
     /// as the minter addr
     /// question: how do we validate this?
     pub royalty_payment_address: String,
+    /// Per token recipients. When set, replaces `royalty_percentage`
+    /// and `royalty_payment_address`.
+    #[serde(default)]
+    pub royalty_info: Option<RoyaltyInfo>,
+}
+
+/// data for a single royalty
+#[cw_serde]
+pub struct Royalty {
+    /// address to send royalties to
+    pub recipient: String,
+    /// royalty rate
+    pub rate: u16,
+}
+
+/// all royalty information
+#[cw_serde]
+pub struct RoyaltyInfo {
+    /// decimal places in royalty rates, 4 makes the rates basis points
+    pub decimal_places_in_rates: u8,
+    /// list of royalties
+    pub royalties: Vec<Royalty>,
+}
+
+impl RoyaltyInfo {
+    /// Largest supported `decimal_places_in_rates`
+    pub const MAX_DECIMAL_PLACES: u8 = 18;
+
+    /// Rate denominator, `None` if the decimal places are not supported
+    pub fn denominator(&self) -> Option<u128> {
+        if self.decimal_places_in_rates > Self::MAX_DECIMAL_PLACES {
+            return None;
+        }
+        Some(10u128.pow(self.decimal_places_in_rates as u32))
+    }
 }
@@ -0,0 +1,7 @@
+use cw_storage_plus::{Item, Map};
+
+/// Set at instantiation, see [crate::init::InstantiateMsg::burn_on_return]
+pub const BURN_ON_RETURN: Item<bool> = Item::new("burn_on_return");
+
+/// Tokens burned by the bridge that haven't been minted back yet
+pub const BURNED_IN_TRANSIT: Map<&str, bool> = Map::new("burned_in_transit");
@@ -1,4 +1,3 @@
-
 // #[cfg(test)]
 // mod tests {
 //     use super::*;
@@ -197,4 +196,4 @@
 //         .unwrap();
 //         assert_eq!(res, voyager_expected);
 //     }
-// }
\ No newline at end of file
+// }
@@ -0,0 +1,10 @@
+use cosm_nft1155::init::InstantiateMsg;
+use cosm_nft1155::msg::{Nft1155ExecuteMsg, Nft1155QueryMsg};
+use cosmwasm_schema::write_api;
+fn main() {
+    write_api! {
+        instantiate: InstantiateMsg,
+        execute: Nft1155ExecuteMsg,
+        query: Nft1155QueryMsg,
+    }
+}
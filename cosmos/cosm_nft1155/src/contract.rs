@@ -0,0 +1,98 @@
+use cosmwasm_std::{
+    to_json_binary, Addr, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult,
+};
+use cw1155_base::state::TOKENS;
+
+use crate::{
+    constants::{CONTRACT_NAME, CONTRACT_VERSION},
+    error::ContractError,
+    init::InstantiateMsg,
+    msg::{ContractInfoResponse, Nft1155ExecuteMsg, Nft1155QueryMsg},
+    reply::ReplyCollectionInfo,
+    state::{CollectionInfo, COLLECTION_INFO},
+};
+
+pub mod methods {
+
+    use super::*;
+
+    pub fn init(
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: InstantiateMsg,
+    ) -> Result<Response, ContractError> {
+        cw1155_base::contract::instantiate(
+            deps.branch(),
+            env.clone(),
+            info,
+            cw1155_base::msg::InstantiateMsg {
+                minter: msg.minter.clone(),
+            },
+        )?;
+        cw2::set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+        COLLECTION_INFO.save(
+            deps.storage,
+            &CollectionInfo {
+                name: msg.name.clone(),
+                symbol: msg.symbol,
+            },
+        )?;
+
+        let response = to_json_binary(&ReplyCollectionInfo {
+            label: msg.name,
+            owner: Addr::unchecked(msg.minter),
+            address: env.contract.address,
+            destination_user_address: msg.destination_user_address,
+            royalty_receiver: msg.royalty_receiver,
+            metadata: msg.metadata,
+            source_nft_contract_address: msg.source_nft_contract_address,
+            source_chain: msg.source_chain,
+            token_id: msg.token_id,
+            token_amount: msg.token_amount,
+            royalty: msg.royalty,
+            transaction_hash: msg.transaction_hash,
+            lock_tx_chain: msg.lock_tx_chain,
+        })?;
+        Ok(Response::new().set_data(response))
+    }
+
+    pub fn execute(
+        mut deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: Nft1155ExecuteMsg,
+    ) -> Result<Response, ContractError> {
+        let uri = match &msg {
+            Nft1155ExecuteMsg::Mint {
+                token_id,
+                token_uri: Some(uri),
+                ..
+            } => Some((token_id.clone(), uri.clone())),
+            _ => None,
+        };
+
+        let res = cw1155_base::contract::execute(deps.branch(), env, info, msg.into())?;
+
+        // cw1155-base registers new token ids with an empty uri
+        if let Some((token_id, uri)) = uri {
+            if TOKENS.load(deps.storage, &token_id)?.is_empty() {
+                TOKENS.save(deps.storage, &token_id, &uri)?;
+            }
+        }
+        Ok(res)
+    }
+
+    pub fn query(deps: Deps, env: Env, msg: Nft1155QueryMsg) -> StdResult<Binary> {
+        match msg.into_base() {
+            Some(base) => cw1155_base::contract::query(deps, env, base),
+            None => {
+                let info = COLLECTION_INFO.load(deps.storage)?;
+                to_json_binary(&ContractInfoResponse {
+                    name: info.name,
+                    symbol: info.symbol,
+                })
+            }
+        }
+    }
+}
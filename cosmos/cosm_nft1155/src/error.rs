@@ -0,0 +1,24 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum ContractError {
+    #[error(transparent)]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("Approval expired")]
+    Expired,
+}
+
+impl From<cw1155_base::ContractError> for ContractError {
+    fn from(err: cw1155_base::ContractError) -> Self {
+        match err {
+            cw1155_base::ContractError::Std(e) => ContractError::Std(e),
+            cw1155_base::ContractError::Unauthorized {} => ContractError::Unauthorized,
+            cw1155_base::ContractError::Expired {} => ContractError::Expired,
+        }
+    }
+}
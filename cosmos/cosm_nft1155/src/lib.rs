@@ -0,0 +1,47 @@
+use self::contract::methods;
+use self::error::ContractError;
+use cosmwasm_std::{entry_point, Binary, Deps, DepsMut, Env, MessageInfo, Response, StdResult};
+use init::InstantiateMsg;
+use msg::{Nft1155ExecuteMsg, Nft1155QueryMsg};
+
+pub mod constants;
+pub mod contract;
+pub mod error;
+pub mod init;
+pub mod msg;
+pub mod reply;
+pub mod state;
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+pub mod tests;
+
+#[cfg(not(feature = "library"))]
+pub mod entry {
+
+    use super::*;
+
+    #[entry_point]
+    pub fn instantiate(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: InstantiateMsg,
+    ) -> Result<Response, ContractError> {
+        methods::init(deps, env, info, msg)
+    }
+
+    #[entry_point]
+    pub fn execute(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: Nft1155ExecuteMsg,
+    ) -> Result<Response, ContractError> {
+        methods::execute(deps, env, info, msg)
+    }
+
+    #[entry_point]
+    pub fn query(deps: Deps, env: Env, msg: Nft1155QueryMsg) -> StdResult<Binary> {
+        methods::query(deps, env, msg)
+    }
+}
@@ -0,0 +1,210 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Binary, Uint128};
+use cw1155::{
+    ApprovedForAllResponse, BalanceResponse, BatchBalanceResponse, Cw1155ExecuteMsg,
+    Cw1155QueryMsg, Expiration, IsApprovedForAllResponse, TokenId, TokenInfoResponse,
+    TokensResponse,
+};
+
+/// Same surface as [Cw1155ExecuteMsg], except that `Mint` can carry the
+/// metadata uri of a token id the first time it is minted.
+#[cw_serde]
+pub enum Nft1155ExecuteMsg {
+    SendFrom {
+        from: String,
+        /// If `to` is not contract, `msg` should be `None`
+        to: String,
+        token_id: TokenId,
+        value: Uint128,
+        /// `None` means don't call the receiver interface
+        msg: Option<Binary>,
+    },
+    BatchSendFrom {
+        from: String,
+        /// If `to` is not contract, `msg` should be `None`
+        to: String,
+        batch: Vec<(TokenId, Uint128)>,
+        /// `None` means don't call the receiver interface
+        msg: Option<Binary>,
+    },
+    /// Only callable by the minter (the bridge)
+    Mint {
+        /// If `to` is not contract, `msg` should be `None`
+        to: String,
+        token_id: TokenId,
+        value: Uint128,
+        /// Stored only if the token id has no uri yet
+        token_uri: Option<String>,
+        /// `None` means don't call the receiver interface
+        msg: Option<Binary>,
+    },
+    BatchMint {
+        /// If `to` is not contract, `msg` should be `None`
+        to: String,
+        batch: Vec<(TokenId, Uint128)>,
+        /// `None` means don't call the receiver interface
+        msg: Option<Binary>,
+    },
+    Burn {
+        from: String,
+        token_id: TokenId,
+        value: Uint128,
+    },
+    BatchBurn {
+        from: String,
+        batch: Vec<(TokenId, Uint128)>,
+    },
+    ApproveAll {
+        operator: String,
+        expires: Option<Expiration>,
+    },
+    RevokeAll {
+        operator: String,
+    },
+}
+
+impl From<Nft1155ExecuteMsg> for Cw1155ExecuteMsg {
+    fn from(msg: Nft1155ExecuteMsg) -> Self {
+        match msg {
+            Nft1155ExecuteMsg::SendFrom {
+                from,
+                to,
+                token_id,
+                value,
+                msg,
+            } => Cw1155ExecuteMsg::SendFrom {
+                from,
+                to,
+                token_id,
+                value,
+                msg,
+            },
+            Nft1155ExecuteMsg::BatchSendFrom {
+                from,
+                to,
+                batch,
+                msg,
+            } => Cw1155ExecuteMsg::BatchSendFrom {
+                from,
+                to,
+                batch,
+                msg,
+            },
+            Nft1155ExecuteMsg::Mint {
+                to,
+                token_id,
+                value,
+                msg,
+                ..
+            } => Cw1155ExecuteMsg::Mint {
+                to,
+                token_id,
+                value,
+                msg,
+            },
+            Nft1155ExecuteMsg::BatchMint { to, batch, msg } => {
+                Cw1155ExecuteMsg::BatchMint { to, batch, msg }
+            }
+            Nft1155ExecuteMsg::Burn {
+                from,
+                token_id,
+                value,
+            } => Cw1155ExecuteMsg::Burn {
+                from,
+                token_id,
+                value,
+            },
+            Nft1155ExecuteMsg::BatchBurn { from, batch } => {
+                Cw1155ExecuteMsg::BatchBurn { from, batch }
+            }
+            Nft1155ExecuteMsg::ApproveAll { operator, expires } => {
+                Cw1155ExecuteMsg::ApproveAll { operator, expires }
+            }
+            Nft1155ExecuteMsg::RevokeAll { operator } => Cw1155ExecuteMsg::RevokeAll { operator },
+        }
+    }
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum Nft1155QueryMsg {
+    #[returns(ContractInfoResponse)]
+    ContractInfo {},
+    #[returns(BalanceResponse)]
+    Balance { owner: String, token_id: TokenId },
+    #[returns(BatchBalanceResponse)]
+    BatchBalance {
+        owner: String,
+        token_ids: Vec<TokenId>,
+    },
+    #[returns(ApprovedForAllResponse)]
+    ApprovedForAll {
+        owner: String,
+        include_expired: Option<bool>,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(IsApprovedForAllResponse)]
+    IsApprovedForAll { owner: String, operator: String },
+    #[returns(TokenInfoResponse)]
+    TokenInfo { token_id: TokenId },
+    #[returns(TokensResponse)]
+    Tokens {
+        owner: String,
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+    #[returns(TokensResponse)]
+    AllTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+impl Nft1155QueryMsg {
+    /// Queries answered by cw1155-base, `None` for the ones handled here
+    pub fn into_base(self) -> Option<Cw1155QueryMsg> {
+        match self {
+            Nft1155QueryMsg::ContractInfo {} => None,
+            Nft1155QueryMsg::Balance { owner, token_id } => {
+                Some(Cw1155QueryMsg::Balance { owner, token_id })
+            }
+            Nft1155QueryMsg::BatchBalance { owner, token_ids } => {
+                Some(Cw1155QueryMsg::BatchBalance { owner, token_ids })
+            }
+            Nft1155QueryMsg::ApprovedForAll {
+                owner,
+                include_expired,
+                start_after,
+                limit,
+            } => Some(Cw1155QueryMsg::ApprovedForAll {
+                owner,
+                include_expired,
+                start_after,
+                limit,
+            }),
+            Nft1155QueryMsg::IsApprovedForAll { owner, operator } => {
+                Some(Cw1155QueryMsg::IsApprovedForAll { owner, operator })
+            }
+            Nft1155QueryMsg::TokenInfo { token_id } => Some(Cw1155QueryMsg::TokenInfo { token_id }),
+            Nft1155QueryMsg::Tokens {
+                owner,
+                start_after,
+                limit,
+            } => Some(Cw1155QueryMsg::Tokens {
+                owner,
+                start_after,
+                limit,
+            }),
+            Nft1155QueryMsg::AllTokens { start_after, limit } => {
+                Some(Cw1155QueryMsg::AllTokens { start_after, limit })
+            }
+        }
+    }
+}
+
+#[cw_serde]
+pub struct ContractInfoResponse {
+    pub name: String,
+    pub symbol: String,
+}
@@ -0,0 +1,10 @@
+use cosmwasm_schema::cw_serde;
+use cw_storage_plus::Item;
+
+#[cw_serde]
+pub struct CollectionInfo {
+    pub name: String,
+    pub symbol: String,
+}
+
+pub const COLLECTION_INFO: Item<CollectionInfo> = Item::new("c_info");
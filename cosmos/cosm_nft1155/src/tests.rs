@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use cosmwasm_std::{Addr, Uint128};
+    use cw1155::{BalanceResponse, TokenInfoResponse};
+    use cw_multi_test::{App, ContractWrapper, Executor};
+
+    use crate::{
+        entry::{execute, instantiate, query},
+        init::InstantiateMsg,
+        msg::{ContractInfoResponse, Nft1155ExecuteMsg, Nft1155QueryMsg},
+    };
+
+    #[test]
+    fn test_contract() -> Result<(), Box<dyn Error>> {
+        let mut app = App::default();
+
+        let code = ContractWrapper::new(execute, instantiate, query);
+        let code_id = app.store_code(Box::new(code));
+
+        let addr = app.instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &InstantiateMsg {
+                name: "Test Collection".to_string(),
+                symbol: "TEST".to_string(),
+                minter: "minter".to_string(),
+                source_nft_contract_address: "0xabc".to_string(),
+                source_chain: "BSC".to_string(),
+                destination_user_address: Addr::unchecked("receiver"),
+                token_id: "1".to_string(),
+                token_amount: 5,
+                royalty: 1,
+                royalty_receiver: Addr::unchecked("receiver"),
+                metadata: "https://example.com/1".to_string(),
+                transaction_hash: "tx_hash".to_string(),
+                lock_tx_chain: "BSC".to_string(),
+            },
+            &[],
+            "Collection",
+            None,
+        )?;
+
+        let info: ContractInfoResponse = app
+            .wrap()
+            .query_wasm_smart(addr.clone(), &Nft1155QueryMsg::ContractInfo {})?;
+        assert_eq!(info.name, "Test Collection");
+        assert_eq!(info.symbol, "TEST");
+
+        let unauthorized = app.execute_contract(
+            Addr::unchecked("receiver"),
+            addr.clone(),
+            &Nft1155ExecuteMsg::Mint {
+                to: "receiver".to_string(),
+                token_id: "1".to_string(),
+                value: Uint128::new(5),
+                token_uri: None,
+                msg: None,
+            },
+            &[],
+        );
+        assert!(unauthorized.is_err());
+
+        for _ in 0..2 {
+            app.execute_contract(
+                Addr::unchecked("minter"),
+                addr.clone(),
+                &Nft1155ExecuteMsg::Mint {
+                    to: "receiver".to_string(),
+                    token_id: "1".to_string(),
+                    value: Uint128::new(5),
+                    token_uri: Some("https://example.com/1".to_string()),
+                    msg: None,
+                },
+                &[],
+            )?;
+        }
+
+        let balance: BalanceResponse = app.wrap().query_wasm_smart(
+            addr.clone(),
+            &Nft1155QueryMsg::Balance {
+                owner: "receiver".to_string(),
+                token_id: "1".to_string(),
+            },
+        )?;
+        assert_eq!(balance.balance, Uint128::new(10));
+
+        let token: TokenInfoResponse = app.wrap().query_wasm_smart(
+            addr,
+            &Nft1155QueryMsg::TokenInfo {
+                token_id: "1".to_string(),
+            },
+        )?;
+        assert_eq!(token.url, "https://example.com/1");
+
+        Ok(())
+    }
+}
@@ -27,6 +27,8 @@ const signer = (await directWallet.getAccounts())[0];
 const contracts = {
   Nft: "cosm_nft.wasm",
   NftStore: "nft_store.wasm",
+  Nft1155: "cosm_nft1155.wasm",
+  NftStore1155: "nft_store1155.wasm",
   StoreDeployer: "store_deployer.wasm",
   CollectionDeployer: "collection_deployer.wasm",
   Bridge: "bridge.wasm",
@@ -53,6 +55,8 @@ for (const contract of Object.values(contracts)) {
 const instBridge = async (codeId: number) => {
   const storageCodeId = storage[contracts.NftStore];
   const collectionCodeId = storage[contracts.Nft];
+  const storage1155CodeId = storage[contracts.NftStore1155];
+  const collection1155CodeId = storage[contracts.Nft1155];
   const cdCodeId = storage[contracts.CollectionDeployer];
   const sdCodeId = storage[contracts.StoreDeployer];
   const init = await client.instantiate(
@@ -70,10 +74,41 @@ const instBridge = async (codeId: number) => {
         ],
       ],
       chain_type: "TERRA",
+      fee_denom: "uluna",
+      legacy_claim_hash_until: null,
+      max_batch_size: null,
+      burn_on_return: false,
+      threshold: null,
+      supported_chains: [
+        ["BSC", { address_format: "hex20", lock_fee: null, enabled: true }],
+        ["ETH", { address_format: "hex20", lock_fee: null, enabled: true }],
+        [
+          "SECRET",
+          {
+            address_format: { bech32: { prefix: "secret" } },
+            lock_fee: null,
+            enabled: true,
+          },
+        ],
+        [
+          "MULTIVERSX",
+          {
+            address_format: { bech32: { prefix: "erd" } },
+            lock_fee: null,
+            enabled: true,
+          },
+        ],
+        [
+          "NEAR",
+          { address_format: "near_account_id", lock_fee: null, enabled: true },
+        ],
+      ],
       storage_label: "xp-storage-1",
       collection_label: "xp-collection-1",
       collection721_code_id: collectionCodeId,
       storage721_code_id: storageCodeId,
+      collection1155_code_id: collection1155CodeId,
+      storage1155_code_id: storage1155CodeId,
       collection_deployer_code_id: cdCodeId,
       storage_deployer_code_id: sdCodeId,
     },
@@ -7,4 +7,6 @@ pub enum StorageContractError {
     Std(#[from] StdError),
     #[error("You are not authorized to perform this function")]
     Unauthorized,
+    #[error("Token {token_id} has no custody record")]
+    NotInCustody { token_id: String },
 }
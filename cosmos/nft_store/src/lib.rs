@@ -1,12 +1,17 @@
-use cosmwasm_std::{to_json_binary, Addr, CosmosMsg, DepsMut, Empty, Response};
+use cosmwasm_std::{
+    to_json_binary, Addr, CosmosMsg, Deps, DepsMut, Empty, Env, Order, Response, StdResult,
+};
+use cw_storage_plus::Bound;
 use error::StorageContractError;
-use state::COLLECTION721_ADDRESS;
+use msg::{CustodiedToken, ListCustodiedTokensResponse};
+use state::{TokenCustody, COLLECTION721_ADDRESS, CUSTODY, DEFAULT_QUERY_LIMIT, MAX_QUERY_LIMIT};
 
 pub mod error;
 pub mod msg;
 mod state;
 mod storage_deployer_msg;
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod tests;
 
 #[cfg(not(feature = "library"))]
@@ -17,9 +22,14 @@ pub mod entry {
     };
 
     use crate::{
+        emergency_return,
         error::StorageContractError,
-        msg::{NftStoreExecuteMsg, NftStoreInstantiateMsg, NftStoreQueryMsg},
-        state::{COLLECTION721_ADDRESS, OWNER},
+        list_custodied_tokens,
+        msg::{
+            GetTokenCustodyResponse, NftStoreExecuteMsg, NftStoreInstantiateMsg, NftStoreQueryMsg,
+        },
+        record_custody,
+        state::{COLLECTION721_ADDRESS, CUSTODY, OWNER},
         storage_deployer_msg::StorageDeployerInfo,
         unlock_token,
     };
@@ -47,7 +57,7 @@ pub mod entry {
     #[entry_point]
     pub fn execute(
         deps: DepsMut,
-        _env: Env,
+        env: Env,
         info: MessageInfo,
         msg: NftStoreExecuteMsg,
     ) -> Result<Response, StorageContractError> {
@@ -56,6 +66,20 @@ pub mod entry {
         }
         match msg {
             NftStoreExecuteMsg::UnLockToken { token_id, to } => unlock_token(deps, token_id, to),
+            NftStoreExecuteMsg::RecordCustody {
+                token_id,
+                locker,
+                destination_chain,
+                destination_user_address,
+            } => record_custody(
+                deps,
+                env,
+                token_id,
+                locker,
+                destination_chain,
+                destination_user_address,
+            ),
+            NftStoreExecuteMsg::EmergencyReturn { token_id } => emergency_return(deps, token_id),
         }
     }
 
@@ -69,6 +93,15 @@ pub mod entry {
             NftStoreQueryMsg::GetCollectionAddress => {
                 Ok(to_json_binary(&COLLECTION721_ADDRESS.load(deps.storage)?)?)
             }
+            NftStoreQueryMsg::GetOwner => Ok(to_json_binary(&OWNER.load(deps.storage)?)?),
+            NftStoreQueryMsg::GetTokenCustody { token_id } => {
+                Ok(to_json_binary(&GetTokenCustodyResponse {
+                    data: CUSTODY.may_load(deps.storage, &token_id)?,
+                })?)
+            }
+            NftStoreQueryMsg::ListCustodiedTokens { start_after, limit } => Ok(to_json_binary(
+                &list_custodied_tokens(deps, start_after, limit)?,
+            )?),
         }
     }
 }
@@ -78,6 +111,7 @@ fn unlock_token(
     token_id: String,
     to: Addr,
 ) -> Result<Response, StorageContractError> {
+    CUSTODY.remove(deps.storage, &token_id);
     let colection_address = COLLECTION721_ADDRESS.load(deps.storage)?;
     let transfer_msg = cw721_base::msg::ExecuteMsg::<Empty, Empty>::TransferNft {
         recipient: to.to_string(),
@@ -90,3 +124,48 @@ fn unlock_token(
     });
     Ok(Response::new().add_message(msg))
 }
+
+fn record_custody(
+    deps: DepsMut,
+    env: Env,
+    token_id: String,
+    locker: Addr,
+    destination_chain: String,
+    destination_user_address: String,
+) -> Result<Response, StorageContractError> {
+    CUSTODY.save(
+        deps.storage,
+        &token_id,
+        &TokenCustody {
+            locker,
+            locked_at: env.block.time,
+            destination_chain,
+            destination_user_address,
+        },
+    )?;
+    Ok(Response::new())
+}
+
+fn emergency_return(deps: DepsMut, token_id: String) -> Result<Response, StorageContractError> {
+    let custody = CUSTODY.may_load(deps.storage, &token_id)?.ok_or_else(|| {
+        StorageContractError::NotInCustody {
+            token_id: token_id.clone(),
+        }
+    })?;
+    unlock_token(deps, token_id, custody.locker)
+}
+
+fn list_custodied_tokens(
+    deps: Deps,
+    start_after: Option<String>,
+    limit: Option<u32>,
+) -> StdResult<ListCustodiedTokensResponse> {
+    let limit = limit.unwrap_or(DEFAULT_QUERY_LIMIT).min(MAX_QUERY_LIMIT) as usize;
+    let start = start_after.as_deref().map(Bound::exclusive);
+    let data = CUSTODY
+        .range(deps.storage, start, None, Order::Ascending)
+        .take(limit)
+        .map(|item| item.map(|(token_id, custody)| CustodiedToken { token_id, custody }))
+        .collect::<StdResult<_>>()?;
+    Ok(ListCustodiedTokensResponse { data })
+}
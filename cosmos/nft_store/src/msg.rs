@@ -1,6 +1,8 @@
-use cosmwasm_schema::cw_serde;
+use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::Addr;
 
+pub use crate::state::TokenCustody;
+
 #[cw_serde]
 pub struct NftStoreInstantiateMsg {
     pub collection_address: Addr,
@@ -12,10 +14,51 @@ pub struct NftStoreInstantiateMsg {
 
 #[cw_serde]
 pub enum NftStoreExecuteMsg {
-    UnLockToken { token_id: String, to: Addr },
+    UnLockToken {
+        token_id: String,
+        to: Addr,
+    },
+    /// Sent by the bridge right after transferring a locked token in
+    RecordCustody {
+        token_id: String,
+        locker: Addr,
+        destination_chain: String,
+        destination_user_address: String,
+    },
+    /// Gives a token whose lock was never claimed back to its locker
+    EmergencyReturn {
+        token_id: String,
+    },
 }
 
 #[cw_serde]
+#[derive(QueryResponses)]
 pub enum NftStoreQueryMsg {
+    #[returns(Addr)]
     GetCollectionAddress,
+    #[returns(Addr)]
+    GetOwner,
+    #[returns(GetTokenCustodyResponse)]
+    GetTokenCustody { token_id: String },
+    #[returns(ListCustodiedTokensResponse)]
+    ListCustodiedTokens {
+        start_after: Option<String>,
+        limit: Option<u32>,
+    },
+}
+
+#[cw_serde]
+pub struct GetTokenCustodyResponse {
+    pub data: Option<TokenCustody>,
+}
+
+#[cw_serde]
+pub struct CustodiedToken {
+    pub token_id: String,
+    pub custody: TokenCustody,
+}
+
+#[cw_serde]
+pub struct ListCustodiedTokensResponse {
+    pub data: Vec<CustodiedToken>,
 }
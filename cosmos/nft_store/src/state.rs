@@ -1,7 +1,23 @@
-use cosmwasm_std::Addr;
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Timestamp};
 
-use cw_storage_plus::Item;
+use cw_storage_plus::{Item, Map};
 
 pub const OWNER: Item<Addr> = Item::new("owner");
 
 pub const COLLECTION721_ADDRESS: Item<Addr> = Item::new("c721_a");
+
+/// token id -> how the token came into the storage
+pub const CUSTODY: Map<&str, TokenCustody> = Map::new("custody");
+
+pub const DEFAULT_QUERY_LIMIT: u32 = 10;
+pub const MAX_QUERY_LIMIT: u32 = 30;
+
+#[cw_serde]
+pub struct TokenCustody {
+    /// Who locked the token on the bridge, emergency returns go back to it
+    pub locker: Addr,
+    pub locked_at: Timestamp,
+    pub destination_chain: String,
+    pub destination_user_address: String,
+}
@@ -8,9 +8,14 @@ mod tests {
 
     use crate::{
         entry::{execute, instantiate},
-        msg::{NftStoreExecuteMsg, NftStoreInstantiateMsg},
+        error::StorageContractError,
+        msg::{
+            GetTokenCustodyResponse, ListCustodiedTokensResponse, NftStoreExecuteMsg,
+            NftStoreInstantiateMsg, NftStoreQueryMsg,
+        },
     };
 
+    #[allow(clippy::type_complexity)]
     fn prepare_collection() -> ContractWrapper<
         cw721_base::ExecuteMsg<std::option::Option<cosmwasm_std::Empty>, cosmwasm_std::Empty>,
         cw721_base::InstantiateMsg,
@@ -19,12 +24,11 @@ mod tests {
         cosmwasm_std::StdError,
         cosmwasm_std::StdError,
     > {
-        let code = ContractWrapper::new(
+        ContractWrapper::new(
             cw721_base::entry::execute,
             cw721_base::entry::instantiate,
             cw721_base::entry::query,
-        );
-        code
+        )
     }
 
     #[test]
@@ -51,7 +55,7 @@ mod tests {
         let code_id = app.store_code(Box::new(storage_code));
 
         let addr = app.instantiate_contract(
-            code_id.clone(),
+            code_id,
             Addr::unchecked("owner"),
             &NftStoreInstantiateMsg {
                 collection_address: nft_addr.clone(),
@@ -93,4 +97,156 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_custody() -> Result<(), Box<dyn Error>> {
+        let mut app = App::default();
+
+        let collection_code_id = app.store_code(Box::new(prepare_collection()));
+        let nft_addr = app.instantiate_contract(
+            collection_code_id,
+            Addr::unchecked("owner"),
+            &cw721_base::InstantiateMsg {
+                minter: Addr::unchecked("minter").to_string(),
+                name: "Test Contract".to_string(),
+                symbol: "TEST".to_string(),
+            },
+            &[],
+            "nft",
+            None,
+        )?;
+        let code_id = app.store_code(Box::new(ContractWrapper::new(
+            execute,
+            instantiate,
+            crate::entry::query,
+        )));
+        let addr = app.instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &NftStoreInstantiateMsg {
+                collection_address: nft_addr.clone(),
+                owner: Addr::unchecked("owner"),
+                collection_code_id,
+                is_original: true,
+                token_id: "1".to_string(),
+            },
+            &[],
+            "Storage",
+            None,
+        )?;
+
+        for token_id in ["1", "2"] {
+            app.execute_contract(
+                Addr::unchecked("minter"),
+                nft_addr.clone(),
+                &cw721_base::msg::ExecuteMsg::<Empty, Empty>::Mint {
+                    token_id: token_id.to_string(),
+                    owner: addr.to_string(),
+                    token_uri: None,
+                    extension: Empty {},
+                },
+                &[],
+            )?;
+            app.execute_contract(
+                Addr::unchecked("owner"),
+                addr.clone(),
+                &NftStoreExecuteMsg::RecordCustody {
+                    token_id: token_id.to_string(),
+                    locker: Addr::unchecked("locker"),
+                    destination_chain: "BSC".to_string(),
+                    destination_user_address: "0xabc".to_string(),
+                },
+                &[],
+            )?;
+        }
+
+        let owner: Addr = app
+            .wrap()
+            .query_wasm_smart(addr.clone(), &NftStoreQueryMsg::GetOwner)?;
+        assert_eq!(owner, Addr::unchecked("owner"));
+        let custody: GetTokenCustodyResponse = app.wrap().query_wasm_smart(
+            addr.clone(),
+            &NftStoreQueryMsg::GetTokenCustody {
+                token_id: "1".to_string(),
+            },
+        )?;
+        let custody = custody.data.unwrap();
+        assert_eq!(custody.locker, Addr::unchecked("locker"));
+        assert_eq!(custody.locked_at, app.block_info().time);
+        let list = |app: &App, start_after: Option<String>| -> ListCustodiedTokensResponse {
+            app.wrap()
+                .query_wasm_smart(
+                    addr.clone(),
+                    &NftStoreQueryMsg::ListCustodiedTokens {
+                        start_after,
+                        limit: Some(1),
+                    },
+                )
+                .unwrap()
+        };
+        assert_eq!(list(&app, None).data[0].token_id, "1");
+        assert_eq!(list(&app, Some("1".to_string())).data[0].token_id, "2");
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("locker"),
+                addr.clone(),
+                &NftStoreExecuteMsg::EmergencyReturn {
+                    token_id: "1".to_string(),
+                },
+                &[],
+            )
+            .unwrap_err()
+            .downcast::<StorageContractError>()?;
+        assert_eq!(err, StorageContractError::Unauthorized);
+
+        app.execute_contract(
+            Addr::unchecked("owner"),
+            addr.clone(),
+            &NftStoreExecuteMsg::EmergencyReturn {
+                token_id: "1".to_string(),
+            },
+            &[],
+        )?;
+        let owner: cw721::OwnerOfResponse = app.wrap().query_wasm_smart(
+            nft_addr,
+            &cw721_base::QueryMsg::<Empty>::OwnerOf {
+                token_id: "1".to_string(),
+                include_expired: None,
+            },
+        )?;
+        assert_eq!(owner.owner, "locker");
+
+        // unlocking after a claim drops the record too
+        app.execute_contract(
+            Addr::unchecked("owner"),
+            addr.clone(),
+            &NftStoreExecuteMsg::UnLockToken {
+                token_id: "2".to_string(),
+                to: Addr::unchecked("claimer"),
+            },
+            &[],
+        )?;
+        assert!(list(&app, None).data.is_empty());
+
+        let err = app
+            .execute_contract(
+                Addr::unchecked("owner"),
+                addr,
+                &NftStoreExecuteMsg::EmergencyReturn {
+                    token_id: "1".to_string(),
+                },
+                &[],
+            )
+            .unwrap_err()
+            .downcast::<StorageContractError>()?;
+        assert_eq!(
+            err,
+            StorageContractError::NotInCustody {
+                token_id: "1".to_string()
+            }
+        );
+
+        Ok(())
+    }
 }
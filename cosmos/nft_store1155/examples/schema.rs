@@ -0,0 +1,10 @@
+use cosmwasm_schema::write_api;
+
+use nft_store1155::msg::{NftStoreExecuteMsg, NftStoreInstantiateMsg};
+
+fn main() {
+    write_api! {
+        instantiate: NftStoreInstantiateMsg,
+        execute: NftStoreExecuteMsg,
+    }
+}
@@ -0,0 +1,104 @@
+use cosmwasm_std::{to_json_binary, Addr, CosmosMsg, DepsMut, Env, Response, Uint128};
+use cw1155::Cw1155ExecuteMsg;
+use error::StorageContractError;
+use state::COLLECTION1155_ADDRESS;
+
+pub mod error;
+pub mod msg;
+mod state;
+mod storage_deployer_msg;
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests;
+
+#[cfg(not(feature = "library"))]
+pub mod entry {
+
+    use cosmwasm_std::{
+        entry_point, to_json_binary, Binary, Deps, DepsMut, Env, MessageInfo, Response,
+    };
+
+    use crate::{
+        error::StorageContractError,
+        msg::{NftStoreExecuteMsg, NftStoreInstantiateMsg, NftStoreQueryMsg},
+        state::{COLLECTION1155_ADDRESS, OWNER},
+        storage_deployer_msg::StorageDeployerInfo,
+        unlock_token,
+    };
+
+    #[entry_point]
+    pub fn instantiate(
+        deps: DepsMut,
+        env: Env,
+        _info: MessageInfo,
+        msg: NftStoreInstantiateMsg,
+    ) -> Result<Response, StorageContractError> {
+        OWNER.save(deps.storage, &msg.owner)?;
+        COLLECTION1155_ADDRESS.save(deps.storage, &msg.collection_address)?;
+
+        let offspring_info = StorageDeployerInfo {
+            label: msg.collection_address.clone().into_string(),
+            address: env.contract.address,
+            is_original: msg.is_original,
+            token_id: msg.token_id,
+            token_amount: msg.token_amount,
+            from: msg.from,
+        };
+        Ok(Response::new().set_data(to_json_binary(&offspring_info)?))
+    }
+
+    #[entry_point]
+    pub fn execute(
+        deps: DepsMut,
+        env: Env,
+        info: MessageInfo,
+        msg: NftStoreExecuteMsg,
+    ) -> Result<Response, StorageContractError> {
+        if OWNER.load(deps.storage)? != info.sender {
+            return Err(StorageContractError::Unauthorized);
+        }
+        match msg {
+            NftStoreExecuteMsg::UnLockToken {
+                token_id,
+                amount,
+                to,
+            } => unlock_token(deps, env, token_id, amount, to),
+        }
+    }
+
+    #[entry_point]
+    pub fn query(
+        deps: Deps,
+        _env: Env,
+        msg: NftStoreQueryMsg,
+    ) -> Result<Binary, StorageContractError> {
+        match msg {
+            NftStoreQueryMsg::GetCollectionAddress => {
+                Ok(to_json_binary(&COLLECTION1155_ADDRESS.load(deps.storage)?)?)
+            }
+        }
+    }
+}
+
+fn unlock_token(
+    deps: DepsMut,
+    env: Env,
+    token_id: String,
+    amount: u128,
+    to: Addr,
+) -> Result<Response, StorageContractError> {
+    let collection_address = COLLECTION1155_ADDRESS.load(deps.storage)?;
+    let transfer_msg = Cw1155ExecuteMsg::SendFrom {
+        from: env.contract.address.into_string(),
+        to: to.into_string(),
+        token_id,
+        value: Uint128::from(amount),
+        msg: None,
+    };
+    let msg = CosmosMsg::Wasm(cosmwasm_std::WasmMsg::Execute {
+        contract_addr: collection_address.to_string(),
+        msg: to_json_binary(&transfer_msg)?,
+        funds: vec![],
+    });
+    Ok(Response::new().add_message(msg))
+}
@@ -0,0 +1,7 @@
+use cosmwasm_std::Addr;
+
+use cw_storage_plus::Item;
+
+pub const OWNER: Item<Addr> = Item::new("owner");
+
+pub const COLLECTION1155_ADDRESS: Item<Addr> = Item::new("c1155_a");
@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use cosmwasm_std::{Addr, Uint128};
+    use cw1155::{BalanceResponse, Cw1155ExecuteMsg, Cw1155QueryMsg};
+    use cw_multi_test::{App, ContractWrapper, Executor};
+
+    use crate::{
+        entry::{execute, instantiate, query},
+        msg::{NftStoreExecuteMsg, NftStoreInstantiateMsg},
+    };
+
+    fn prepare_collection() -> ContractWrapper<
+        Cw1155ExecuteMsg,
+        cw1155_base::msg::InstantiateMsg,
+        Cw1155QueryMsg,
+        cw1155_base::ContractError,
+        cosmwasm_std::StdError,
+        cosmwasm_std::StdError,
+    > {
+        ContractWrapper::new(
+            cw1155_base::contract::execute,
+            cw1155_base::contract::instantiate,
+            cw1155_base::contract::query,
+        )
+    }
+
+    #[test]
+    fn test_contract() -> Result<(), Box<dyn Error>> {
+        let mut app = App::default();
+
+        let collection_code_id = app.store_code(Box::new(prepare_collection()));
+
+        let nft_addr = app.instantiate_contract(
+            collection_code_id,
+            Addr::unchecked("owner"),
+            &cw1155_base::msg::InstantiateMsg {
+                minter: "minter".to_string(),
+            },
+            &[],
+            "nft",
+            None,
+        )?;
+
+        let storage_code = ContractWrapper::new(execute, instantiate, query);
+        let code_id = app.store_code(Box::new(storage_code));
+
+        let addr = app.instantiate_contract(
+            code_id,
+            Addr::unchecked("owner"),
+            &NftStoreInstantiateMsg {
+                collection_address: nft_addr.clone(),
+                owner: Addr::unchecked("owner"),
+                collection_code_id,
+                is_original: true,
+                token_id: "1".to_string(),
+                token_amount: 10,
+                from: Addr::unchecked("minter"),
+            },
+            &[],
+            "Storage",
+            None,
+        );
+        assert!(addr.is_ok());
+        let addr = addr.unwrap();
+
+        let mint = app.execute_contract(
+            Addr::unchecked("minter"),
+            nft_addr.clone(),
+            &Cw1155ExecuteMsg::Mint {
+                to: addr.to_string(),
+                token_id: "1".to_string(),
+                value: Uint128::new(10),
+                msg: None,
+            },
+            &[],
+        );
+        assert!(mint.is_ok());
+
+        let unauthorized = app.execute_contract(
+            Addr::unchecked("minter"),
+            addr.clone(),
+            &NftStoreExecuteMsg::UnLockToken {
+                token_id: "1".to_string(),
+                amount: 4,
+                to: Addr::unchecked("minter"),
+            },
+            &[],
+        );
+        assert!(unauthorized.is_err());
+
+        let unlock = app.execute_contract(
+            Addr::unchecked("owner"),
+            addr.clone(),
+            &NftStoreExecuteMsg::UnLockToken {
+                token_id: "1".to_string(),
+                amount: 4,
+                to: Addr::unchecked("minter"),
+            },
+            &[],
+        );
+        assert!(unlock.is_ok());
+
+        let balance: BalanceResponse = app.wrap().query_wasm_smart(
+            nft_addr,
+            &Cw1155QueryMsg::Balance {
+                owner: addr.to_string(),
+                token_id: "1".to_string(),
+            },
+        )?;
+        assert_eq!(balance.balance, Uint128::new(6));
+
+        Ok(())
+    }
+}
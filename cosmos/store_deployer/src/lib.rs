@@ -1,31 +1,45 @@
-use cosmwasm_std::{from_json, to_json_binary, Reply, Response};
+use cosmwasm_std::{
+    from_json, instantiate2_address, to_json_binary, to_json_vec, Addr, Binary, Deps, Env, Reply,
+    Response, StdError, StdResult,
+};
 use error::StorageFactoryContractError;
-use msg::ReplyStorageInfo;
+use msg::{ReplyStorage1155Info, ReplyStorageInfo};
+use sha2::{Digest, Sha256};
 
 mod bridge_msg;
 pub mod error;
 pub mod msg;
 mod state;
+#[allow(dead_code)]
 mod storage_deployer_msg;
 mod storage_info;
 #[cfg(test)]
+#[allow(clippy::module_inception)]
 mod tests;
 
 #[cfg(not(feature = "library"))]
 pub mod entry {
 
     use cosmwasm_std::{
-        entry_point, to_json_binary, Binary, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo,
-        Reply, Response, SubMsg,
+        entry_point, to_json_binary, Binary, CosmosMsg, Deps, DepsMut, Env, MessageInfo, Reply,
+        Response, SubMsg,
     };
 
     use crate::{
         bridge_msg::BridgeInfo,
         error::StorageFactoryContractError,
-        handle_instantiate_reply_721,
-        msg::StoreFactoryInstantiateMsg,
-        state::{OWNER, STORAGE721_CODE, STORAGE721_INSTANTIATE_REPLY_ID},
-        storage_info::StorageInstantiateMsg,
+        handle_instantiate_reply_1155, handle_instantiate_reply_721,
+        msg::{
+            PredictStorageAddressResponse, StorageKind, StoreFactoryInstantiateMsg,
+            StoreFactoryQueryMsg,
+        },
+        predict_address,
+        state::{
+            OWNER, STORAGE1155_CODE, STORAGE1155_INSTANTIATE_REPLY_ID, STORAGE721_CODE,
+            STORAGE721_INSTANTIATE_REPLY_ID,
+        },
+        storage_info::{Storage1155InstantiateMsg, StorageInstantiateMsg},
+        storage_salt,
     };
 
     #[entry_point]
@@ -37,6 +51,7 @@ pub mod entry {
     ) -> Result<Response, StorageFactoryContractError> {
         OWNER.save(deps.storage, &info.sender)?;
         STORAGE721_CODE.save(deps.storage, &msg.storage721_code_id)?;
+        STORAGE1155_CODE.save(deps.storage, &msg.storage1155_code_id)?;
 
         let offspring_info = BridgeInfo {
             address: env.contract.address,
@@ -58,6 +73,8 @@ pub mod entry {
             crate::msg::StoreFactoryExecuteMsg::CreateStorage721 {
                 label,
                 collection_address,
+                source_chain,
+                source_nft_contract_address,
                 collection_code_id,
                 owner,
                 is_original,
@@ -67,19 +84,58 @@ pub mod entry {
                 env,
                 label,
                 collection_address,
+                storage_salt(&source_chain, &source_nft_contract_address)?,
                 collection_code_id,
                 owner,
                 is_original,
                 token_id,
             ),
+            crate::msg::StoreFactoryExecuteMsg::CreateStorage1155 {
+                label,
+                collection_address,
+                source_chain,
+                source_nft_contract_address,
+                collection_code_id,
+                owner,
+                is_original,
+                token_id,
+                token_amount,
+                from,
+            } => {
+                let initmsg = Storage1155InstantiateMsg {
+                    collection_address,
+                    owner: deps.api.addr_validate(&owner)?,
+                    collection_code_id,
+                    is_original,
+                    token_id,
+                    token_amount,
+                    from,
+                };
+                let salt = storage_salt(&source_chain, &source_nft_contract_address)?;
+                try_create_storage_1155(deps, label, salt, initmsg)
+            }
+            crate::msg::StoreFactoryExecuteMsg::UpdateStorageCode {
+                storage721_code_id,
+                storage1155_code_id,
+            } => {
+                if let Some(code_id) = storage721_code_id {
+                    STORAGE721_CODE.save(deps.storage, &code_id)?;
+                }
+                if let Some(code_id) = storage1155_code_id {
+                    STORAGE1155_CODE.save(deps.storage, &code_id)?;
+                }
+                Ok(Response::new().add_attribute("action", "update_storage_code"))
+            }
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn try_create_storage_721(
         deps: DepsMut<'_>,
         _env: Env,
         label: String,
         collection_address: cosmwasm_std::Addr,
+        salt: Binary,
         collection_code_id: u64,
         owner: String,
         is_original: bool,
@@ -98,12 +154,13 @@ pub mod entry {
         let offspring_code_id = STORAGE721_CODE.load(deps.storage)?;
 
         let init_submsg = SubMsg::reply_always(
-            CosmosMsg::Wasm(cosmwasm_std::WasmMsg::Instantiate {
-                admin: None,
+            CosmosMsg::Wasm(cosmwasm_std::WasmMsg::Instantiate2 {
+                admin: Some(OWNER.load(deps.storage)?.into_string()),
                 code_id: offspring_code_id,
                 msg: to_json_binary(&initmsg)?,
                 funds: vec![],
                 label,
+                salt,
             }),
             STORAGE721_INSTANTIATE_REPLY_ID,
         );
@@ -111,13 +168,51 @@ pub mod entry {
         Ok(Response::new().add_submessage(init_submsg))
     }
 
+    fn try_create_storage_1155(
+        deps: DepsMut<'_>,
+        label: String,
+        salt: Binary,
+        initmsg: Storage1155InstantiateMsg,
+    ) -> Result<Response, StorageFactoryContractError> {
+        let offspring_code_id = STORAGE1155_CODE.load(deps.storage)?;
+
+        let init_submsg = SubMsg::reply_always(
+            CosmosMsg::Wasm(cosmwasm_std::WasmMsg::Instantiate2 {
+                admin: Some(OWNER.load(deps.storage)?.into_string()),
+                code_id: offspring_code_id,
+                msg: to_json_binary(&initmsg)?,
+                funds: vec![],
+                label,
+                salt,
+            }),
+            STORAGE1155_INSTANTIATE_REPLY_ID,
+        );
+
+        Ok(Response::new().add_submessage(init_submsg))
+    }
+
     #[entry_point]
     pub fn query(
-        _deps: Deps,
-        _env: Env,
-        _msg: Empty,
+        deps: Deps,
+        env: Env,
+        msg: StoreFactoryQueryMsg,
     ) -> Result<Binary, StorageFactoryContractError> {
-        Ok(Binary::default())
+        match msg {
+            StoreFactoryQueryMsg::PredictStorageAddress {
+                kind,
+                source_chain,
+                source_nft_contract_address,
+            } => {
+                let code_id = match kind {
+                    StorageKind::Storage721 => STORAGE721_CODE.load(deps.storage)?,
+                    StorageKind::Storage1155 => STORAGE1155_CODE.load(deps.storage)?,
+                };
+                let salt = storage_salt(&source_chain, &source_nft_contract_address)?;
+                Ok(to_json_binary(&PredictStorageAddressResponse {
+                    address: predict_address(deps, &env, code_id, &salt)?,
+                })?)
+            }
+        }
     }
 
     #[entry_point]
@@ -128,11 +223,29 @@ pub mod entry {
     ) -> Result<Response, StorageFactoryContractError> {
         match msg.id {
             STORAGE721_INSTANTIATE_REPLY_ID => handle_instantiate_reply_721(msg),
+            STORAGE1155_INSTANTIATE_REPLY_ID => handle_instantiate_reply_1155(msg),
             id => Err(StorageFactoryContractError::UnexpectedReplyId { id }),
         }
     }
 }
 
+/// A storage holds the tokens of a single collection on this chain, so
+/// storages are instantiated with the sha256 of the JSON encoding of
+/// `[source_chain, source_nft_contract_address]` of that collection as salt
+pub fn storage_salt(source_chain: &str, source_nft_contract_address: &str) -> StdResult<Binary> {
+    let mut hasher = Sha256::new();
+    hasher.update(to_json_vec(&(source_chain, source_nft_contract_address))?);
+    Ok(Binary::from(hasher.finalize().as_slice()))
+}
+
+fn predict_address(deps: Deps, env: &Env, code_id: u64, salt: &[u8]) -> StdResult<Addr> {
+    let checksum = deps.querier.query_wasm_code_info(code_id)?.checksum;
+    let creator = deps.api.addr_canonicalize(env.contract.address.as_str())?;
+    let address = instantiate2_address(&checksum, &creator, salt)
+        .map_err(|e| StdError::generic_err(e.to_string()))?;
+    deps.api.addr_humanize(&address)
+}
+
 fn handle_instantiate_reply_721(msg: Reply) -> Result<Response, StorageFactoryContractError> {
     let reply = cw0::parse_reply_instantiate_data(msg).map_err(|e| {
         StorageFactoryContractError::CustomError(format!(
@@ -158,3 +271,22 @@ fn register_storage_721_impl(
         .add_attribute("storage_address_721", &reply_info.address)
         .set_data(to_json_binary(&reply_info)?))
 }
+
+fn handle_instantiate_reply_1155(msg: Reply) -> Result<Response, StorageFactoryContractError> {
+    let reply = cw0::parse_reply_instantiate_data(msg).map_err(|e| {
+        StorageFactoryContractError::CustomError(format!(
+            "Failed to parse instantiate reply: {}",
+            e
+        ))
+    })?;
+    if let Some(bin) = reply.data {
+        let reply_info: ReplyStorage1155Info = from_json(&bin)?;
+        Ok(Response::new()
+            .add_attribute("storage_address_1155", &reply_info.address)
+            .set_data(to_json_binary(&reply_info)?))
+    } else {
+        Err(StorageFactoryContractError::CustomError(
+            "Init didn't response with store address 1155".to_string(),
+        ))
+    }
+}
@@ -1,25 +1,70 @@
-use cosmwasm_schema::cw_serde;
+use cosmwasm_schema::{cw_serde, QueryResponses};
 use cosmwasm_std::Addr;
 
 #[cw_serde]
 pub struct StoreFactoryInstantiateMsg {
     pub storage721_code_id: u64,
+    pub storage1155_code_id: u64,
 }
 
 #[cw_serde]
 pub enum StoreFactoryExecuteMsg {
+    /// `source_chain` and `source_nft_contract_address` name the collection
+    /// `collection_address` was originally deployed as
     CreateStorage721 {
         label: String,
         collection_address: Addr,
+        source_chain: String,
+        source_nft_contract_address: String,
         collection_code_id: u64,
         owner: String,
         is_original: bool,
         token_id: String,
     },
+    /// Same as [StoreFactoryExecuteMsg::CreateStorage721]
+    CreateStorage1155 {
+        label: String,
+        collection_address: Addr,
+        source_chain: String,
+        source_nft_contract_address: String,
+        collection_code_id: u64,
+        owner: String,
+        is_original: bool,
+        token_id: String,
+        token_amount: u128,
+        from: Addr,
+    },
+    /// Rotates the code ids used for storages deployed from now on
+    UpdateStorageCode {
+        storage721_code_id: Option<u64>,
+        storage1155_code_id: Option<u64>,
+    },
+}
+
+#[cw_serde]
+pub enum StorageKind {
+    Storage721,
+    Storage1155,
 }
 
 #[cw_serde]
-pub enum StoreFactoryQueryMsg {}
+#[derive(QueryResponses)]
+pub enum StoreFactoryQueryMsg {
+    /// Address the storage of the collection originally deployed as
+    /// `source_nft_contract_address` on `source_chain` is deployed at, as long
+    /// as the code of `kind` is not rotated in between
+    #[returns(PredictStorageAddressResponse)]
+    PredictStorageAddress {
+        kind: StorageKind,
+        source_chain: String,
+        source_nft_contract_address: String,
+    },
+}
+
+#[cw_serde]
+pub struct PredictStorageAddressResponse {
+    pub address: Addr,
+}
 
 #[cw_serde]
 pub struct ReplyStorageInfo {
@@ -29,3 +74,13 @@ pub struct ReplyStorageInfo {
     pub token_id: String,
     pub token_amount: u128,
 }
+
+#[cw_serde]
+pub struct ReplyStorage1155Info {
+    pub label: String,
+    pub address: Addr,
+    pub is_original: bool,
+    pub token_id: String,
+    pub token_amount: u128,
+    pub from: Addr,
+}
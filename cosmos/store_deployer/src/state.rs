@@ -6,4 +6,8 @@ pub const OWNER: Item<Addr> = Item::new("owner");
 
 pub const STORAGE721_CODE: Item<u64> = Item::new("st721_v");
 
+pub const STORAGE1155_CODE: Item<u64> = Item::new("st1155_v");
+
 pub const STORAGE721_INSTANTIATE_REPLY_ID: u64 = 7;
+
+pub const STORAGE1155_INSTANTIATE_REPLY_ID: u64 = 8;
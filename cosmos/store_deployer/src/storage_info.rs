@@ -9,3 +9,14 @@ pub struct StorageInstantiateMsg {
     pub is_original: bool,
     pub token_id: String,
 }
+
+#[cw_serde]
+pub struct Storage1155InstantiateMsg {
+    pub collection_address: Addr,
+    pub owner: Addr,
+    pub collection_code_id: u64,
+    pub is_original: bool,
+    pub token_id: String,
+    pub token_amount: u128,
+    pub from: Addr,
+}
@@ -3,12 +3,17 @@ mod tests {
     use std::error::Error;
 
     use cosmwasm_std::Addr;
-    use cw721_base::entry::query;
-    use cw_multi_test::{App, ContractWrapper, Executor};
+    use cw_multi_test::{
+        App, AppBuilder, BankKeeper, ContractWrapper, Executor, MockAddressGenerator,
+        MockApiBech32, WasmKeeper,
+    };
 
     use crate::{
-        entry::{execute, instantiate, reply},
-        msg::{StoreFactoryExecuteMsg, StoreFactoryInstantiateMsg},
+        entry::{execute, instantiate, query, reply},
+        msg::{
+            PredictStorageAddressResponse, StorageKind, StoreFactoryExecuteMsg,
+            StoreFactoryInstantiateMsg, StoreFactoryQueryMsg,
+        },
     };
 
     fn prepare_storage() -> ContractWrapper<
@@ -19,17 +24,38 @@ mod tests {
         nft_store::error::StorageContractError,
         nft_store::error::StorageContractError,
     > {
-        let code = ContractWrapper::new(
+        ContractWrapper::new(
             nft_store::entry::execute,
             nft_store::entry::instantiate,
             nft_store::entry::query,
-        );
-        code
+        )
+    }
+
+    fn prepare_storage_1155() -> ContractWrapper<
+        nft_store1155::msg::NftStoreExecuteMsg,
+        nft_store1155::msg::NftStoreInstantiateMsg,
+        nft_store1155::msg::NftStoreQueryMsg,
+        nft_store1155::error::StorageContractError,
+        nft_store1155::error::StorageContractError,
+        nft_store1155::error::StorageContractError,
+    > {
+        ContractWrapper::new(
+            nft_store1155::entry::execute,
+            nft_store1155::entry::instantiate,
+            nft_store1155::entry::query,
+        )
+    }
+
+    fn mock_app() -> App<BankKeeper, MockApiBech32> {
+        AppBuilder::new()
+            .with_api(MockApiBech32::new("cosmwasm"))
+            .with_wasm(WasmKeeper::default().with_address_generator(MockAddressGenerator))
+            .build(|_, _, _| {})
     }
 
     #[test]
     fn test_contract() -> Result<(), Box<dyn Error>> {
-        let mut app = App::default();
+        let mut app = mock_app();
 
         let storage_code = prepare_storage();
 
@@ -40,10 +66,11 @@ mod tests {
         let storage_factory_code_id: u64 = app.store_code(Box::new(storage_factory_code));
 
         let addr = app.instantiate_contract(
-            storage_factory_code_id.clone(),
-            Addr::unchecked("owner"),
+            storage_factory_code_id,
+            app.api().addr_make("owner"),
             &StoreFactoryInstantiateMsg {
                 storage721_code_id: storage_code_id,
+                storage1155_code_id: storage_code_id,
             },
             &[],
             "Storage",
@@ -53,13 +80,15 @@ mod tests {
         let addr = addr.unwrap();
 
         let create = app.execute_contract(
-            Addr::unchecked("owner"),
+            app.api().addr_make("owner"),
             addr,
             &StoreFactoryExecuteMsg::CreateStorage721 {
                 label: "nft".to_string(),
-                collection_address: Addr::unchecked("collection"),
+                collection_address: app.api().addr_make("collection"),
+                source_chain: "cosmos".to_string(),
+                source_nft_contract_address: app.api().addr_make("collection").to_string(),
                 collection_code_id: 1231,
-                owner: "owner".to_string(),
+                owner: app.api().addr_make("owner").to_string(),
                 is_original: true,
                 token_id: "1".to_string(),
             },
@@ -67,17 +96,14 @@ mod tests {
         );
         assert!(create.is_ok());
         let create = create.unwrap();
-        let inst_ev = create
-            .events
-            .iter()
-            .find(|ev| ev.ty == "instantiate".to_string());
+        let inst_ev = create.events.iter().find(|ev| ev.ty == "instantiate");
 
         assert!(inst_ev.is_some());
         let inst_ev = inst_ev.unwrap().clone();
         let address = inst_ev
             .attributes
             .iter()
-            .find(|e| e.key == "_contract_addr");
+            .find(|e| e.key == "_contract_address");
         assert!(address.is_some());
         let address = address.unwrap().value.clone();
 
@@ -88,7 +114,213 @@ mod tests {
                 &nft_store::msg::NftStoreQueryMsg::GetCollectionAddress,
             )
             .unwrap();
-        assert_eq!(a, Addr::unchecked("collection"));
+        assert_eq!(a, app.api().addr_make("collection"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_storage_1155() -> Result<(), Box<dyn Error>> {
+        let mut app = mock_app();
+
+        let storage721_code_id = app.store_code(Box::new(prepare_storage()));
+        let storage1155_code_id = app.store_code(Box::new(prepare_storage_1155()));
+
+        let storage_factory_code = ContractWrapper::new(execute, instantiate, query);
+        let storage_factory_code = storage_factory_code.with_reply(reply);
+        let storage_factory_code_id: u64 = app.store_code(Box::new(storage_factory_code));
+
+        let addr = app.instantiate_contract(
+            storage_factory_code_id,
+            app.api().addr_make("owner"),
+            &StoreFactoryInstantiateMsg {
+                storage721_code_id,
+                storage1155_code_id,
+            },
+            &[],
+            "Storage",
+            None,
+        )?;
+
+        let create = app.execute_contract(
+            app.api().addr_make("owner"),
+            addr,
+            &StoreFactoryExecuteMsg::CreateStorage1155 {
+                label: "nft".to_string(),
+                collection_address: app.api().addr_make("collection"),
+                source_chain: "cosmos".to_string(),
+                source_nft_contract_address: app.api().addr_make("collection").to_string(),
+                collection_code_id: 1231,
+                owner: app.api().addr_make("owner").to_string(),
+                is_original: true,
+                token_id: "1".to_string(),
+                token_amount: 5,
+                from: app.api().addr_make("holder"),
+            },
+            &[],
+        );
+        assert!(create.is_ok());
+        let create = create.unwrap();
+
+        let storage_ev = create.events.iter().find(|ev| {
+            ev.attributes
+                .iter()
+                .any(|attr| attr.key == "storage_address_1155")
+        });
+        assert!(storage_ev.is_some());
+
+        let inst_ev = create
+            .events
+            .iter()
+            .find(|ev| ev.ty == "instantiate")
+            .unwrap();
+        let address = inst_ev
+            .attributes
+            .iter()
+            .find(|e| e.key == "_contract_address")
+            .unwrap()
+            .value
+            .clone();
+
+        let a: Addr = app.wrap().query_wasm_smart(
+            address,
+            &nft_store1155::msg::NftStoreQueryMsg::GetCollectionAddress,
+        )?;
+        assert_eq!(a, app.api().addr_make("collection"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_storage_code() -> Result<(), Box<dyn Error>> {
+        let mut app = mock_app();
+
+        let storage_code_id = app.store_code(Box::new(prepare_storage()));
+        let new_storage_code_id = app.store_code(Box::new(prepare_storage()));
+
+        let storage_factory_code =
+            ContractWrapper::new(execute, instantiate, query).with_reply(reply);
+        let storage_factory_code_id = app.store_code(Box::new(storage_factory_code));
+
+        let addr = app.instantiate_contract(
+            storage_factory_code_id,
+            app.api().addr_make("owner"),
+            &StoreFactoryInstantiateMsg {
+                storage721_code_id: storage_code_id,
+                storage1155_code_id: storage_code_id,
+            },
+            &[],
+            "Storage",
+            None,
+        )?;
+
+        let update = StoreFactoryExecuteMsg::UpdateStorageCode {
+            storage721_code_id: Some(new_storage_code_id),
+            storage1155_code_id: None,
+        };
+        let unauthorized =
+            app.execute_contract(app.api().addr_make("other"), addr.clone(), &update, &[]);
+        assert!(unauthorized.is_err());
+
+        app.execute_contract(app.api().addr_make("owner"), addr.clone(), &update, &[])?;
+
+        let create = app.execute_contract(
+            app.api().addr_make("owner"),
+            addr,
+            &StoreFactoryExecuteMsg::CreateStorage721 {
+                label: "nft".to_string(),
+                collection_address: app.api().addr_make("collection"),
+                source_chain: "cosmos".to_string(),
+                source_nft_contract_address: app.api().addr_make("collection").to_string(),
+                collection_code_id: 1231,
+                owner: app.api().addr_make("owner").to_string(),
+                is_original: true,
+                token_id: "1".to_string(),
+            },
+            &[],
+        )?;
+        let address = create
+            .events
+            .iter()
+            .find(|ev| ev.ty == "instantiate")
+            .and_then(|ev| ev.attributes.iter().find(|e| e.key == "_contract_address"))
+            .map(|e| Addr::unchecked(e.value.clone()))
+            .unwrap();
+
+        let storage = app.contract_data(&address)?;
+        assert_eq!(storage.code_id, new_storage_code_id);
+        assert_eq!(storage.admin, Some(app.api().addr_make("owner")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_predict_storage_address() -> Result<(), Box<dyn Error>> {
+        let mut app = mock_app();
+
+        let storage721_code_id = app.store_code(Box::new(prepare_storage()));
+        let storage1155_code_id = app.store_code(Box::new(prepare_storage_1155()));
+        let storage_factory_code =
+            ContractWrapper::new(execute, instantiate, query).with_reply(reply);
+        let storage_factory_code_id = app.store_code(Box::new(storage_factory_code));
+
+        let owner = app.api().addr_make("owner");
+        let collection = app.api().addr_make("collection");
+        let addr = app.instantiate_contract(
+            storage_factory_code_id,
+            owner.clone(),
+            &StoreFactoryInstantiateMsg {
+                storage721_code_id,
+                storage1155_code_id,
+            },
+            &[],
+            "Storage",
+            None,
+        )?;
+
+        let predicted: PredictStorageAddressResponse = app.wrap().query_wasm_smart(
+            addr.clone(),
+            &StoreFactoryQueryMsg::PredictStorageAddress {
+                kind: StorageKind::Storage1155,
+                source_chain: "BSC".to_string(),
+                source_nft_contract_address: "0xabc".to_string(),
+            },
+        )?;
+        let other_chain: PredictStorageAddressResponse = app.wrap().query_wasm_smart(
+            addr.clone(),
+            &StoreFactoryQueryMsg::PredictStorageAddress {
+                kind: StorageKind::Storage1155,
+                source_chain: "ETH".to_string(),
+                source_nft_contract_address: "0xabc".to_string(),
+            },
+        )?;
+        assert_ne!(predicted.address, other_chain.address);
+
+        let create = app.execute_contract(
+            owner.clone(),
+            addr,
+            &StoreFactoryExecuteMsg::CreateStorage1155 {
+                label: "nft".to_string(),
+                collection_address: collection,
+                source_chain: "BSC".to_string(),
+                source_nft_contract_address: "0xabc".to_string(),
+                collection_code_id: 1231,
+                owner: owner.to_string(),
+                is_original: true,
+                token_id: "1".to_string(),
+                token_amount: 5,
+                from: app.api().addr_make("holder"),
+            },
+            &[],
+        )?;
+        let address = create
+            .events
+            .iter()
+            .find(|ev| ev.ty == "instantiate")
+            .and_then(|ev| ev.attributes.iter().find(|e| e.key == "_contract_address"))
+            .map(|e| Addr::unchecked(e.value.clone()))
+            .unwrap();
+        assert_eq!(predicted.address, address);
 
         Ok(())
     }
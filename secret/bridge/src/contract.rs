@@ -3,17 +3,22 @@ use std::collections::BTreeMap;
 use collection_deployer::bridge_msg::ReplyCollectionDeployerInfo;
 use common::CodeInfo;
 use cosmwasm_std::{
-    entry_point, from_binary, to_binary, Addr, Api, Attribute, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, Reply, Response, StdError, StdResult, Storage, SubMsg, SubMsgResult, Uint128, Uint256, WasmMsg
+    entry_point, from_binary, from_slice, to_binary, to_vec, Addr, Api, Attribute, BankMsg, Binary, Coin, CosmosMsg, Deps, DepsMut, Empty, Env, MessageInfo, QueryRequest, Reply, Response, StdError, StdResult, Storage, SubMsg, SubMsgResult, Uint128, Uint256, WasmMsg, WasmQuery
 };
 use schemars::JsonSchema;
+use secret_toolkit::permit::{validate, Permit, RevokedPermits, TokenPermissions};
 use secret_toolkit::serialization::Bincode2;
 use secret_toolkit::storage::{Keymap, WithoutIter};
 use secret_toolkit::utils::{HandleCallback, InitCallback, Query};
+use secret_toolkit::viewing_key::{ViewingKey, ViewingKeyStore};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
+use snip1155::bridge::bridge_token;
 use snip1155::reply::ReplyCollectionInfo as ReplyCollection1155Info;
 use snip1155::state::metadata::Metadata as Snip1155Meta;
-use snip1155::state::state_structs::{
-    CurateTokenId, LbPair, TknConfig, TokenAmount, TokenIdBalance, TokenInfoMsg
+use snip1155::state::state_structs::{TokenAmount, TokenIdBalance};
+use snip721::msg::{
+    QueryWithPermit as Snip721QueryWithPermit, Snip721QueryAnswer, Snip721QueryMsg, ViewerInfo,
 };
 use snip721::reply::ReplyCollectionInfo as ReplyCollection721Info;
 use snip721::royalties::{Royalty, RoyaltyInfo};
@@ -21,28 +26,38 @@ use snip721::token::Metadata as Snip721Meta;
 use storage_deployer::bridge_msg::ReplyStorageDeployerInfo;
 use storage_deployer::structs::{ReplyStorage721Info,ReplyStorage1155Info};
 
+use crate::encryption::{self, derive_secret_key, EncryptedPayload};
 use crate::error::ContractError;
 use crate::events::{
     AddNewValidatorEventInfo, Claimed1155EventInfo, Claimed721EventInfo, LockedEventInfo,
-    RewardValidatorEventInfo, UnLock1155EventInfo, UnLock721EventInfo,
+    PrivateLockPayload, PrivateLockedEventInfo, RewardValidatorEventInfo, UnLock1155EventInfo,
+    UnLock721EventInfo,
+};
+use crate::msg::{
+    BlacklistValidatorMsg, BridgeExecuteAnswer, BridgeExecuteMsg, BridgeQueryAnswer,
+    BridgeQueryMsg, QueryWithPermit, ResponseStatus,
 };
-use crate::msg::{BlacklistValidatorMsg, BridgeExecuteMsg, BridgeQueryAnswer, BridgeQueryMsg};
 use crate::state::{
-    config, config_read, BLACKLISTED_VALIDATORS, CODEHASHES, COLLECTION_DEPLOYER_1155_REPLY_ID,
-    COLLECTION_DEPLOYER_721_REPLY_ID, COLLECTION_DEPLOYER_REPLY_ID, COLLETION_DEPLOYER_CODE,
+    config, config_read, BLACKLISTED_VALIDATORS, BRIDGE_KEY, CODEHASHES,
+    COLLECTION_DEPLOYER_1155_REPLY_ID, COLLECTION_DEPLOYER_721_REPLY_ID,
+    COLLECTION_DEPLOYER_REPLY_ID, COLLETION_DEPLOYER_CODE,
     DUPLICATE_STORAGE_1155, DUPLICATE_STORAGE_721, DUPLICATE_TO_ORIGINAL_STORAGE,
-    NFT_COLLECTION_OWNER, ORIGINAL_STORAGE_1155, ORIGINAL_STORAGE_721,
-    ORIGINAL_TO_DUPLICATE_STORAGE, STORAGE_DEPLOYER_1155_REPLY_ID, STORAGE_DEPLOYER_721_REPLY_ID,
-    STORAGE_DEPLOYER_CODE, STORAGE_DEPLOYER_REPLY_ID, UNIQUE_IDENTIFIER_STORAGE,
-    VALIDATORS_STORAGE,
+    CLAIMS_COUNT, LOCKS_COUNT, LOCK_NONCE, NFT_COLLECTION_OWNER, ORIGINAL_STORAGE_1155, ORIGINAL_STORAGE_721,
+    ORIGINAL_TO_DUPLICATE_STORAGE, PENDING_PRIVATE_METADATA_721, PREFIX_REVOKED_PERMITS,
+    PRIVATE_LOCKS, VALIDATOR_ENCRYPTION_KEYS, VALIDATOR_ENCRYPTION_KEY_NONCES,
+    STORAGE_DEPLOYER_1155_REPLY_ID, STORAGE_DEPLOYER_721_REPLY_ID, STORAGE_DEPLOYER_CODE,
+    STORAGE_DEPLOYER_REPLY_ID, UNIQUE_IDENTIFIER_STORAGE, USER_CLAIMS, USER_DESTINATIONS,
+    USER_LOCKS, VALIDATORS_STORAGE,
 };
 use crate::structs::{
-    AddValidatorMsg, BridgeInstantiateMsg, ClaimData, ClaimMsg, ClaimValidatorRewardsMsg,
-    DuplicateToOriginalContractInfo, Lock1155Msg, Lock721Msg, OriginalToDuplicateContractInfo,
-    SignerAndSignature, State, Validator, VerifyMsg,
+    AddValidatorMsg, BridgeInstantiateMsg, ClaimData, ClaimMsg, ClaimRecord,
+    ClaimValidatorRewardsMsg, Destination, DuplicateToOriginalContractInfo, Lock1155Msg,
+    Lock721Msg, LockRecord, OriginalToDuplicateContractInfo, PrivateMetadataLock, ReceiveLockMsg,
+    SetValidatorEncryptionKeyMsg,
+    SignerAndSignature, State, Validator, ValidatorLockPayload, VerifyMsg,
 };
 use sha2::{Digest, Sha256};
-use snip1155::msg::{Snip1155ExecuteMsg,Snip1155QueryMsg};
+use snip1155::msg::{Snip1155ExecuteMsg, Snip1155QueryAnswer, Snip1155QueryMsg};
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
 struct Balance {
@@ -81,6 +96,15 @@ pub fn instantiate(
 
     config(deps.storage).save(&state)?;
 
+    let random = _env
+        .block
+        .random
+        .clone()
+        .ok_or_else(|| StdError::generic_err("Block randomness is not available"))?;
+    let bridge_key = derive_secret_key(&[random.as_slice(), _env.contract.address.as_bytes()])?;
+    BRIDGE_KEY.save(deps.storage, &bridge_key)?;
+    ViewingKey::set_seed(deps.storage, random.as_slice());
+
     STORAGE_DEPLOYER_CODE.save(deps.storage, &msg.storage_deployer_code_info)?;
     COLLETION_DEPLOYER_CODE.save(deps.storage, &msg.collection_deployer_code_info)?;
 
@@ -134,14 +158,90 @@ pub fn execute(
         BridgeExecuteMsg::AddValidator { data } => add_validator(deps, data),
         BridgeExecuteMsg::ClaimValidatorRewards { data } => claim_validator_rewards(deps, data),
         BridgeExecuteMsg::BlacklistValidator { data } => blacklist_validator(deps, data),
-        BridgeExecuteMsg::Lock721 { data } => lock721(deps, env, data),
-        BridgeExecuteMsg::Lock1155 { data } => lock1155(deps, env, info, data),
+        BridgeExecuteMsg::Lock721 { data } => {
+            require_permit(&data.private_metadata)?;
+            lock721(deps, env, info.sender, data)
+        }
+        BridgeExecuteMsg::Lock1155 { data } => {
+            require_permit(&data.private_metadata)?;
+            lock1155(deps, env, info.sender.clone(), info.sender, data)
+        }
         BridgeExecuteMsg::Claim721 { data } => claim721(deps, env, info, data),
         BridgeExecuteMsg::Claim1155 { data } => claim1155(deps, env, info, data),
         BridgeExecuteMsg::VerifySig { data } => verify_sig(deps, data),
+        BridgeExecuteMsg::SetValidatorEncryptionKey { data } => {
+            set_validator_encryption_key(deps, env, data)
+        }
+        BridgeExecuteMsg::RegisterReceiver {
+            collection,
+            code_hash,
+            nft_type,
+        } => register_receiver(deps, env, collection, code_hash, nft_type),
+        BridgeExecuteMsg::ReceiveNft {
+            sender,
+            token_id,
+            msg,
+        } => receive_nft(deps, env, info, sender, vec![token_id], msg),
+        BridgeExecuteMsg::BatchReceiveNft {
+            from,
+            token_ids,
+            msg,
+            ..
+        } => receive_nft(deps, env, info, from, token_ids, msg),
+        BridgeExecuteMsg::Snip1155Receive {
+            token_id,
+            from,
+            amount,
+            msg,
+            ..
+        } => receive_1155(deps, env, info, from, token_id, amount, msg),
+        BridgeExecuteMsg::CreateViewingKey { entropy } => {
+            create_viewing_key(deps, env, info, entropy)
+        }
+        BridgeExecuteMsg::SetViewingKey { key } => set_viewing_key(deps, info, key),
+        BridgeExecuteMsg::RevokePermit { permit_name } => revoke_permit(deps, info, permit_name),
     }
 }
 
+fn create_viewing_key(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    entropy: String,
+) -> StdResult<Response> {
+    let key = ViewingKey::create(
+        deps.storage,
+        &info,
+        &env,
+        info.sender.as_str(),
+        entropy.as_ref(),
+    );
+    Ok(Response::new().set_data(to_binary(&BridgeExecuteAnswer::CreateViewingKey { key })?))
+}
+
+fn set_viewing_key(deps: DepsMut, info: MessageInfo, key: String) -> StdResult<Response> {
+    ViewingKey::set(deps.storage, info.sender.as_str(), key.as_str());
+    Ok(
+        Response::new().set_data(to_binary(&BridgeExecuteAnswer::SetViewingKey {
+            status: ResponseStatus::Success,
+        })?),
+    )
+}
+
+fn revoke_permit(deps: DepsMut, info: MessageInfo, permit_name: String) -> StdResult<Response> {
+    RevokedPermits::revoke_permit(
+        deps.storage,
+        PREFIX_REVOKED_PERMITS,
+        info.sender.as_str(),
+        &permit_name,
+    );
+    Ok(
+        Response::new().set_data(to_binary(&BridgeExecuteAnswer::RevokePermit {
+            status: ResponseStatus::Success,
+        })?),
+    )
+}
+
 fn verify_sig(deps: DepsMut, msg: VerifyMsg) -> StdResult<Response> {
     let serialized = serde_json::to_vec(&msg.claim_data_as_binary)
         .expect("Failed to convert claim data to binary");
@@ -243,6 +343,7 @@ fn blacklist_validator(deps: DepsMut, blacklist_msg: BlacklistValidatorMsg) -> S
         return Err(StdError::generic_err("Threshold not reached!"));
     }
     VALIDATORS_STORAGE.remove(deps.storage, &blacklist_msg.validator.0)?;
+    VALIDATOR_ENCRYPTION_KEYS.remove(deps.storage, &blacklist_msg.validator.0)?;
 
     config(deps.storage).update(|mut state| {
         state.validators_count -= 1;
@@ -252,6 +353,50 @@ fn blacklist_validator(deps: DepsMut, blacklist_msg: BlacklistValidatorMsg) -> S
     Ok(Response::new())
 }
 
+/// Registers the encryption key of a validator. The signature covers the
+/// bridge address and a per-validator nonce, so it can neither be replayed
+/// here to restore an older key nor on another bridge.
+fn set_validator_encryption_key(
+    deps: DepsMut,
+    env: Env,
+    msg: SetValidatorEncryptionKeyMsg,
+) -> StdResult<Response> {
+    if !VALIDATORS_STORAGE.contains(deps.storage, &msg.validator) {
+        return Err(StdError::generic_err("Validator does not exist!"));
+    }
+    encryption::check_public_key(&msg.encryption_key)?;
+
+    let expected = VALIDATOR_ENCRYPTION_KEY_NONCES
+        .get(deps.storage, &msg.validator)
+        .unwrap_or_default()
+        + 1;
+    if msg.nonce != expected {
+        return Err(StdError::generic_err(format!(
+            "Invalid nonce, expected {}, found {}",
+            expected, msg.nonce
+        )));
+    }
+
+    let mut hasher = Sha256::new();
+    for field in [
+        env.contract.address.as_bytes(),
+        msg.validator.as_slice(),
+        msg.encryption_key.as_slice(),
+    ] {
+        hasher.update((field.len() as u64).to_be_bytes());
+        hasher.update(field);
+    }
+    hasher.update(msg.nonce.to_be_bytes());
+    let hash: [u8; 32] = hasher.finalize().into();
+    if !verify_signature(deps.api, &msg.signature, &msg.validator, &hash)? {
+        return Err(StdError::generic_err("Invalid validator signature"));
+    }
+
+    VALIDATOR_ENCRYPTION_KEY_NONCES.insert(deps.storage, &msg.validator, &msg.nonce)?;
+    VALIDATOR_ENCRYPTION_KEYS.insert(deps.storage, &msg.validator, &msg.encryption_key)?;
+    Ok(Response::new())
+}
+
 fn verify_signature(
     api: &dyn Api,
     signature: &[u8],
@@ -425,7 +570,9 @@ fn transfer_to_storage_721(
     Ok(Response::new().add_message(transfer_msg))
 }
 
-fn lock721(deps: DepsMut, env: Env, msg: Lock721Msg) -> StdResult<Response> {
+/// Locks `msg.token_id` of `owner`. The bridge moves the token to storage,
+/// so it must be approved for the token or already own it.
+fn lock721(deps: DepsMut, env: Env, owner: Addr, msg: Lock721Msg) -> StdResult<Response> {
     let addr_result = deps
         .api
         .addr_validate(&msg.source_nft_contract_address.clone().into_string());
@@ -439,6 +586,49 @@ fn lock721(deps: DepsMut, env: Env, msg: Lock721Msg) -> StdResult<Response> {
         }
     }
 
+    let private_metadata = match msg.private_metadata {
+        Some(lock) => {
+            let metadata = query_private_metadata_721(
+                deps.as_ref(),
+                &env,
+                &msg.source_nft_contract_address,
+                &msg.collection_code_info.code_hash,
+                &msg.token_id,
+                lock.permit,
+            )?;
+            encrypt_private_metadata(
+                deps.storage,
+                &env,
+                &msg.source_nft_contract_address,
+                &msg.token_id,
+                &lock.recipient_key,
+                metadata,
+            )?
+        }
+        None => None,
+    };
+
+    let lock_nonce = if msg.private_lock {
+        Some(next_lock_nonce(deps.storage)?)
+    } else {
+        None
+    };
+
+    record_lock(
+        deps.storage,
+        &owner,
+        LockRecord {
+            token_id: msg.token_id.clone(),
+            source_nft_contract_address: msg.source_nft_contract_address.clone(),
+            token_amount: 1,
+            nft_type: config_read(deps.storage).load()?.type_erc_721,
+            destination_chain: msg.destination_chain.clone(),
+            destination_user_address: msg.destination_user_address.clone(),
+            timestamp: env.block.time.seconds(),
+            lock_nonce,
+        },
+    )?;
+
     let original_collection_address_option = DUPLICATE_TO_ORIGINAL_STORAGE.get(
         deps.storage,
         &(msg.source_nft_contract_address.clone(), self_chain.clone()),
@@ -447,7 +637,7 @@ fn lock721(deps: DepsMut, env: Env, msg: Lock721Msg) -> StdResult<Response> {
     match original_collection_address_option {
         Some(_v) => {
             // notOriginal
-            let log: Vec<Attribute> = vec![LockedEventInfo::new(
+            let lock = LockedEventInfo::new(
                 msg.token_id.clone(),
                 msg.destination_chain,
                 msg.destination_user_address,
@@ -456,8 +646,9 @@ fn lock721(deps: DepsMut, env: Env, msg: Lock721Msg) -> StdResult<Response> {
                 config_read(deps.storage).load()?.type_erc_721,
                 _v.chain,
                 msg.metadata_uri,
-            )
-            .try_into()?];
+                private_metadata,
+            );
+            let log = lock_event_log(deps.storage, &env, lock_nonce, lock)?;
 
             let res = check_storage_721(
                 deps,
@@ -476,7 +667,7 @@ fn lock721(deps: DepsMut, env: Env, msg: Lock721Msg) -> StdResult<Response> {
         None => {
             // isOriginal
 
-            let log: Vec<Attribute> = vec![LockedEventInfo::new(
+            let lock = LockedEventInfo::new(
                 msg.token_id.clone(),
                 msg.destination_chain.clone(),
                 msg.destination_user_address,
@@ -485,8 +676,9 @@ fn lock721(deps: DepsMut, env: Env, msg: Lock721Msg) -> StdResult<Response> {
                 config_read(deps.storage).load()?.type_erc_721,
                 config(deps.storage).load()?.self_chain,
                 msg.metadata_uri,
-            )
-            .try_into()?];
+                private_metadata,
+            );
+            let log = lock_event_log(deps.storage, &env, lock_nonce, lock)?;
 
             let res = check_storage_721(
                 deps,
@@ -619,7 +811,15 @@ fn transfer_to_storage_1155(
     Ok(Response::new().add_message(transfer_msg))
 }
 
-fn lock1155(deps: DepsMut, env: Env, info: MessageInfo, msg: Lock1155Msg) -> StdResult<Response> {
+/// Locks tokens of `owner`, which the bridge moves to storage from `from`:
+/// the owner if the bridge is approved, or the bridge if it received them.
+fn lock1155(
+    deps: DepsMut,
+    env: Env,
+    owner: Addr,
+    from: Addr,
+    msg: Lock1155Msg,
+) -> StdResult<Response> {
     let addr_result = deps
         .api
         .addr_validate(&msg.source_nft_contract_address.clone().into_string());
@@ -637,6 +837,49 @@ fn lock1155(deps: DepsMut, env: Env, info: MessageInfo, msg: Lock1155Msg) -> Std
         return Err(StdError::generic_err("token amount must be > than zero"));
     }
 
+    let private_metadata = match msg.private_metadata {
+        Some(lock) => {
+            let metadata = query_private_metadata_1155(
+                deps.as_ref(),
+                &env,
+                &msg.source_nft_contract_address,
+                &msg.collection_code_info.code_hash,
+                &msg.token_id,
+                lock.permit,
+            )?;
+            encrypt_private_metadata(
+                deps.storage,
+                &env,
+                &msg.source_nft_contract_address,
+                &msg.token_id,
+                &lock.recipient_key,
+                metadata,
+            )?
+        }
+        None => None,
+    };
+
+    let lock_nonce = if msg.private_lock {
+        Some(next_lock_nonce(deps.storage)?)
+    } else {
+        None
+    };
+
+    record_lock(
+        deps.storage,
+        &owner,
+        LockRecord {
+            token_id: msg.token_id.clone(),
+            source_nft_contract_address: msg.source_nft_contract_address.clone(),
+            token_amount: msg.token_amount,
+            nft_type: config_read(deps.storage).load()?.type_erc_1155,
+            destination_chain: msg.destination_chain.clone(),
+            destination_user_address: msg.destination_user_address.clone(),
+            timestamp: env.block.time.seconds(),
+            lock_nonce,
+        },
+    )?;
+
     let original_collection_address_option = DUPLICATE_TO_ORIGINAL_STORAGE.get(
         deps.storage,
         &(msg.source_nft_contract_address.clone(), self_chain.clone()),
@@ -646,7 +889,7 @@ fn lock1155(deps: DepsMut, env: Env, info: MessageInfo, msg: Lock1155Msg) -> Std
         Some(_v) => {
             // notOriginal
 
-            let log: Vec<Attribute> = vec![LockedEventInfo::new(
+            let lock = LockedEventInfo::new(
                 msg.token_id.clone(),
                 msg.destination_chain,
                 msg.destination_user_address,
@@ -655,8 +898,9 @@ fn lock1155(deps: DepsMut, env: Env, info: MessageInfo, msg: Lock1155Msg) -> Std
                 config_read(deps.storage).load()?.type_erc_1155,
                 _v.chain,
                 msg.metadata_uri,
-            )
-            .try_into()?];
+                private_metadata,
+            );
+            let log = lock_event_log(deps.storage, &env, lock_nonce, lock)?;
 
             let res = check_storage_1155(
                 deps,
@@ -668,7 +912,7 @@ fn lock1155(deps: DepsMut, env: Env, info: MessageInfo, msg: Lock1155Msg) -> Std
                 msg.collection_code_info,
                 env.contract.address,
                 false,
-                info.sender.clone(),
+                from.clone(),
                 env.block.time.seconds(),
             )?;
 
@@ -677,7 +921,7 @@ fn lock1155(deps: DepsMut, env: Env, info: MessageInfo, msg: Lock1155Msg) -> Std
         None => {
             // isOriginal
 
-            let log: Vec<Attribute> = vec![LockedEventInfo::new(
+            let lock = LockedEventInfo::new(
                 msg.token_id.clone(),
                 msg.destination_chain.clone(),
                 msg.destination_user_address,
@@ -686,8 +930,9 @@ fn lock1155(deps: DepsMut, env: Env, info: MessageInfo, msg: Lock1155Msg) -> Std
                 config_read(deps.storage).load()?.type_erc_1155,
                 config(deps.storage).load()?.self_chain,
                 msg.metadata_uri,
-            )
-            .try_into()?];
+                private_metadata,
+            );
+            let log = lock_event_log(deps.storage, &env, lock_nonce, lock)?;
 
             let res = check_storage_1155(
                 deps,
@@ -699,7 +944,7 @@ fn lock1155(deps: DepsMut, env: Env, info: MessageInfo, msg: Lock1155Msg) -> Std
                 msg.collection_code_info,
                 env.contract.address,
                 true,
-                info.sender.clone(),
+                from.clone(),
                 env.block.time.seconds(),
             )?;
             Ok(res.add_attributes(log))
@@ -707,6 +952,388 @@ fn lock1155(deps: DepsMut, env: Env, info: MessageInfo, msg: Lock1155Msg) -> Std
     }
 }
 
+/// Registers the bridge on `collection` so that it is called back when tokens
+/// are sent to it
+fn register_receiver(
+    deps: DepsMut,
+    env: Env,
+    collection: Addr,
+    code_hash: String,
+    nft_type: String,
+) -> StdResult<Response> {
+    let state = config_read(deps.storage).load()?;
+    let key = collection_viewing_key(deps.storage, &collection)?;
+    let messages = if nft_type == state.type_erc_721 {
+        // without batch support the collection sends one callback per token,
+        // each locked after the previous one deployed the storage
+        vec![
+            snip721::msg::Snip721ExecuteMsg::RegisterReceiveNft {
+                code_hash: env.contract.code_hash,
+                also_implements_batch_receive_nft: Some(false),
+                padding: Option::None,
+            }
+            .to_cosmos_msg(code_hash.clone(), collection.to_string(), None)?,
+            snip721::msg::Snip721ExecuteMsg::SetViewingKey {
+                key,
+                padding: Option::None,
+            }
+            .to_cosmos_msg(code_hash, collection.into_string(), None)?,
+        ]
+    } else if nft_type == state.type_erc_1155 {
+        vec![
+            Snip1155ExecuteMsg::RegisterReceive {
+                code_hash: env.contract.code_hash,
+                padding: Option::None,
+            }
+            .to_cosmos_msg(code_hash.clone(), collection.to_string(), None)?,
+            Snip1155ExecuteMsg::SetViewingKey {
+                key,
+                padding: Option::None,
+            }
+            .to_cosmos_msg(code_hash, collection.into_string(), None)?,
+        ]
+    } else {
+        return Err(StdError::generic_err("Invalid NFT type!"));
+    };
+    Ok(Response::new().add_messages(messages))
+}
+
+/// Viewing key the bridge sets on `collection`. It is derived from the bridge
+/// key per collection, so a collection never learns a key valid on another.
+fn collection_viewing_key(storage: &dyn Storage, collection: &Addr) -> StdResult<String> {
+    let mut hasher = Sha256::new();
+    hasher.update(BRIDGE_KEY.load(storage)?);
+    hasher.update(b"viewing_key");
+    hasher.update(collection.as_bytes());
+    Ok(Binary::from(hasher.finalize().to_vec()).to_base64())
+}
+
+/// The bridge owns a token it received, so it reads the private metadata with
+/// its own viewing key rather than a permit of the previous owner
+fn received_private_metadata(lock: Option<PrivateMetadataLock>) -> Option<PrivateMetadataLock> {
+    lock.map(|lock| PrivateMetadataLock {
+        permit: None,
+        ..lock
+    })
+}
+
+fn receive_lock_msg(msg: Option<Binary>) -> StdResult<ReceiveLockMsg> {
+    match msg {
+        Some(msg) => from_binary(&msg),
+        None => Err(StdError::generic_err("Missing lock message")),
+    }
+}
+
+/// Locks a token sent to the bridge with `SendNft`, on behalf of its previous
+/// owner `from`
+fn receive_nft(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    from: Addr,
+    token_ids: Vec<String>,
+    msg: Option<Binary>,
+) -> StdResult<Response> {
+    // a lock may deploy a storage for the collection, which can only be
+    // done once per message. `RegisterReceiver` opts out of batch callbacks,
+    // so only collections registered before that send batches here
+    let token_id = match token_ids.as_slice() {
+        [token_id] => token_id.clone(),
+        _ => return Err(StdError::generic_err("Send one token per lock")),
+    };
+    let lock = receive_lock_msg(msg)?;
+    lock721(
+        deps,
+        env,
+        from,
+        Lock721Msg {
+            destination_chain: lock.destination_chain,
+            destination_user_address: lock.destination_user_address,
+            source_nft_contract_address: info.sender,
+            collection_code_info: lock.collection_code_info,
+            token_id,
+            metadata_uri: lock.metadata_uri,
+            private_metadata: received_private_metadata(lock.private_metadata),
+            private_lock: lock.private_lock,
+        },
+    )
+}
+
+/// Locks 1155 tokens sent to the bridge with `Send`, on behalf of their
+/// previous owner `from`
+fn receive_1155(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    from: Addr,
+    token_id: String,
+    amount: Uint256,
+    msg: Option<Binary>,
+) -> StdResult<Response> {
+    let token_amount = Uint128::try_from(amount)
+        .map_err(|_| StdError::generic_err("token amount overflows u128"))?
+        .u128();
+    let lock = receive_lock_msg(msg)?;
+    let bridge = env.contract.address.clone();
+    lock1155(
+        deps,
+        env,
+        from,
+        bridge,
+        Lock1155Msg {
+            destination_chain: lock.destination_chain,
+            destination_user_address: lock.destination_user_address,
+            source_nft_contract_address: info.sender,
+            collection_code_info: lock.collection_code_info,
+            token_id,
+            token_amount,
+            metadata_uri: lock.metadata_uri,
+            private_metadata: received_private_metadata(lock.private_metadata),
+            private_lock: lock.private_lock,
+        },
+    )
+}
+
+fn next_lock_nonce(storage: &mut dyn Storage) -> StdResult<u64> {
+    let nonce = LOCK_NONCE.may_load(storage)?.unwrap_or_default() + 1;
+    LOCK_NONCE.save(storage, &nonce)?;
+    Ok(nonce)
+}
+
+/// Logs `lock` in plaintext, or, for a private lock, stores it encrypted to
+/// each validator and only logs its nonce and commitment
+fn lock_event_log(
+    storage: &mut dyn Storage,
+    env: &Env,
+    lock_nonce: Option<u64>,
+    lock: LockedEventInfo,
+) -> StdResult<Vec<Attribute>> {
+    let nonce = match lock_nonce {
+        Some(nonce) => nonce,
+        None => return Ok(vec![lock.try_into()?]),
+    };
+
+    let bridge_key = BRIDGE_KEY.load(storage)?;
+    let random = env.block.random.clone().unwrap_or_default();
+    let mut hasher = Sha256::new();
+    hasher.update(bridge_key);
+    hasher.update(random.as_slice());
+    hasher.update(nonce.to_be_bytes());
+    let salt: [u8; 32] = hasher.finalize().into();
+
+    let payload = to_vec(&PrivateLockPayload {
+        nonce,
+        salt: Binary::from(salt.to_vec()),
+        lock,
+    })?;
+    let mut hasher = Sha256::new();
+    hasher.update(&payload);
+    let commitment: [u8; 32] = hasher.finalize().into();
+
+    let mut payloads = vec![];
+    for item in VALIDATOR_ENCRYPTION_KEYS.iter(storage)? {
+        let (validator, encryption_key) = item?;
+        if !VALIDATORS_STORAGE.contains(storage, &validator) {
+            continue;
+        }
+        let ephemeral_key = derive_secret_key(&[&bridge_key, &salt, validator.as_slice()])?;
+        payloads.push(ValidatorLockPayload {
+            validator,
+            payload: encryption::encrypt(&encryption_key, &ephemeral_key, &payload)?,
+        });
+    }
+    // the lock could never be claimed if too few validators can read it
+    let state = config_read(storage).load()?;
+    if (payloads.len() as i128) < required_threshold(state.validators_count as u128) {
+        return Err(StdError::generic_err(
+            "Not enough validators registered an encryption key",
+        ));
+    }
+    PRIVATE_LOCKS.insert(storage, &nonce, &payloads)?;
+
+    Ok(vec![PrivateLockedEventInfo::new(
+        nonce,
+        Binary::from(commitment.to_vec()),
+    )
+    .try_into()?])
+}
+
+/// Records a lock for the private queries of the locking user
+fn record_lock(storage: &mut dyn Storage, sender: &Addr, record: LockRecord) -> StdResult<()> {
+    let destinations = USER_DESTINATIONS.add_suffix(sender.as_bytes());
+    let destination = (
+        record.destination_chain.clone(),
+        record.destination_user_address.clone(),
+    );
+    let locks = destinations.get(storage, &destination).unwrap_or_default();
+    destinations.insert(storage, &destination, &(locks + 1))?;
+    USER_LOCKS.add_suffix(sender.as_bytes()).push(storage, &record)?;
+    let count = LOCKS_COUNT.may_load(storage)?.unwrap_or_default();
+    LOCKS_COUNT.save(storage, &(count + 1))
+}
+
+/// Records a claim for the private queries of the receiving user
+fn record_claim(storage: &mut dyn Storage, data: &ClaimData, timestamp: u64) -> StdResult<()> {
+    USER_CLAIMS
+        .add_suffix(data.destination_user_address.as_bytes())
+        .push(
+            storage,
+            &ClaimRecord {
+                token_id: data.token_id.clone(),
+                source_chain: data.source_chain.clone(),
+                source_nft_contract_address: data.source_nft_contract_address.clone(),
+                token_amount: data.token_amount,
+                nft_type: data.nft_type.clone(),
+                transaction_hash: data.transaction_hash.clone(),
+                timestamp,
+            },
+        )?;
+    let count = CLAIMS_COUNT.may_load(storage)?.unwrap_or_default();
+    CLAIMS_COUNT.save(storage, &(count + 1))
+}
+
+/// Checks that a lock of private metadata carries the owner permit, the
+/// bridge viewing key only reads tokens sent to the bridge
+fn require_permit(private_metadata: &Option<PrivateMetadataLock>) -> StdResult<()> {
+    match private_metadata {
+        Some(PrivateMetadataLock { permit: None, .. }) => Err(StdError::generic_err(
+            "A permit is required to lock private metadata",
+        )),
+        _ => Ok(()),
+    }
+}
+
+fn query_private_metadata_721(
+    deps: Deps,
+    env: &Env,
+    collection: &Addr,
+    code_hash: &str,
+    token_id: &str,
+    permit: Option<Permit>,
+) -> StdResult<Option<Snip721Meta>> {
+    let query = match permit {
+        Some(permit) => Snip721QueryMsg::WithPermit {
+            permit,
+            query: Snip721QueryWithPermit::PrivateMetadata {
+                token_id: token_id.to_string(),
+            },
+        },
+        None => Snip721QueryMsg::PrivateMetadata {
+            token_id: token_id.to_string(),
+            viewer: Some(ViewerInfo {
+                address: env.contract.address.to_string(),
+                viewing_key: collection_viewing_key(deps.storage, collection)?,
+            }),
+        },
+    };
+    let answer: Snip721QueryAnswer = deps.querier.query(&QueryRequest::Wasm(WasmQuery::Smart {
+        contract_addr: collection.to_string(),
+        code_hash: code_hash.to_string(),
+        msg: to_binary(&query)?,
+    }))?;
+    match answer {
+        Snip721QueryAnswer::PrivateMetadata {
+            token_uri: None,
+            extension: None,
+        } => Ok(None),
+        Snip721QueryAnswer::PrivateMetadata {
+            token_uri,
+            extension,
+        } => Ok(Some(Snip721Meta {
+            token_uri,
+            extension,
+        })),
+        _ => Err(StdError::generic_err("Private metadata not readable")),
+    }
+}
+
+fn query_private_metadata_1155(
+    deps: Deps,
+    env: &Env,
+    collection: &Addr,
+    code_hash: &str,
+    token_id: &str,
+    permit: Option<Permit>,
+) -> StdResult<Option<Snip1155Meta>> {
+    let query = match permit {
+        Some(permit) => Snip1155QueryMsg::WithPermit {
+            permit,
+            query: snip1155::msg::QueryWithPermit::TokenIdPrivateInfo {
+                token_id: token_id.to_string(),
+            },
+        },
+        None => Snip1155QueryMsg::TokenIdPrivateInfo {
+            address: env.contract.address.clone(),
+            key: collection_viewing_key(deps.storage, collection)?,
+            token_id: token_id.to_string(),
+        },
+    };
+    let answer = query.query::<Empty, Snip1155QueryAnswer>(
+        deps.querier,
+        code_hash.to_string(),
+        collection.to_string(),
+    )?;
+    match answer {
+        Snip1155QueryAnswer::TokenIdPrivateInfo { token_id_info, .. } => {
+            Ok(token_id_info.private_metadata)
+        }
+        _ => Err(StdError::generic_err("Private metadata not readable")),
+    }
+}
+
+/// Encrypts the private metadata of a locked token to the destination bridge.
+/// The one-time key mixes the bridge key in, so it stays unpredictable even
+/// without block randomness.
+fn encrypt_private_metadata<T: Serialize>(
+    storage: &dyn Storage,
+    env: &Env,
+    collection: &Addr,
+    token_id: &str,
+    recipient_key: &Binary,
+    metadata: Option<T>,
+) -> StdResult<Option<EncryptedPayload>> {
+    let metadata = match metadata {
+        Some(metadata) => metadata,
+        None => return Ok(None),
+    };
+    let bridge_key = BRIDGE_KEY.load(storage)?;
+    let random = env.block.random.clone().unwrap_or_default();
+    let ephemeral_key = derive_secret_key(&[
+        &bridge_key,
+        random.as_slice(),
+        &env.block.height.to_be_bytes(),
+        collection.as_bytes(),
+        token_id.as_bytes(),
+    ])?;
+    Ok(Some(encryption::encrypt(
+        recipient_key,
+        &ephemeral_key,
+        &to_vec(&metadata)?,
+    )?))
+}
+
+/// Decrypts the private metadata of a claim, which was encrypted to the bridge
+/// key. Metadata that doesn't open or parse doesn't hold the claim up: the
+/// token is minted without it and the returned attribute reports that.
+fn decrypt_private_metadata<T: DeserializeOwned>(
+    storage: &dyn Storage,
+    payload: &Option<EncryptedPayload>,
+) -> StdResult<(Option<T>, Vec<Attribute>)> {
+    let payload = match payload {
+        Some(payload) => payload,
+        None => return Ok((None, vec![])),
+    };
+    let metadata = encryption::decrypt(&BRIDGE_KEY.load(storage)?, payload)
+        .and_then(|plaintext| from_slice(&plaintext));
+    match metadata {
+        Ok(metadata) => Ok((Some(metadata), vec![])),
+        Err(_) => Ok((
+            None,
+            vec![Attribute::new("private_metadata", "undecryptable")],
+        )),
+    }
+}
+
 fn create_claim_data_hash(data: ClaimData) -> [u8; 32] {
     let mut hasher = Sha256::new();
     hasher.update(data.concat_all_fields());
@@ -815,7 +1442,6 @@ fn deploy_collection_1155(
     deps: DepsMut,
     name: String,
     symbol: String,
-    owner: Addr,
     source_nft_contract_address: String,
     source_chain: String,
     destination_user_address: Addr,
@@ -826,45 +1452,14 @@ fn deploy_collection_1155(
     metadata: String,
     transaction_hash: String,
     lock_tx_chain: String,
+    private_metadata: Option<Snip1155Meta>,
     time: u64
 ) -> StdResult<Response> {
     let create_collection_msg =
-        collection_deployer::msg::CollectionDeployerExecuteMsg::CreateCollection1155 {
-            has_admin: true,
-            admin: Some(owner.clone()),
-            curators: vec![owner.clone()],
-            initial_tokens: vec![CurateTokenId {
-                token_info: TokenInfoMsg {
-                    token_id: token_id.to_string(),
-                    name: name.clone(),
-                    symbol: symbol.clone(),
-                    token_config: TknConfig::Fungible {
-                        minters: vec![owner.clone()],
-                        decimals: 6,
-                        public_total_supply: true,
-                        enable_mint: true,
-                        enable_burn: true,
-                        minter_may_update_metadata: true,
-                    },
-                    public_metadata: Some(Snip1155Meta {
-                        token_uri: Some(metadata.clone()),
-                        extension: Option::None,
-                    }),
-                    private_metadata: Option::None,
-                },
-                balances: vec![TokenIdBalance {
-                    address: destination_user_address.clone(),
-                    amount: Uint256::from(token_amount),
-                }],
-            }],
-            entropy: name.clone() + &symbol + &source_nft_contract_address,
-            lb_pair_info: LbPair{
-                name: name.clone(),
-                symbol: symbol.clone(),
-                lb_pair_address: Addr::unchecked(&source_nft_contract_address),
-                decimals: 18,
-            },
+        collection_deployer::msg::CollectionDeployerExecuteMsg::CreateBridgeCollection1155 {
             label: name.clone() + &symbol + &source_nft_contract_address + &time.to_string(),
+            name,
+            symbol,
             source_nft_contract_address,
             source_chain,
             destination_user_address,
@@ -873,8 +1468,7 @@ fn deploy_collection_1155(
             royalty,
             royalty_receiver,
             metadata,
-            name,
-            symbol,
+            private_metadata,
             transaction_hash,
             lock_tx_chain,
         };
@@ -932,6 +1526,11 @@ fn claim721(deps: DepsMut, env: Env, info: MessageInfo, msg: ClaimMsg) -> StdRes
         balance.into(),
     )?;
 
+    let (private_metadata, metadata_log): (Option<Snip721Meta>, _) =
+        decrypt_private_metadata(deps.storage, &msg.data.private_metadata)?;
+
+    record_claim(deps.storage, &msg.data, env.block.time.seconds())?;
+
     let duplicate_collection_address_option = ORIGINAL_TO_DUPLICATE_STORAGE.get(
         deps.storage,
         &(
@@ -1042,7 +1641,7 @@ fn claim721(deps: DepsMut, env: Env, info: MessageInfo, msg: ClaimMsg) -> StdRes
                         token_uri: Some(msg.data.metadata),
                         extension: Option::None,
                     }),
-                    private_metadata: Option::None,
+                    private_metadata: private_metadata.clone(),
                     serial_number: Option::None,
                     royalty_info: Some(RoyaltyInfo {
                         decimal_places_in_rates: 4,
@@ -1087,7 +1686,7 @@ fn claim721(deps: DepsMut, env: Env, info: MessageInfo, msg: ClaimMsg) -> StdRes
                 token_uri: Some(msg.data.metadata),
                 extension: Option::None,
             }),
-            private_metadata: Option::None,
+            private_metadata: private_metadata.clone(),
             serial_number: Option::None,
             royalty_info: Some(RoyaltyInfo {
                 decimal_places_in_rates: 4,
@@ -1123,7 +1722,10 @@ fn claim721(deps: DepsMut, env: Env, info: MessageInfo, msg: ClaimMsg) -> StdRes
     }
     // ===============================/ NOT hasDuplicate && NOT hasStorage /=======================
     else if !has_duplicate && !has_storage {
-        // new collection
+        // new collection, minted with the private metadata once it is deployed
+        if let Some(metadata) = &private_metadata {
+            PENDING_PRIVATE_METADATA_721.save(deps.storage, metadata)?;
+        }
         deploy_collection_721(
             deps,
             msg.data.name,
@@ -1211,7 +1813,7 @@ fn claim721(deps: DepsMut, env: Env, info: MessageInfo, msg: ClaimMsg) -> StdRes
                         token_uri: Some(msg.data.metadata),
                         extension: Option::None,
                     }),
-                    private_metadata: Option::None,
+                    private_metadata: private_metadata.clone(),
                     serial_number: Option::None,
                     royalty_info: Some(RoyaltyInfo {
                         decimal_places_in_rates: 4,
@@ -1247,7 +1849,7 @@ fn claim721(deps: DepsMut, env: Env, info: MessageInfo, msg: ClaimMsg) -> StdRes
     } else {
         return Err(StdError::generic_err("Invalid bridge state"));
     }?;
-    Ok(res)
+    Ok(res.add_attributes(metadata_log))
 }
 
 fn claim1155(deps: DepsMut, env: Env, info: MessageInfo, msg: ClaimMsg) -> StdResult<Response> {
@@ -1289,6 +1891,11 @@ fn claim1155(deps: DepsMut, env: Env, info: MessageInfo, msg: ClaimMsg) -> StdRe
         balance.into(),
     );
 
+    let (private_metadata, metadata_log): (Option<Snip1155Meta>, _) =
+        decrypt_private_metadata(deps.storage, &msg.data.private_metadata)?;
+
+    record_claim(deps.storage, &msg.data, env.block.time.seconds())?;
+
     let duplicate_collection_address_option = ORIGINAL_TO_DUPLICATE_STORAGE.get(
         deps.storage,
         &(
@@ -1469,30 +2076,16 @@ fn claim1155(deps: DepsMut, env: Env, info: MessageInfo, msg: ClaimMsg) -> StdRe
             }
             None => {
                 let create_new_token = Snip1155ExecuteMsg::CurateTokenIds {
-                    initial_tokens: vec![CurateTokenId {
-                        token_info: TokenInfoMsg {
-                            token_id: msg.data.token_id.to_string(),
-                            name: msg.data.name.clone(),
-                            symbol: msg.data.symbol.clone(),
-                            token_config: TknConfig::Fungible {
-                                minters: vec![env.contract.address.clone()],
-                                decimals: 6,
-                                public_total_supply: true,
-                                enable_mint: true,
-                                enable_burn: true,
-                                minter_may_update_metadata: true,
-                            },
-                            public_metadata: Some(Snip1155Meta {
-                                token_uri: Some(msg.data.metadata.clone()),
-                                extension: Option::None,
-                            }),
-                            private_metadata: Option::None,
-                        },
-                        balances: vec![TokenIdBalance {
-                            address: msg.data.destination_user_address,
-                            amount: Uint256::from(msg.data.token_amount),
-                        }],
-                    }],
+                    initial_tokens: vec![bridge_token(
+                        env.contract.address.clone(),
+                        msg.data.token_id.to_string(),
+                        msg.data.name.clone(),
+                        msg.data.symbol.clone(),
+                        msg.data.metadata.clone(),
+                        private_metadata.clone(),
+                        msg.data.destination_user_address,
+                        msg.data.token_amount,
+                    )],
                     memo: Option::None,
                     padding: Option::None,
                 };
@@ -1595,30 +2188,16 @@ fn claim1155(deps: DepsMut, env: Env, info: MessageInfo, msg: ClaimMsg) -> StdRe
         }
         else {
             let create_new_token = Snip1155ExecuteMsg::CurateTokenIds {
-                initial_tokens: vec![CurateTokenId {
-                    token_info: TokenInfoMsg {
-                        token_id: msg.data.token_id.to_string(),
-                        name: msg.data.name.clone(),
-                        symbol: msg.data.symbol.clone(),
-                        token_config: TknConfig::Fungible {
-                            minters: vec![env.contract.address.clone()],
-                            decimals: 6,
-                            public_total_supply: true,
-                            enable_mint: true,
-                            enable_burn: true,
-                            minter_may_update_metadata: true,
-                        },
-                        public_metadata: Some(Snip1155Meta {
-                            token_uri: Some(msg.data.metadata.clone()),
-                            extension: Option::None,
-                        }),
-                        private_metadata: Option::None,
-                    },
-                    balances: vec![TokenIdBalance {
-                        address: msg.data.destination_user_address,
-                        amount: Uint256::from(msg.data.token_amount),
-                    }],
-                }],
+                initial_tokens: vec![bridge_token(
+                    env.contract.address.clone(),
+                    msg.data.token_id.to_string(),
+                    msg.data.name.clone(),
+                    msg.data.symbol.clone(),
+                    msg.data.metadata.clone(),
+                    private_metadata.clone(),
+                    msg.data.destination_user_address,
+                    msg.data.token_amount,
+                )],
                 memo: Option::None,
                 padding: Option::None,
             };
@@ -1653,7 +2232,6 @@ fn claim1155(deps: DepsMut, env: Env, info: MessageInfo, msg: ClaimMsg) -> StdRe
             deps,
             msg.data.name,
             msg.data.symbol,
-            env.contract.address,
             msg.data.source_nft_contract_address,
             msg.data.source_chain.clone(),
             msg.data.destination_user_address,
@@ -1664,6 +2242,7 @@ fn claim1155(deps: DepsMut, env: Env, info: MessageInfo, msg: ClaimMsg) -> StdRe
             msg.data.metadata,
             msg.data.transaction_hash,
             msg.data.lock_tx_chain,
+            private_metadata,
             env.block.time.seconds(),
         )
     }
@@ -1818,11 +2397,11 @@ fn claim1155(deps: DepsMut, env: Env, info: MessageInfo, msg: ClaimMsg) -> StdRe
         return Err(StdError::generic_err("Invalid bridge state"));
     }?;
 
-    Ok(res)
+    Ok(res.add_attributes(metadata_log))
 }
 // Queries
 #[entry_point]
-pub fn query(deps: Deps, _env: Env, msg: BridgeQueryMsg) -> StdResult<Binary> {
+pub fn query(deps: Deps, env: Env, msg: BridgeQueryMsg) -> StdResult<Binary> {
     match msg {
         BridgeQueryMsg::GetValidatorsCount {} => to_binary(&validators_count(deps)?),
         BridgeQueryMsg::GetValidator { address } => to_binary(&validators(deps, address)?),
@@ -1844,9 +2423,146 @@ pub fn query(deps: Deps, _env: Env, msg: BridgeQueryMsg) -> StdResult<Binary> {
             contract_address,
             chain,
         } => to_binary(&duplicate_to_original(deps, contract_address, chain)?),
+        BridgeQueryMsg::GetBridgePublicKey {} => to_binary(&bridge_public_key(deps)?),
+        BridgeQueryMsg::GetBridgeStats {} => to_binary(&bridge_stats(deps)?),
+        BridgeQueryMsg::GetValidatorEncryptionKey { validator } => {
+            to_binary(&BridgeQueryAnswer::ValidatorEncryptionKey {
+                data: VALIDATOR_ENCRYPTION_KEYS.get(deps.storage, &validator),
+                nonce: VALIDATOR_ENCRYPTION_KEY_NONCES
+                    .get(deps.storage, &validator)
+                    .unwrap_or_default(),
+            })
+        }
+        BridgeQueryMsg::GetPrivateLock { nonce } => to_binary(&BridgeQueryAnswer::PrivateLock {
+            data: PRIVATE_LOCKS.get(deps.storage, &nonce),
+        }),
+        BridgeQueryMsg::WithPermit { permit, query } => permit_queries(deps, env, permit, query),
+        BridgeQueryMsg::GetMyLocks { .. }
+        | BridgeQueryMsg::GetMyClaims { .. }
+        | BridgeQueryMsg::GetMyDestinations { .. } => viewing_keys_queries(deps, msg),
     }
 }
 
+fn permit_queries(
+    deps: Deps,
+    env: Env,
+    permit: Permit,
+    query: QueryWithPermit,
+) -> StdResult<Binary> {
+    let account = validate(
+        deps,
+        PREFIX_REVOKED_PERMITS,
+        &permit,
+        env.contract.address.to_string(),
+        None,
+    )?;
+    let account = deps.api.addr_validate(&account)?;
+
+    if !permit.check_permission(&TokenPermissions::Owner) {
+        return Err(StdError::generic_err(format!(
+            "`Owner` permit required for bridge permit queries, got permissions {:?}",
+            permit.params.permissions
+        )));
+    }
+
+    match query {
+        QueryWithPermit::MyLocks { page, page_size } => {
+            to_binary(&user_locks(deps, &account, page.unwrap_or(0), page_size)?)
+        }
+        QueryWithPermit::MyClaims { page, page_size } => {
+            to_binary(&user_claims(deps, &account, page.unwrap_or(0), page_size)?)
+        }
+        QueryWithPermit::MyDestinations { page, page_size } => {
+            to_binary(&user_destinations(deps, &account, page.unwrap_or(0), page_size)?)
+        }
+    }
+}
+
+fn viewing_keys_queries(deps: Deps, msg: BridgeQueryMsg) -> StdResult<Binary> {
+    let (address, key) = match &msg {
+        BridgeQueryMsg::GetMyLocks { address, key, .. }
+        | BridgeQueryMsg::GetMyClaims { address, key, .. }
+        | BridgeQueryMsg::GetMyDestinations { address, key, .. } => (address, key),
+        _ => unreachable!("This query type does not require viewing key authentication"),
+    };
+    if ViewingKey::check(deps.storage, address.as_str(), key.as_str()).is_err() {
+        return to_binary(&BridgeQueryAnswer::ViewingKeyError {
+            msg: "Wrong viewing key for this address or viewing key not set".to_string(),
+        });
+    }
+
+    match msg {
+        BridgeQueryMsg::GetMyLocks {
+            address,
+            page,
+            page_size,
+            ..
+        } => to_binary(&user_locks(deps, &address, page.unwrap_or(0), page_size)?),
+        BridgeQueryMsg::GetMyClaims {
+            address,
+            page,
+            page_size,
+            ..
+        } => to_binary(&user_claims(deps, &address, page.unwrap_or(0), page_size)?),
+        BridgeQueryMsg::GetMyDestinations {
+            address,
+            page,
+            page_size,
+            ..
+        } => to_binary(&user_destinations(deps, &address, page.unwrap_or(0), page_size)?),
+        _ => unreachable!("This query type does not require viewing key authentication"),
+    }
+}
+
+fn bridge_stats(deps: Deps) -> StdResult<BridgeQueryAnswer> {
+    Ok(BridgeQueryAnswer::BridgeStats {
+        locks: LOCKS_COUNT.may_load(deps.storage)?.unwrap_or_default(),
+        claims: CLAIMS_COUNT.may_load(deps.storage)?.unwrap_or_default(),
+    })
+}
+
+fn user_locks(deps: Deps, user: &Addr, page: u32, page_size: u32) -> StdResult<BridgeQueryAnswer> {
+    let locks = USER_LOCKS.add_suffix(user.as_bytes());
+    Ok(BridgeQueryAnswer::Locks {
+        locks: locks.paging(deps.storage, page, page_size)?,
+        total: locks.get_len(deps.storage)?,
+    })
+}
+
+fn user_claims(
+    deps: Deps,
+    user: &Addr,
+    page: u32,
+    page_size: u32,
+) -> StdResult<BridgeQueryAnswer> {
+    let claims = USER_CLAIMS.add_suffix(user.as_bytes());
+    Ok(BridgeQueryAnswer::Claims {
+        claims: claims.paging(deps.storage, page, page_size)?,
+        total: claims.get_len(deps.storage)?,
+    })
+}
+
+fn user_destinations(
+    deps: Deps,
+    user: &Addr,
+    page: u32,
+    page_size: u32,
+) -> StdResult<BridgeQueryAnswer> {
+    let destinations = USER_DESTINATIONS.add_suffix(user.as_bytes());
+    Ok(BridgeQueryAnswer::Destinations {
+        destinations: destinations
+            .paging(deps.storage, page, page_size)?
+            .into_iter()
+            .map(|((chain, address), locks)| Destination {
+                chain,
+                address,
+                locks,
+            })
+            .collect(),
+        total: destinations.get_len(deps.storage)?,
+    })
+}
+
 fn validators_count(deps: Deps) -> StdResult<BridgeQueryAnswer> {
     let state = config_read(deps.storage).load()?;
     Ok(BridgeQueryAnswer::ValidatorCountResponse {
@@ -1921,6 +2637,13 @@ fn duplicate_to_original(
     })
 }
 
+fn bridge_public_key(deps: Deps) -> StdResult<BridgeQueryAnswer> {
+    let bridge_key = BRIDGE_KEY.load(deps.storage)?;
+    Ok(BridgeQueryAnswer::BridgePublicKey {
+        data: encryption::public_key(&bridge_key)?,
+    })
+}
+
 // Replies
 #[entry_point]
 pub fn reply(_deps: DepsMut, _env: Env, msg: Reply) -> Result<Response, ContractError> {
@@ -2136,6 +2859,8 @@ fn register_collection_721_impl(
     reply_info: ReplyCollection721Info,
 ) -> Result<Response, ContractError> {
     let self_chain = config(deps.storage).load()?.self_chain;
+    let private_metadata = PENDING_PRIVATE_METADATA_721.may_load(deps.storage)?;
+    PENDING_PRIVATE_METADATA_721.remove(deps.storage);
 
     let _ = ORIGINAL_TO_DUPLICATE_STORAGE.insert(
         deps.storage,
@@ -2167,7 +2892,7 @@ fn register_collection_721_impl(
             token_uri: Some(reply_info.metadata),
             extension: Option::None,
         }),
-        private_metadata: Option::None,
+        private_metadata: private_metadata,
         serial_number: Option::None,
         royalty_info: Some(RoyaltyInfo {
             decimal_places_in_rates: 4,
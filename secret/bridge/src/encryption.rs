@@ -0,0 +1,164 @@
+//! Private metadata crosses the bridge encrypted to the receiving bridge's key.
+//!
+//! The sender derives a shared secret from a one-time secp256k1 key and the
+//! recipient public key (ECDH), hashes it with both public keys into a
+//! ChaCha20-Poly1305 key and seals the plaintext with it. Every payload uses a
+//! fresh one-time key, so no key seals twice and the nonce can stay fixed. A
+//! payload encrypted to another key, or changed on the way, fails to open.
+
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use cosmwasm_std::{Binary, StdError, StdResult};
+use schemars::JsonSchema;
+use secret_toolkit::crypto::secp256k1::{PrivateKey, PublicKey};
+use secret_toolkit::crypto::sha_256;
+use serde::{Deserialize, Serialize};
+
+/// Separates the payload key from any other use of the shared secret
+const PAYLOAD_KEY_DOMAIN: &[u8] = b"xp-bridge:private-metadata";
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct EncryptedPayload {
+    /// compressed public key of the one-time key of the sender
+    pub ephemeral_public_key: Binary,
+    /// ChaCha20-Poly1305 ciphertext, the tag appended
+    pub ciphertext: Binary,
+}
+
+/// Derives a valid secp256k1 secret key from `seed`
+pub fn derive_secret_key(seed: &[&[u8]]) -> StdResult<[u8; 32]> {
+    let key = sha_256(&seed.concat());
+    PrivateKey::parse(&key)
+        .map_err(|_| StdError::generic_err("Derived key is not a valid secp256k1 key"))?;
+    Ok(key)
+}
+
+/// Compressed public key of `secret`
+pub fn public_key(secret: &[u8; 32]) -> StdResult<Binary> {
+    Ok(Binary::from(
+        PrivateKey::parse(secret)
+            .map_err(|_| StdError::generic_err("Invalid secret key"))?
+            .pubkey()
+            .serialize_compressed()
+            .to_vec(),
+    ))
+}
+
+/// Checks that `key` is a valid compressed or uncompressed public key
+pub fn check_public_key(key: &[u8]) -> StdResult<()> {
+    PublicKey::parse(key)
+        .map(|_| ())
+        .map_err(|_| StdError::generic_err("Invalid public key"))
+}
+
+/// Encrypts `plaintext` to `recipient` with the one-time key `ephemeral_secret`
+pub fn encrypt(
+    recipient: &[u8],
+    ephemeral_secret: &[u8; 32],
+    plaintext: &[u8],
+) -> StdResult<EncryptedPayload> {
+    let ephemeral_public_key = public_key(ephemeral_secret)?;
+    let recipient_public_key = normalize_public_key(recipient)?;
+    let cipher = payload_cipher(
+        shared_secret(recipient, ephemeral_secret)?,
+        &ephemeral_public_key,
+        &recipient_public_key,
+    );
+    let ciphertext = cipher
+        .encrypt(&Nonce::default(), plaintext)
+        .map_err(|_| StdError::generic_err("Encryption failed"))?;
+    Ok(EncryptedPayload {
+        ephemeral_public_key,
+        ciphertext: Binary::from(ciphertext),
+    })
+}
+
+/// Decrypts a payload that was encrypted to the public key of `secret`
+pub fn decrypt(secret: &[u8; 32], payload: &EncryptedPayload) -> StdResult<Vec<u8>> {
+    let ephemeral_public_key = normalize_public_key(&payload.ephemeral_public_key)?;
+    let cipher = payload_cipher(
+        shared_secret(&payload.ephemeral_public_key, secret)?,
+        &ephemeral_public_key,
+        &public_key(secret)?,
+    );
+    cipher
+        .decrypt(&Nonce::default(), payload.ciphertext.as_slice())
+        .map_err(|_| StdError::generic_err("Private metadata does not decrypt"))
+}
+
+/// ECDH, which secret-toolkit-crypto doesn't expose, over the secp256k1 it links
+fn shared_secret(public: &[u8], secret: &[u8; 32]) -> StdResult<[u8; 32]> {
+    let public = secp256k1::PublicKey::from_slice(public)
+        .map_err(|_| StdError::generic_err("Invalid public key"))?;
+    let secret = secp256k1::SecretKey::from_slice(secret)
+        .map_err(|_| StdError::generic_err("Invalid secret key"))?;
+    Ok(secp256k1::ecdh::SharedSecret::new(&public, &secret).secret_bytes())
+}
+
+/// Binds the key to both public keys, so it only opens the payload it sealed
+fn payload_cipher(
+    shared_secret: [u8; 32],
+    ephemeral_public_key: &[u8],
+    recipient_public_key: &[u8],
+) -> ChaCha20Poly1305 {
+    let key = sha_256(
+        &[
+            PAYLOAD_KEY_DOMAIN,
+            &shared_secret,
+            ephemeral_public_key,
+            recipient_public_key,
+        ]
+        .concat(),
+    );
+    ChaCha20Poly1305::new(Key::from_slice(&key))
+}
+
+fn normalize_public_key(key: &[u8]) -> StdResult<Binary> {
+    let key = PublicKey::parse(key).map_err(|_| StdError::generic_err("Invalid public key"))?;
+    Ok(Binary::from(key.serialize_compressed().to_vec()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(seed: &[u8]) -> ([u8; 32], Binary) {
+        let secret = derive_secret_key(&[seed]).unwrap();
+        let public = public_key(&secret).unwrap();
+        (secret, public)
+    }
+
+    #[test]
+    fn roundtrip() {
+        let (secret, public) = keys(b"bridge");
+        let (ephemeral, _) = keys(b"ephemeral");
+        let payload = encrypt(&public, &ephemeral, b"private metadata").unwrap();
+        assert_ne!(payload.ciphertext.as_slice(), b"private metadata");
+        assert_eq!(decrypt(&secret, &payload).unwrap(), b"private metadata");
+    }
+
+    #[test]
+    fn wrong_key_fails() {
+        let (_, public) = keys(b"bridge");
+        let (other_secret, _) = keys(b"other bridge");
+        let (ephemeral, _) = keys(b"ephemeral");
+        let payload = encrypt(&public, &ephemeral, b"private metadata").unwrap();
+        assert!(decrypt(&other_secret, &payload).is_err());
+    }
+
+    #[test]
+    fn tampered_ciphertext_fails() {
+        let (secret, public) = keys(b"bridge");
+        let (ephemeral, _) = keys(b"ephemeral");
+        let mut payload = encrypt(&public, &ephemeral, b"private metadata").unwrap();
+        let mut ciphertext = payload.ciphertext.to_vec();
+        ciphertext[0] ^= 1;
+        payload.ciphertext = Binary::from(ciphertext);
+        assert!(decrypt(&secret, &payload).is_err());
+
+        // swapping the one-time key breaks it as well
+        let mut payload = encrypt(&public, &ephemeral, b"private metadata").unwrap();
+        payload.ephemeral_public_key = keys(b"another ephemeral").1;
+        assert!(decrypt(&secret, &payload).is_err());
+    }
+}
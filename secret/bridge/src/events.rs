@@ -1,10 +1,12 @@
 //! Events emitted by the Bridge Contract
 use std::convert::TryInto;
 
-use cosmwasm_std::{Addr, Attribute, StdError, StdResult};
+use cosmwasm_std::{Addr, Attribute, Binary, StdError, StdResult};
 
 use serde::Serialize;
 
+use crate::encryption::EncryptedPayload;
+
 /// Converts an event to [LogAttribute]
 fn to_log_attr<T: Serialize>(name: &str, e: &T) -> StdResult<Attribute> {
     let ser =
@@ -60,7 +62,10 @@ pub struct LockedEventInfo {
     pub token_amount: u128,
     pub nft_type: String,
     pub source_chain: String,
-    pub metadata_uri: String
+    pub metadata_uri: String,
+    /// private metadata encrypted to the destination bridge
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_metadata: Option<EncryptedPayload>,
 }
 bridge_event!(LockedEventInfo);
 
@@ -73,7 +78,8 @@ impl LockedEventInfo {
         token_amount: u128,
         nft_type: String,
         source_chain: String,
-        metadata_uri: String
+        metadata_uri: String,
+        private_metadata: Option<EncryptedPayload>,
     ) -> Self {
         Self {
             token_id,
@@ -83,11 +89,38 @@ impl LockedEventInfo {
             token_amount,
             nft_type,
             source_chain,
-            metadata_uri
+            metadata_uri,
+            private_metadata,
         }
     }
 }
 
+/// Public part of a private lock. The lock itself is only readable by the
+/// validators, who check it against `commitment`.
+#[derive(Debug, Serialize)]
+pub struct PrivateLockedEventInfo {
+    pub nonce: u64,
+    /// sha256 of the plaintext JSON of the [PrivateLockPayload], which each
+    /// validator recomputes after decrypting its copy
+    pub commitment: Binary,
+}
+bridge_event!(PrivateLockedEventInfo);
+
+impl PrivateLockedEventInfo {
+    pub fn new(nonce: u64, commitment: Binary) -> Self {
+        Self { nonce, commitment }
+    }
+}
+
+/// Plaintext of a private lock. `salt` keeps the commitment from being
+/// brute forced from guessable lock fields.
+#[derive(Debug, Serialize)]
+pub struct PrivateLockPayload {
+    pub nonce: u64,
+    pub salt: Binary,
+    pub lock: LockedEventInfo,
+}
+
 #[derive(Debug, Serialize)]
 pub struct UnLock721EventInfo {
     pub to: Addr,
@@ -1,7 +1,11 @@
 
 pub mod contract;
+pub mod encryption;
 pub mod error;
 pub mod msg;
 pub mod state;
 pub mod structs;
-pub mod events;
\ No newline at end of file
+pub mod events;
+#[cfg(test)]
+#[allow(clippy::module_inception)]
+mod tests;
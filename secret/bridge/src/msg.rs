@@ -1,9 +1,13 @@
 use crate::structs::{
-    AddValidatorMsg, ClaimMsg, ClaimValidatorRewardsMsg, DuplicateToOriginalContractInfo, Lock1155Msg, Lock721Msg, OriginalToDuplicateContractInfo, SignerAndSignature, Validator, VerifyMsg
+    AddValidatorMsg, ClaimMsg, ClaimRecord, ClaimValidatorRewardsMsg, Destination,
+    DuplicateToOriginalContractInfo, Lock1155Msg, Lock721Msg, LockRecord,
+    OriginalToDuplicateContractInfo, SetValidatorEncryptionKeyMsg, SignerAndSignature, Validator,
+    ValidatorLockPayload, VerifyMsg,
 };
 
-use cosmwasm_std::{Addr, Binary, SubMsg};
+use cosmwasm_std::{Addr, Binary, SubMsg, Uint256};
 use schemars::JsonSchema;
+use secret_toolkit::permit::Permit;
 use serde::{Deserialize, Serialize};
 
 /// Executes
@@ -18,6 +22,61 @@ pub enum BridgeExecuteMsg {
     Claim721 { data: ClaimMsg },
     Claim1155 { data: ClaimMsg },
     VerifySig { data: VerifyMsg },
+    SetValidatorEncryptionKey { data: SetValidatorEncryptionKeyMsg },
+    /// registers the bridge as the receiver of `collection`, so tokens can be
+    /// locked by sending them to the bridge with a [ReceiveLockMsg](crate::structs::ReceiveLockMsg),
+    /// and sets the bridge viewing key of `collection` on it to read their
+    /// private metadata.
+    /// 721 collections call back once per token, batch sends included.
+    RegisterReceiver {
+        collection: Addr,
+        code_hash: String,
+        nft_type: String,
+    },
+    /// snip721 receive callbacks
+    ReceiveNft {
+        sender: Addr,
+        token_id: String,
+        msg: Option<Binary>,
+    },
+    /// only sent by collections registered with batch support, which can lock
+    /// one token per callback
+    BatchReceiveNft {
+        sender: Addr,
+        from: Addr,
+        token_ids: Vec<String>,
+        msg: Option<Binary>,
+    },
+    /// snip1155 receive callback
+    Snip1155Receive {
+        sender: Addr,
+        token_id: String,
+        from: Addr,
+        amount: Uint256,
+        memo: Option<String>,
+        msg: Option<Binary>,
+    },
+    /// creates a viewing key for the private bridge queries of the sender
+    CreateViewingKey { entropy: String },
+    SetViewingKey { key: String },
+    /// disallows the use of a query permit
+    RevokePermit { permit_name: String },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseStatus {
+    Success,
+    Failure,
+}
+
+/// Data set on the responses of the viewing key and permit executes
+#[derive(Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BridgeExecuteAnswer {
+    CreateViewingKey { key: String },
+    SetViewingKey { status: ResponseStatus },
+    RevokePermit { status: ResponseStatus },
 }
 
 #[derive(Serialize, Deserialize, JsonSchema)]
@@ -26,8 +85,10 @@ pub struct BlacklistValidatorMsg {
     pub signatures: Vec<SignerAndSignature>,
 }
 
-/// Queries
-#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+/// Queries. The ones about a user are authenticated with a viewing key or a
+/// permit. Of the public ones, only `GetBridgeStats` came with them, and it
+/// only exposes aggregate counts.
+#[derive(Serialize, Deserialize, Clone, Debug, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum BridgeQueryMsg {
     // GetCount returns the current count as a json-encoded number
@@ -53,6 +114,52 @@ pub enum BridgeQueryMsg {
         contract_address: Addr,
         chain: String,
     },
+    /// key private metadata is encrypted to
+    GetBridgePublicKey {},
+    /// number of locks and claims processed by the bridge
+    GetBridgeStats {},
+    /// key `validator` receives the private locks with
+    GetValidatorEncryptionKey {
+        validator: Binary,
+    },
+    /// private lock with `nonce`, encrypted to each validator
+    GetPrivateLock {
+        nonce: u64,
+    },
+    /// locks made by `address`
+    GetMyLocks {
+        address: Addr,
+        key: String,
+        page: Option<u32>,
+        page_size: u32,
+    },
+    /// claims received by `address`
+    GetMyClaims {
+        address: Addr,
+        key: String,
+        page: Option<u32>,
+        page_size: u32,
+    },
+    /// addresses `address` has locked tokens to
+    GetMyDestinations {
+        address: Addr,
+        key: String,
+        page: Option<u32>,
+        page_size: u32,
+    },
+    WithPermit {
+        permit: Permit,
+        query: QueryWithPermit,
+    },
+}
+
+/// Private queries about the permit signer
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum QueryWithPermit {
+    MyLocks { page: Option<u32>, page_size: u32 },
+    MyClaims { page: Option<u32>, page_size: u32 },
+    MyDestinations { page: Option<u32>, page_size: u32 },
 }
 
 /// responses to queries
@@ -80,6 +187,37 @@ pub enum BridgeQueryAnswer {
     DuplicateToOriginal {
         data: Option<DuplicateToOriginalContractInfo>,
     },
+    BridgePublicKey {
+        data: Binary,
+    },
+    BridgeStats {
+        locks: u64,
+        claims: u64,
+    },
+    ValidatorEncryptionKey {
+        data: Option<Binary>,
+        /// nonce of the last registration, the next one signs `nonce + 1`
+        nonce: u64,
+    },
+    PrivateLock {
+        data: Option<Vec<ValidatorLockPayload>>,
+    },
+    Locks {
+        locks: Vec<LockRecord>,
+        total: u32,
+    },
+    Claims {
+        claims: Vec<ClaimRecord>,
+        total: u32,
+    },
+    Destinations {
+        destinations: Vec<Destination>,
+        total: u32,
+    },
+    /// returned when the viewing key of an authenticated query is wrong
+    ViewingKeyError {
+        msg: String,
+    },
     // List the offspring where address is associated.
     // ListMyOffspring {
     //     /// lists of the address' active offspring
@@ -99,17 +237,6 @@ pub enum BridgeQueryAnswer {
     //     /// inactive offspring in no particular order
     //     inactive: Vec<StoreOffspringInfo>,
     // },
-    // /// Viewing Key Error
-    // ViewingKeyError { error: String },
-    // /// result of authenticating address/key pair
-    // IsKeyValid { is_valid: bool },
-    // /// result of authenticating a permit
-    // IsPermitValid {
-    //     is_valid: bool,
-    //     /// address of the permit signer if the permit was valid
-    //     #[serde(skip_serializing_if = "Option::is_none")]
-    //     address: Option<Addr>,
-    // },
 }
 
 // We define a custom struct for each query response
@@ -2,18 +2,22 @@
 use common::CodeInfo;
 use secret_toolkit::{
     serialization::Bincode2,
-    storage::{Item, Keymap, KeymapBuilder, WithoutIter},
+    storage::{AppendStore, Item, Keymap, KeymapBuilder, WithoutIter},
 };
 
 use cosmwasm_std::{Addr, Binary, Storage};
 use cosmwasm_storage::{singleton, singleton_read, ReadonlySingleton, Singleton};
+use snip721::token::Metadata;
 
 use crate::structs::{
-     DuplicateToOriginalContractInfo, OriginalToDuplicateContractInfo, State, Validator,
+    ClaimRecord, DuplicateToOriginalContractInfo, LockRecord, OriginalToDuplicateContractInfo,
+    State, Validator, ValidatorLockPayload,
 };
 
 pub static CONFIG_KEY: &[u8] = b"config";
 
+pub const PREFIX_REVOKED_PERMITS: &str = "revokedperms";
+
 pub const BLOCK_SIZE: usize = 256;
 
 pub const STORAGE_DEPLOYER_721_REPLY_ID: u64 = 1;
@@ -70,6 +74,39 @@ pub const COLLETION_DEPLOYER_CODE: Item<CodeInfo> = Item::new(b"cd_v");
 
 pub const STORAGE_DEPLOYER_CODE: Item<CodeInfo> = Item::new(b"sd_v");
 
+/// secp256k1 secret key private metadata is encrypted to
+pub const BRIDGE_KEY: Item<[u8; 32]> = Item::new(b"bk");
+
+/// locks by user, suffixed with the locking address
+pub static USER_LOCKS: AppendStore<LockRecord> = AppendStore::new(b"ul");
+
+/// claims by user, suffixed with the receiving address
+pub static USER_CLAIMS: AppendStore<ClaimRecord> = AppendStore::new(b"uc");
+
+/// (destination chain, destination address) => locks, suffixed with the locking address
+pub static USER_DESTINATIONS: Keymap<(String, String), u32> = KeymapBuilder::new(b"ud").build();
+
+pub const LOCKS_COUNT: Item<u64> = Item::new(b"lc");
+
+pub const CLAIMS_COUNT: Item<u64> = Item::new(b"clc");
+
+/// validator public key => key the private locks are encrypted to
+pub static VALIDATOR_ENCRYPTION_KEYS: Keymap<Binary, Binary> = KeymapBuilder::new(b"vek").build();
+
+/// validator public key => nonce of its last encryption key registration
+pub static VALIDATOR_ENCRYPTION_KEY_NONCES: Keymap<Binary, u64, Bincode2, WithoutIter> =
+    KeymapBuilder::new(b"vekn").without_iter().build();
+
+/// nonce of the last private lock
+pub const LOCK_NONCE: Item<u64> = Item::new(b"ln");
+
+/// lock nonce => lock encrypted to each validator
+pub static PRIVATE_LOCKS: Keymap<u64, Vec<ValidatorLockPayload>, Bincode2, WithoutIter> =
+    KeymapBuilder::new(b"pl").without_iter().build();
+
+/// private metadata of a 721 claim waiting for its collection to be deployed
+pub const PENDING_PRIVATE_METADATA_721: Item<Metadata> = Item::new(b"ppm");
+
 pub fn config(storage: &mut dyn Storage) -> Singleton<State> {
     singleton(storage, CONFIG_KEY)
 }
@@ -4,8 +4,11 @@
 use common::CodeInfo;
 use cosmwasm_std::{Addr, Binary};
 use schemars::{JsonSchema, Map};
+use secret_toolkit::permit::Permit;
 use serde::{Deserialize, Serialize};
 
+use crate::encryption::EncryptedPayload;
+
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, JsonSchema, Serialize)]
 pub struct Validator {
     pub address: Addr,
@@ -64,6 +67,18 @@ pub struct AddValidatorMsg {
     pub signatures: Vec<SignerAndSignature>,
 }
 
+/// Registers the key the private locks are encrypted to for `validator`.
+/// `signature` is the signature of the validator over sha256 of the bridge
+/// address, `validator`, `encryption_key` and `nonce`, each length prefixed.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct SetValidatorEncryptionKeyMsg {
+    pub validator: Binary,
+    pub encryption_key: Binary,
+    /// one more than the last nonce `validator` registered a key with
+    pub nonce: u64,
+    pub signature: Binary,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 pub struct ClaimValidatorRewardsMsg {
     pub validator: Binary,
@@ -77,7 +92,39 @@ pub struct Lock721Msg {
     pub source_nft_contract_address: Addr,
     pub collection_code_info: CodeInfo,
     pub token_id: String,
-    pub metadata_uri: String
+    pub metadata_uri: String,
+    #[serde(default)]
+    pub private_metadata: Option<PrivateMetadataLock>,
+    /// emits the lock encrypted to the validators instead of in plaintext
+    #[serde(default)]
+    pub private_lock: bool,
+}
+
+/// Lock carried by the `msg` of a `SendNft` or 1155 `Send` to the bridge. The
+/// collection, token and amount are those of the receive callback.
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct ReceiveLockMsg {
+    pub destination_chain: String,
+    pub destination_user_address: String,
+    pub collection_code_info: CodeInfo,
+    pub metadata_uri: String,
+    #[serde(default)]
+    pub private_metadata: Option<PrivateMetadataLock>,
+    #[serde(default)]
+    pub private_lock: bool,
+}
+
+/// Bundles the private metadata of a locked token for the destination. The
+/// bridge reads it with the owner's `permit`, which `Lock721` and `Lock1155`
+/// require. A token sent to the bridge is read with the viewing key
+/// `RegisterReceiver` set on its collection, so the permit is ignored there.
+#[derive(Serialize, Deserialize, JsonSchema)]
+pub struct PrivateMetadataLock {
+    /// public key of the destination bridge the metadata is encrypted to
+    pub recipient_key: Binary,
+    /// owner permit allowing to query the private metadata of the token
+    #[serde(default)]
+    pub permit: Option<Permit>,
 }
 
 #[derive(Serialize, Deserialize, JsonSchema)]
@@ -88,7 +135,12 @@ pub struct Lock1155Msg {
     pub collection_code_info: CodeInfo,
     pub token_id: String,
     pub token_amount: u128,
-    pub metadata_uri: String
+    pub metadata_uri: String,
+    #[serde(default)]
+    pub private_metadata: Option<PrivateMetadataLock>,
+    /// emits the lock encrypted to the validators instead of in plaintext
+    #[serde(default)]
+    pub private_lock: bool,
 }
 
 #[derive(Serialize, Deserialize, JsonSchema)]
@@ -107,6 +159,48 @@ pub struct VerifyMsg {
     pub claim_data_as_binary: ClaimData,
 }
 
+/// A lock, as listed to the user that made it. It only records the lock: the
+/// bridge doesn't learn whether the claim was made on the destination chain.
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct LockRecord {
+    pub token_id: String,
+    pub source_nft_contract_address: Addr,
+    pub token_amount: u128,
+    pub nft_type: String,
+    pub destination_chain: String,
+    pub destination_user_address: String,
+    pub timestamp: u64,
+    /// nonce of the lock if it was private
+    pub lock_nonce: Option<u64>,
+}
+
+/// A private lock encrypted to one validator
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct ValidatorLockPayload {
+    pub validator: Binary,
+    pub payload: EncryptedPayload,
+}
+
+/// A claim, as listed to the user that received the tokens
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct ClaimRecord {
+    pub token_id: String,
+    pub source_chain: String,
+    pub source_nft_contract_address: String,
+    pub token_amount: u128,
+    pub nft_type: String,
+    pub transaction_hash: String,
+    pub timestamp: u64,
+}
+
+/// An address a user has locked tokens to, with the number of locks
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
+pub struct Destination {
+    pub chain: String,
+    pub address: String,
+    pub locks: u32,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq, JsonSchema)]
 pub struct TransferToStorage721Msg {
     pub storage_mapping721: Map<String, Map<String, Addr>>,
@@ -130,12 +224,17 @@ pub struct ClaimData {
     pub token_amount: u128,
     pub nft_type: String,
     pub fee: u128,
-    pub lock_tx_chain: String
+    pub lock_tx_chain: String,
+    /// private metadata encrypted to the bridge key
+    #[serde(default)]
+    pub private_metadata: Option<EncryptedPayload>,
 }
 
 impl ClaimData {
+    /// The encrypted private metadata is only appended when present, so claims
+    /// without it hash as before
     pub fn concat_all_fields(&self) -> String {
-        format!(
+        let fields = format!(
             "{}{}{}{}{}{}{}{}{}{}{}{}{}{}{}",
             self.token_id,
             self.source_chain,
@@ -152,6 +251,13 @@ impl ClaimData {
             self.nft_type,
             self.fee,
             self.lock_tx_chain
-        )
+        );
+        match &self.private_metadata {
+            Some(payload) => format!(
+                "{}{}{}",
+                fields, payload.ephemeral_public_key, payload.ciphertext
+            ),
+            None => fields,
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,891 @@
+#[cfg(test)]
+mod tests {
+    use bech32::{ToBase32, Variant};
+    use common::CodeInfo;
+    use cosmwasm_std::testing::{
+        mock_dependencies, mock_env, mock_info, MockApi, MockQuerier, MockStorage,
+    };
+    use cosmwasm_std::{
+        coins, from_binary, to_binary, Addr, Binary, CosmosMsg, Env, OwnedDeps, Response,
+        StdResult, Uint256, WasmMsg,
+    };
+    use secret_toolkit::permit::{
+        pubkey_to_account, Permit, PermitParams, PermitSignature, PubKey, SignedPermit,
+        TokenPermissions,
+    };
+    use sha2::{Digest, Sha256};
+
+    use crate::contract::{execute, instantiate, query};
+    use crate::encryption::{self, derive_secret_key};
+    use crate::msg::{BridgeExecuteMsg, BridgeQueryAnswer, BridgeQueryMsg, QueryWithPermit};
+    use crate::state::{config, BLACKLISTED_VALIDATORS, VALIDATORS_STORAGE};
+    use crate::structs::{
+        BridgeInstantiateMsg, ClaimData, ClaimMsg, ClaimRecord, Destination, Lock721Msg,
+        LockRecord, ReceiveLockMsg, SetValidatorEncryptionKeyMsg, SignerAndSignature,
+        ValidatorLockPayload,
+    };
+
+    type MockDeps = OwnedDeps<MockStorage, MockApi, MockQuerier>;
+
+    fn env() -> Env {
+        let mut env = mock_env();
+        env.block.random = Some(Binary::from([7u8; 32].to_vec()));
+        env
+    }
+
+    fn code_info(code_id: u64) -> CodeInfo {
+        CodeInfo {
+            code_id,
+            code_hash: format!("code_hash_{}", code_id),
+        }
+    }
+
+    fn instantiate_bridge(validators: Vec<(Binary, Addr)>) -> MockDeps {
+        let mut deps = mock_dependencies();
+        instantiate(
+            deps.as_mut(),
+            env(),
+            mock_info("deployer", &[]),
+            BridgeInstantiateMsg {
+                validators,
+                chain_type: "SECRET".to_string(),
+                storage_label: "storage".to_string(),
+                collection_label: "collection".to_string(),
+                collection721_code_info: code_info(1),
+                storage721_code_info: code_info(2),
+                collection1155_code_info: code_info(3),
+                storage1155_code_info: code_info(4),
+                collection_deployer_code_info: code_info(5),
+                storage_deployer_code_info: code_info(6),
+            },
+        )
+        .unwrap();
+        deps
+    }
+
+    fn execute_as(deps: &mut MockDeps, sender: &str, msg: BridgeExecuteMsg) -> StdResult<Response> {
+        execute(deps.as_mut(), env(), mock_info(sender, &[]), msg)
+    }
+
+    fn lock_msg() -> Binary {
+        to_binary(&ReceiveLockMsg {
+            destination_chain: "BSC".to_string(),
+            destination_user_address: "0xdestination".to_string(),
+            collection_code_info: code_info(10),
+            metadata_uri: "uri".to_string(),
+            private_metadata: None,
+            private_lock: false,
+        })
+        .unwrap()
+    }
+
+    fn set_key(deps: &mut MockDeps, user: &str, key: &str) {
+        execute_as(
+            deps,
+            user,
+            BridgeExecuteMsg::SetViewingKey {
+                key: key.to_string(),
+            },
+        )
+        .unwrap();
+    }
+
+    fn query_answer(deps: &MockDeps, msg: BridgeQueryMsg) -> StdResult<BridgeQueryAnswer> {
+        query(deps.as_ref(), env(), msg).and_then(|answer| from_binary(&answer))
+    }
+
+    /// Locks made by `user`, read with a viewing key it sets
+    fn my_locks(deps: &mut MockDeps, user: &str) -> Vec<LockRecord> {
+        set_key(deps, user, "key");
+        let answer = query_answer(
+            deps,
+            BridgeQueryMsg::GetMyLocks {
+                address: Addr::unchecked(user),
+                key: "key".to_string(),
+                page: None,
+                page_size: 10,
+            },
+        )
+        .unwrap();
+        match answer {
+            BridgeQueryAnswer::Locks { locks, .. } => locks,
+            _ => panic!("unexpected answer"),
+        }
+    }
+
+    /// Viewing key of the `SetViewingKey` message `RegisterReceiver` sends
+    fn registered_viewing_key(deps: &mut MockDeps, collection: &str, nft_type: &str) -> String {
+        let res = execute_as(
+            deps,
+            "anyone",
+            BridgeExecuteMsg::RegisterReceiver {
+                collection: Addr::unchecked(collection),
+                code_hash: "collection_hash".to_string(),
+                nft_type: nft_type.to_string(),
+            },
+        )
+        .unwrap();
+        assert_eq!(res.messages.len(), 2);
+        match &res.messages[1].msg {
+            CosmosMsg::Wasm(WasmMsg::Execute { msg, .. }) => {
+                let msg: serde_json::Value = serde_json::from_slice(msg.as_slice()).unwrap();
+                msg["set_viewing_key"]["key"].as_str().unwrap().to_string()
+            }
+            _ => panic!("unexpected message"),
+        }
+    }
+
+    #[test]
+    fn register_receiver_sets_a_viewing_key_per_collection() {
+        let mut deps = instantiate_bridge(vec![]);
+        let key = registered_viewing_key(&mut deps, "collection", "singular");
+        assert_eq!(
+            registered_viewing_key(&mut deps, "collection", "multiple"),
+            key
+        );
+        assert_ne!(registered_viewing_key(&mut deps, "other", "singular"), key);
+
+        let err = execute_as(
+            &mut deps,
+            "anyone",
+            BridgeExecuteMsg::RegisterReceiver {
+                collection: Addr::unchecked("collection"),
+                code_hash: "collection_hash".to_string(),
+                nft_type: "unknown".to_string(),
+            },
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Invalid NFT type"), "{}", err);
+    }
+
+    #[test]
+    fn receive_nft_locks_for_the_previous_owner() {
+        let mut deps = instantiate_bridge(vec![]);
+        let res = execute_as(
+            &mut deps,
+            "collection",
+            BridgeExecuteMsg::ReceiveNft {
+                sender: Addr::unchecked("owner"),
+                token_id: "1".to_string(),
+                msg: Some(lock_msg()),
+            },
+        )
+        .unwrap();
+        // the first lock of the collection deploys its storage
+        assert_eq!(res.messages.len(), 1);
+
+        let locks = my_locks(&mut deps, "owner");
+        assert_eq!(locks.len(), 1);
+        assert_eq!(locks[0].token_id, "1");
+        assert_eq!(locks[0].token_amount, 1);
+        assert_eq!(
+            locks[0].source_nft_contract_address,
+            Addr::unchecked("collection")
+        );
+        assert_eq!(locks[0].destination_chain, "BSC");
+        assert!(my_locks(&mut deps, "collection").is_empty());
+    }
+
+    #[test]
+    fn receive_nft_rejects_batches_and_missing_msg() {
+        let mut deps = instantiate_bridge(vec![]);
+        let err = execute_as(
+            &mut deps,
+            "collection",
+            BridgeExecuteMsg::BatchReceiveNft {
+                sender: Addr::unchecked("owner"),
+                from: Addr::unchecked("owner"),
+                token_ids: vec!["1".to_string(), "2".to_string()],
+                msg: Some(lock_msg()),
+            },
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string().contains("Send one token per lock"),
+            "{}",
+            err
+        );
+
+        let err = execute_as(
+            &mut deps,
+            "collection",
+            BridgeExecuteMsg::ReceiveNft {
+                sender: Addr::unchecked("owner"),
+                token_id: "1".to_string(),
+                msg: None,
+            },
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("Missing lock message"), "{}", err);
+
+        assert!(my_locks(&mut deps, "owner").is_empty());
+    }
+
+    #[test]
+    fn receive_1155_locks_for_the_previous_owner() {
+        let mut deps = instantiate_bridge(vec![]);
+        let receive = |amount: Uint256, msg: Option<Binary>| BridgeExecuteMsg::Snip1155Receive {
+            sender: Addr::unchecked("operator"),
+            token_id: "1".to_string(),
+            from: Addr::unchecked("holder"),
+            amount,
+            memo: None,
+            msg,
+        };
+
+        let err = execute_as(
+            &mut deps,
+            "collection",
+            receive(
+                Uint256::from(u128::MAX) + Uint256::from(1u8),
+                Some(lock_msg()),
+            ),
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string().contains("token amount overflows u128"),
+            "{}",
+            err
+        );
+
+        let err =
+            execute_as(&mut deps, "collection", receive(Uint256::from(5u8), None)).unwrap_err();
+        assert!(err.to_string().contains("Missing lock message"), "{}", err);
+
+        execute_as(
+            &mut deps,
+            "collection",
+            receive(Uint256::from(5u8), Some(lock_msg())),
+        )
+        .unwrap();
+        let locks = my_locks(&mut deps, "holder");
+        assert_eq!(locks.len(), 1);
+        assert_eq!(locks[0].token_amount, 5);
+        assert_eq!(
+            locks[0].source_nft_contract_address,
+            Addr::unchecked("collection")
+        );
+        assert!(my_locks(&mut deps, "collection").is_empty());
+        assert!(my_locks(&mut deps, "operator").is_empty());
+    }
+
+    struct TestValidator {
+        secret: secp256k1::SecretKey,
+        public_key: Binary,
+        encryption_secret: [u8; 32],
+        encryption_key: Binary,
+    }
+
+    fn validator(seed: u8) -> TestValidator {
+        let secret = secp256k1::SecretKey::from_slice(&[seed; 32]).unwrap();
+        let public_key =
+            secp256k1::PublicKey::from_secret_key(&secp256k1::Secp256k1::new(), &secret);
+        let encryption_secret = derive_secret_key(&[b"encryption".as_slice(), &[seed]]).unwrap();
+        TestValidator {
+            secret,
+            public_key: Binary::from(public_key.serialize().to_vec()),
+            encryption_secret,
+            encryption_key: encryption::public_key(&encryption_secret).unwrap(),
+        }
+    }
+
+    fn instantiate_with(validators: &[&TestValidator]) -> MockDeps {
+        instantiate_bridge(
+            validators
+                .iter()
+                .enumerate()
+                .map(|(i, v)| {
+                    (
+                        v.public_key.clone(),
+                        Addr::unchecked(format!("validator{}", i)),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    /// Signs the registration of `key` the way validators do: sha256 of the
+    /// length prefixed bridge address, validator and key, then the nonce
+    fn encryption_key_msg(
+        bridge: &str,
+        validator: &TestValidator,
+        key: &Binary,
+        nonce: u64,
+    ) -> BridgeExecuteMsg {
+        let mut hasher = Sha256::new();
+        for field in [
+            bridge.as_bytes(),
+            validator.public_key.as_slice(),
+            key.as_slice(),
+        ] {
+            hasher.update((field.len() as u64).to_be_bytes());
+            hasher.update(field);
+        }
+        hasher.update(nonce.to_be_bytes());
+        let hash = hasher.finalize();
+        let signature = secp256k1::Secp256k1::new()
+            .sign_ecdsa(
+                &secp256k1::Message::from_slice(hash.as_slice()).unwrap(),
+                &validator.secret,
+            )
+            .serialize_compact();
+        BridgeExecuteMsg::SetValidatorEncryptionKey {
+            data: SetValidatorEncryptionKeyMsg {
+                validator: validator.public_key.clone(),
+                encryption_key: key.clone(),
+                nonce,
+                signature: Binary::from(signature.to_vec()),
+            },
+        }
+    }
+
+    fn register_encryption_key(deps: &mut MockDeps, validator: &TestValidator, nonce: u64) {
+        let bridge = env().contract.address;
+        let msg = encryption_key_msg(bridge.as_str(), validator, &validator.encryption_key, nonce);
+        execute_as(deps, "anyone", msg).unwrap();
+    }
+
+    fn encryption_key(deps: &MockDeps, validator: &TestValidator) -> (Option<Binary>, u64) {
+        let answer = query(
+            deps.as_ref(),
+            env(),
+            BridgeQueryMsg::GetValidatorEncryptionKey {
+                validator: validator.public_key.clone(),
+            },
+        )
+        .unwrap();
+        match from_binary(&answer).unwrap() {
+            BridgeQueryAnswer::ValidatorEncryptionKey { data, nonce } => (data, nonce),
+            _ => panic!("unexpected answer"),
+        }
+    }
+
+    #[test]
+    fn set_validator_encryption_key_orders_nonces() {
+        let v = validator(1);
+        let mut deps = instantiate_with(&[&v]);
+        let bridge = env().contract.address.into_string();
+        let rotated = validator(2).encryption_key;
+
+        let err = execute_as(
+            &mut deps,
+            "anyone",
+            encryption_key_msg(&bridge, &v, &v.encryption_key, 2),
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Invalid nonce, expected 1, found 2"),
+            "{}",
+            err
+        );
+
+        let first = encryption_key_msg(&bridge, &v, &v.encryption_key, 1);
+        execute_as(&mut deps, "anyone", first).unwrap();
+        assert_eq!(
+            encryption_key(&deps, &v),
+            (Some(v.encryption_key.clone()), 1)
+        );
+
+        execute_as(
+            &mut deps,
+            "anyone",
+            encryption_key_msg(&bridge, &v, &rotated, 2),
+        )
+        .unwrap();
+        assert_eq!(encryption_key(&deps, &v), (Some(rotated), 2));
+
+        // replaying the first registration can't restore the older key
+        let replay = encryption_key_msg(&bridge, &v, &v.encryption_key, 1);
+        let err = execute_as(&mut deps, "anyone", replay).unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Invalid nonce, expected 3, found 1"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn set_validator_encryption_key_checks_the_signed_fields() {
+        let v = validator(1);
+        let mut deps = instantiate_with(&[&v]);
+        let bridge = env().contract.address.into_string();
+
+        // a registration signed for another bridge
+        let mut msg = encryption_key_msg("other_bridge", &v, &v.encryption_key, 1);
+        let err = execute_as(&mut deps, "anyone", msg).unwrap_err();
+        assert!(
+            err.to_string().contains("Invalid validator signature"),
+            "{}",
+            err
+        );
+
+        // a key swapped after signing
+        msg = encryption_key_msg(&bridge, &v, &v.encryption_key, 1);
+        if let BridgeExecuteMsg::SetValidatorEncryptionKey { data } = &mut msg {
+            data.encryption_key = validator(2).encryption_key;
+        }
+        let err = execute_as(&mut deps, "anyone", msg).unwrap_err();
+        assert!(
+            err.to_string().contains("Invalid validator signature"),
+            "{}",
+            err
+        );
+        assert_eq!(encryption_key(&deps, &v), (None, 0));
+
+        let outsider = validator(3);
+        let msg = encryption_key_msg(&bridge, &outsider, &outsider.encryption_key, 1);
+        let err = execute_as(&mut deps, "anyone", msg).unwrap_err();
+        assert!(
+            err.to_string().contains("Validator does not exist!"),
+            "{}",
+            err
+        );
+    }
+
+    fn private_lock721(deps: &mut MockDeps, token_id: &str) -> StdResult<Response> {
+        execute_as(
+            deps,
+            "owner",
+            BridgeExecuteMsg::Lock721 {
+                data: Lock721Msg {
+                    destination_chain: "BSC".to_string(),
+                    destination_user_address: "0xdestination".to_string(),
+                    source_nft_contract_address: Addr::unchecked("collection"),
+                    collection_code_info: code_info(10),
+                    token_id: token_id.to_string(),
+                    metadata_uri: "uri".to_string(),
+                    private_metadata: None,
+                    private_lock: true,
+                },
+            },
+        )
+    }
+
+    fn private_lock(deps: &MockDeps, nonce: u64) -> Option<Vec<ValidatorLockPayload>> {
+        let answer = query(
+            deps.as_ref(),
+            env(),
+            BridgeQueryMsg::GetPrivateLock { nonce },
+        )
+        .unwrap();
+        match from_binary(&answer).unwrap() {
+            BridgeQueryAnswer::PrivateLock { data } => data,
+            _ => panic!("unexpected answer"),
+        }
+    }
+
+    #[test]
+    fn private_lock_needs_a_threshold_of_encryption_keys() {
+        let (v1, v2) = (validator(1), validator(2));
+        let mut deps = instantiate_with(&[&v1, &v2]);
+        register_encryption_key(&mut deps, &v1, 1);
+
+        let err = private_lock721(&mut deps, "1").unwrap_err();
+        assert!(
+            err.to_string()
+                .contains("Not enough validators registered an encryption key"),
+            "{}",
+            err
+        );
+        assert_eq!(private_lock(&deps, 1), None);
+
+        register_encryption_key(&mut deps, &v2, 1);
+        let res = private_lock721(&mut deps, "2").unwrap();
+
+        // only the nonce and commitment are logged
+        assert!(res.attributes.iter().all(|a| a.key != "LockedEventInfo"));
+        let event = res
+            .attributes
+            .iter()
+            .find(|a| a.key == "PrivateLockedEventInfo")
+            .expect("no private lock event");
+        let event: serde_json::Value = serde_json::from_str(&event.value).unwrap();
+        let nonce = event["nonce"].as_u64().unwrap();
+        let commitment = Binary::from_base64(event["commitment"].as_str().unwrap()).unwrap();
+
+        let payloads = private_lock(&deps, nonce).expect("private lock not stored");
+        assert_eq!(payloads.len(), 2);
+        for v in [&v1, &v2] {
+            let payload = payloads
+                .iter()
+                .find(|p| p.validator == v.public_key)
+                .expect("no payload for validator");
+            let plaintext = encryption::decrypt(&v.encryption_secret, &payload.payload).unwrap();
+            assert_eq!(Sha256::digest(&plaintext).as_slice(), commitment.as_slice());
+
+            let lock: serde_json::Value = serde_json::from_slice(&plaintext).unwrap();
+            assert_eq!(lock["nonce"].as_u64(), Some(nonce));
+            assert_eq!(lock["lock"]["token_id"], "2");
+            assert_eq!(lock["lock"]["destination_chain"], "BSC");
+            assert_eq!(lock["lock"]["source_nft_contract_address"], "collection");
+        }
+        // each validator only opens its own copy
+        let v2_payload = payloads
+            .iter()
+            .find(|p| p.validator == v2.public_key)
+            .unwrap();
+        assert!(encryption::decrypt(&v1.encryption_secret, &v2_payload.payload).is_err());
+    }
+
+    #[test]
+    fn private_lock_skips_blacklisted_validators() {
+        let (v1, v2, v3) = (validator(1), validator(2), validator(3));
+        let mut deps = instantiate_with(&[&v1, &v2, &v3]);
+        for v in [&v1, &v2, &v3] {
+            register_encryption_key(&mut deps, v, 1);
+        }
+
+        // a validator blacklisted while its encryption key stayed registered
+        VALIDATORS_STORAGE
+            .remove(deps.as_mut().storage, &v3.public_key)
+            .unwrap();
+        BLACKLISTED_VALIDATORS
+            .insert(deps.as_mut().storage, &v3.public_key, &true)
+            .unwrap();
+        config(deps.as_mut().storage)
+            .update(|mut state| -> StdResult<_> {
+                state.validators_count -= 1;
+                Ok(state)
+            })
+            .unwrap();
+
+        private_lock721(&mut deps, "1").unwrap();
+        let payloads = private_lock(&deps, 1).expect("private lock not stored");
+        let mut validators: Vec<Binary> = payloads.into_iter().map(|p| p.validator).collect();
+        validators.sort();
+        let mut expected = vec![v1.public_key.clone(), v2.public_key.clone()];
+        expected.sort();
+        assert_eq!(validators, expected);
+    }
+
+    fn lock721_to(deps: &mut MockDeps, user: &str, token_id: &str, destination: (&str, &str)) {
+        execute_as(
+            deps,
+            user,
+            BridgeExecuteMsg::Lock721 {
+                data: Lock721Msg {
+                    destination_chain: destination.0.to_string(),
+                    destination_user_address: destination.1.to_string(),
+                    source_nft_contract_address: Addr::unchecked("collection"),
+                    collection_code_info: code_info(10),
+                    token_id: token_id.to_string(),
+                    metadata_uri: "uri".to_string(),
+                    private_metadata: None,
+                    private_lock: false,
+                },
+            },
+        )
+        .unwrap();
+    }
+
+    /// Claims token `token_id` to `user` with the signature of `validator`
+    fn claim721_to(deps: &mut MockDeps, validator: &TestValidator, user: &str, token_id: &str) {
+        let data = ClaimData {
+            token_id: token_id.to_string(),
+            source_chain: "BSC".to_string(),
+            destination_chain: "SECRET".to_string(),
+            destination_user_address: Addr::unchecked(user),
+            source_nft_contract_address: "0xcollection".to_string(),
+            name: "name".to_string(),
+            symbol: "SYM".to_string(),
+            royalty: 0,
+            royalty_receiver: Addr::unchecked("royalty_receiver"),
+            metadata: "uri".to_string(),
+            transaction_hash: format!("0xlock{}", token_id),
+            token_amount: 1,
+            nft_type: "singular".to_string(),
+            fee: 10,
+            lock_tx_chain: "BSC".to_string(),
+            private_metadata: None,
+        };
+        let hash = Sha256::digest(data.concat_all_fields().as_bytes());
+        let signature = secp256k1::Secp256k1::new()
+            .sign_ecdsa(
+                &secp256k1::Message::from_slice(hash.as_slice()).unwrap(),
+                &validator.secret,
+            )
+            .serialize_compact();
+        deps.querier
+            .update_balance(env().contract.address, coins(1_000, "uscrt"));
+        execute(
+            deps.as_mut(),
+            env(),
+            mock_info("claimer", &coins(10, "uscrt")),
+            BridgeExecuteMsg::Claim721 {
+                data: ClaimMsg {
+                    data,
+                    signatures: vec![SignerAndSignature {
+                        signer_address: validator.public_key.clone(),
+                        signature: Binary::from(signature.to_vec()),
+                    }],
+                },
+            },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn viewing_key_queries_need_the_key_of_the_address() {
+        let mut deps = instantiate_bridge(vec![]);
+        lock721_to(&mut deps, "user", "1", ("BSC", "0xa"));
+        let queries = |address: &str, key: &str| {
+            let (address, key) = (Addr::unchecked(address), key.to_string());
+            vec![
+                BridgeQueryMsg::GetMyLocks {
+                    address: address.clone(),
+                    key: key.clone(),
+                    page: None,
+                    page_size: 10,
+                },
+                BridgeQueryMsg::GetMyClaims {
+                    address: address.clone(),
+                    key: key.clone(),
+                    page: None,
+                    page_size: 10,
+                },
+                BridgeQueryMsg::GetMyDestinations {
+                    address,
+                    key,
+                    page: None,
+                    page_size: 10,
+                },
+            ]
+        };
+
+        // no key set yet
+        for msg in queries("user", "key") {
+            let answer = query_answer(&deps, msg).unwrap();
+            assert!(matches!(answer, BridgeQueryAnswer::ViewingKeyError { .. }));
+        }
+
+        set_key(&mut deps, "user", "key");
+        for msg in queries("user", "wrong") {
+            let answer = query_answer(&deps, msg).unwrap();
+            assert!(matches!(answer, BridgeQueryAnswer::ViewingKeyError { .. }));
+        }
+        // the key of another address doesn't open the records of `user`
+        set_key(&mut deps, "other", "other_key");
+        for msg in queries("user", "other_key") {
+            let answer = query_answer(&deps, msg).unwrap();
+            assert!(matches!(answer, BridgeQueryAnswer::ViewingKeyError { .. }));
+        }
+        for msg in queries("user", "key") {
+            let answer = query_answer(&deps, msg).unwrap();
+            assert!(!matches!(answer, BridgeQueryAnswer::ViewingKeyError { .. }));
+        }
+    }
+
+    struct TestUser {
+        secret: secp256k1::SecretKey,
+        public_key: Binary,
+        account: String,
+    }
+
+    fn permit_user(seed: u8) -> TestUser {
+        let secret = secp256k1::SecretKey::from_slice(&[seed; 32]).unwrap();
+        let public_key = Binary::from(
+            secp256k1::PublicKey::from_secret_key(&secp256k1::Secp256k1::new(), &secret)
+                .serialize()
+                .to_vec(),
+        );
+        let account = bech32::encode(
+            "secret",
+            pubkey_to_account(&public_key).as_slice().to_base32(),
+            Variant::Bech32,
+        )
+        .unwrap();
+        TestUser {
+            secret,
+            public_key,
+            account,
+        }
+    }
+
+    /// Permit for the bridge signed by `user` the way wallets sign them
+    fn permit(user: &TestUser, name: &str, permissions: Vec<TokenPermissions>) -> Permit {
+        let params = PermitParams {
+            allowed_tokens: vec![env().contract.address.into_string()],
+            permit_name: name.to_string(),
+            chain_id: "secret-4".to_string(),
+            permissions,
+        };
+        let signed = to_binary(&SignedPermit::from_params(&params)).unwrap();
+        let hash = Sha256::digest(signed.as_slice());
+        let signature = secp256k1::Secp256k1::new()
+            .sign_ecdsa(
+                &secp256k1::Message::from_slice(hash.as_slice()).unwrap(),
+                &user.secret,
+            )
+            .serialize_compact();
+        Permit {
+            params,
+            signature: PermitSignature {
+                pub_key: PubKey {
+                    r#type: "tendermint/PubKeySecp256k1".to_string(),
+                    value: user.public_key.clone(),
+                },
+                signature: Binary::from(signature.to_vec()),
+            },
+        }
+    }
+
+    fn permit_locks(deps: &MockDeps, permit: Permit) -> StdResult<BridgeQueryAnswer> {
+        query_answer(
+            deps,
+            BridgeQueryMsg::WithPermit {
+                permit,
+                query: QueryWithPermit::MyLocks {
+                    page: None,
+                    page_size: 10,
+                },
+            },
+        )
+    }
+
+    #[test]
+    fn permit_queries_need_an_unrevoked_owner_permit() {
+        let mut deps = instantiate_bridge(vec![]);
+        let user = permit_user(1);
+        lock721_to(&mut deps, &user.account, "1", ("BSC", "0xa"));
+
+        match permit_locks(&deps, permit(&user, "owner", vec![TokenPermissions::Owner])).unwrap() {
+            BridgeQueryAnswer::Locks { locks, total } => {
+                assert_eq!(total, 1);
+                assert_eq!(locks[0].token_id, "1");
+            }
+            _ => panic!("unexpected answer"),
+        }
+
+        let balance_only = permit(&user, "balance", vec![TokenPermissions::Balance]);
+        let err = permit_locks(&deps, balance_only).unwrap_err();
+        assert!(
+            err.to_string().contains("`Owner` permit required"),
+            "{}",
+            err
+        );
+
+        execute_as(
+            &mut deps,
+            &user.account,
+            BridgeExecuteMsg::RevokePermit {
+                permit_name: "owner".to_string(),
+            },
+        )
+        .unwrap();
+        let revoked = permit(&user, "owner", vec![TokenPermissions::Owner]);
+        let err = permit_locks(&deps, revoked).unwrap_err();
+        assert!(err.to_string().contains("revoked"), "{}", err);
+
+        // revoking is per permit name
+        let renamed = permit(&user, "renamed", vec![TokenPermissions::Owner]);
+        assert!(permit_locks(&deps, renamed).is_ok());
+    }
+
+    #[test]
+    fn private_queries_page_locks_claims_and_destinations() {
+        let v = validator(1);
+        let mut deps = instantiate_with(&[&v]);
+        lock721_to(&mut deps, "user", "1", ("BSC", "0xa"));
+        lock721_to(&mut deps, "user", "2", ("ETH", "0xb"));
+        lock721_to(&mut deps, "user", "3", ("BSC", "0xa"));
+        lock721_to(&mut deps, "other", "4", ("BSC", "0xa"));
+        claim721_to(&mut deps, &v, "user", "5");
+        claim721_to(&mut deps, &v, "user", "6");
+        claim721_to(&mut deps, &v, "other", "7");
+        set_key(&mut deps, "user", "key");
+
+        let locks = |page: u32| match query_answer(
+            &deps,
+            BridgeQueryMsg::GetMyLocks {
+                address: Addr::unchecked("user"),
+                key: "key".to_string(),
+                page: Some(page),
+                page_size: 2,
+            },
+        )
+        .unwrap()
+        {
+            BridgeQueryAnswer::Locks { locks, total } => (locks, total),
+            _ => panic!("unexpected answer"),
+        };
+        let (first, total) = locks(0);
+        let (second, _) = locks(1);
+        assert_eq!(total, 3);
+        assert_eq!((first.len(), second.len()), (2, 1));
+        let mut token_ids: Vec<String> = first
+            .into_iter()
+            .chain(second)
+            .map(|lock: LockRecord| lock.token_id)
+            .collect();
+        token_ids.sort();
+        assert_eq!(token_ids, vec!["1", "2", "3"]);
+        assert!(locks(2).0.is_empty());
+
+        let claims = |page: u32| match query_answer(
+            &deps,
+            BridgeQueryMsg::GetMyClaims {
+                address: Addr::unchecked("user"),
+                key: "key".to_string(),
+                page: Some(page),
+                page_size: 1,
+            },
+        )
+        .unwrap()
+        {
+            BridgeQueryAnswer::Claims { claims, total } => (claims, total),
+            _ => panic!("unexpected answer"),
+        };
+        let (first, total) = claims(0);
+        let (second, _) = claims(1);
+        assert_eq!(total, 2);
+        let mut claimed: Vec<ClaimRecord> = first.into_iter().chain(second).collect();
+        claimed.sort_by(|a, b| a.token_id.cmp(&b.token_id));
+        assert_eq!(
+            claimed
+                .iter()
+                .map(|claim| (claim.token_id.as_str(), claim.transaction_hash.as_str()))
+                .collect::<Vec<_>>(),
+            vec![("5", "0xlock5"), ("6", "0xlock6")]
+        );
+        assert!(claimed.iter().all(|claim| claim.source_chain == "BSC"));
+
+        let destinations = match query_answer(
+            &deps,
+            BridgeQueryMsg::GetMyDestinations {
+                address: Addr::unchecked("user"),
+                key: "key".to_string(),
+                page: None,
+                page_size: 10,
+            },
+        )
+        .unwrap()
+        {
+            BridgeQueryAnswer::Destinations {
+                mut destinations,
+                total,
+            } => {
+                assert_eq!(total, 2);
+                destinations.sort_by(|a, b| a.chain.cmp(&b.chain));
+                destinations
+            }
+            _ => panic!("unexpected answer"),
+        };
+        assert_eq!(
+            destinations,
+            vec![
+                Destination {
+                    chain: "BSC".to_string(),
+                    address: "0xa".to_string(),
+                    locks: 2,
+                },
+                Destination {
+                    chain: "ETH".to_string(),
+                    address: "0xb".to_string(),
+                    locks: 1,
+                },
+            ]
+        );
+    }
+}
@@ -4,6 +4,8 @@ use cosmwasm_std::{
 };
 
 use secret_toolkit::utils::{pad_handle_result, InitCallback};
+use snip1155::bridge::bridge_token;
+use snip1155::state::metadata::Metadata;
 use snip1155::state::state_structs::{CurateTokenId, LbPair};
 
 use crate::bridge_msg::ReplyCollectionDeployerInfo;
@@ -146,6 +148,39 @@ pub fn execute(
             transaction_hash,
             lock_tx_chain
         ),
+        CollectionDeployerExecuteMsg::CreateBridgeCollection1155 {
+            label,
+            name,
+            symbol,
+            source_nft_contract_address,
+            source_chain,
+            destination_user_address,
+            token_id,
+            token_amount,
+            royalty,
+            royalty_receiver,
+            metadata,
+            private_metadata,
+            transaction_hash,
+            lock_tx_chain,
+        } => try_create_bridge_collection_1155(
+            deps,
+            info.sender,
+            label,
+            name,
+            symbol,
+            source_nft_contract_address,
+            source_chain,
+            destination_user_address,
+            token_id,
+            token_amount,
+            royalty,
+            royalty_receiver,
+            metadata,
+            private_metadata,
+            transaction_hash,
+            lock_tx_chain,
+        ),
     };
     pad_handle_result(response, BLOCK_SIZE)
 }
@@ -239,7 +274,7 @@ fn try_create_collection_1155(
     curators: Vec<Addr>,
     initial_tokens: Vec<CurateTokenId>,
     entropy: String,
-    lb_pair_info: LbPair,
+    lb_pair_info: Option<LbPair>,
     label: String,
     source_nft_contract_address: String,
     source_chain: String,
@@ -304,6 +339,76 @@ fn try_create_collection_1155(
     Ok(Response::new().add_submessage(init_submsg))
 }
 
+/// Returns Result<Response, ContractError>
+///
+/// create a collection with the bridge profile, whose admin, only curator and
+/// only minter is `bridge`
+///
+/// # Arguments
+///
+/// * `deps`   - DepsMut containing all the contract's external dependencies
+/// * `bridge` - address of the bridge the collection is deployed for
+fn try_create_bridge_collection_1155(
+    deps: DepsMut,
+    bridge: Addr,
+    label: String,
+    name: String,
+    symbol: String,
+    source_nft_contract_address: String,
+    source_chain: String,
+    destination_user_address: Addr,
+    token_id: String,
+    token_amount: u128,
+    royalty: u16,
+    royalty_receiver: Addr,
+    metadata: String,
+    private_metadata: Option<Metadata>,
+    transaction_hash: String,
+    lock_tx_chain: String,
+) -> Result<Response, ContractError> {
+    let initmsg = Collection1155InstantiateMsg {
+        has_admin: true,
+        admin: Some(bridge.clone()),
+        curators: vec![bridge.clone()],
+        initial_tokens: vec![bridge_token(
+            bridge,
+            token_id.clone(),
+            name.clone(),
+            symbol.clone(),
+            metadata.clone(),
+            private_metadata,
+            destination_user_address.clone(),
+            token_amount,
+        )],
+        entropy: name + &symbol + &source_nft_contract_address,
+        lb_pair_info: None,
+        label: label.clone(),
+        source_nft_contract_address,
+        source_chain,
+        destination_user_address,
+        token_id,
+        token_amount,
+        royalty,
+        royalty_receiver,
+        metadata,
+        transaction_hash,
+        lock_tx_chain,
+    };
+
+    let offspring_code = SNIP1155_CODE.load(deps.storage)?;
+    let init_submsg = SubMsg::reply_always(
+        initmsg.to_cosmos_msg(
+            None,
+            label,
+            offspring_code.code_id,
+            offspring_code.code_hash,
+            None,
+        )?,
+        SNIP1155_INSTANTIATE_REPLY_ID,
+    );
+    Ok(Response::new().add_submessage(init_submsg))
+}
+
 /////////////////////////////////////// Reply /////////////////////////////////////
 /// Returns Result<Response, ContractError>
 ///
@@ -3,6 +3,7 @@ use cosmwasm_std::Addr;
 use schemars::JsonSchema;
 use secret_toolkit::utils::{HandleCallback, InitCallback};
 use serde::{Deserialize, Serialize};
+use snip1155::state::metadata::Metadata;
 use snip1155::state::state_structs::{CurateTokenId, LbPair};
 
 use crate::state::BLOCK_SIZE;
@@ -52,7 +53,8 @@ pub enum CollectionDeployerExecuteMsg {
         curators: Vec<Addr>,
         initial_tokens: Vec<CurateTokenId>,
         entropy: String,
-        lb_pair_info: LbPair,
+        #[serde(default)]
+        lb_pair_info: Option<LbPair>,
         label: String,
         source_nft_contract_address: String,
         source_chain: String,
@@ -65,6 +67,24 @@ pub enum CollectionDeployerExecuteMsg {
         transaction_hash: String,
         lock_tx_chain: String
     },
+    /// creates a collection with the [bridge profile](snip1155::bridge), the
+    /// sender being the bridge, holding the first claimed token
+    CreateBridgeCollection1155 {
+        label: String,
+        name: String,
+        symbol: String,
+        source_nft_contract_address: String,
+        source_chain: String,
+        destination_user_address: Addr,
+        token_id: String,
+        token_amount: u128,
+        royalty: u16,
+        royalty_receiver: Addr,
+        metadata: String,
+        private_metadata: Option<Metadata>,
+        transaction_hash: String,
+        lock_tx_chain: String,
+    },
 }
 
 /// success or failure response
@@ -91,7 +91,7 @@ pub struct Collection1155InstantiateMsg {
     pub curators: Vec<Addr>,
     pub initial_tokens: Vec<CurateTokenId>,
     pub entropy: String,
-    pub lb_pair_info: LbPair,
+    pub lb_pair_info: Option<LbPair>,
     pub label: String,
     pub source_nft_contract_address: String,
     pub source_chain: String,
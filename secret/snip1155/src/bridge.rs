@@ -0,0 +1,55 @@
+//! Profile of the collections the bridge deploys for wrapped 1155 tokens.
+//!
+//! The bridge is the admin, the only curator and the only minter of these
+//! collections, and they have no LB pair. A token is curated with the amount
+//! of its first claim, and the supply of every later claim is minted.
+
+use cosmwasm_std::{Addr, Uint256};
+
+use crate::state::{
+    metadata::Metadata,
+    state_structs::{CurateTokenId, TknConfig, TokenIdBalance, TokenInfoMsg},
+};
+
+/// token configuration of the tokens of a bridge collection
+pub fn bridge_token_config(bridge: Addr) -> TknConfig {
+    TknConfig::Fungible {
+        minters: vec![bridge],
+        decimals: 6,
+        public_total_supply: true,
+        enable_mint: true,
+        enable_burn: true,
+        minter_may_update_metadata: true,
+    }
+}
+
+/// Curates `token_id` in a bridge collection with the `amount` claimed by `recipient`
+#[allow(clippy::too_many_arguments)]
+pub fn bridge_token(
+    bridge: Addr,
+    token_id: String,
+    name: String,
+    symbol: String,
+    token_uri: String,
+    private_metadata: Option<Metadata>,
+    recipient: Addr,
+    amount: u128,
+) -> CurateTokenId {
+    CurateTokenId {
+        token_info: TokenInfoMsg {
+            token_id,
+            name,
+            symbol,
+            token_config: bridge_token_config(bridge),
+            public_metadata: Some(Metadata {
+                token_uri: Some(token_uri),
+                extension: Option::None,
+            }),
+            private_metadata,
+        },
+        balances: vec![TokenIdBalance {
+            address: recipient,
+            amount: Uint256::from(amount),
+        }],
+    }
+}
@@ -2,6 +2,7 @@
 pub mod handles;
 pub mod queries;
 pub mod msg;
+pub mod bridge;
 pub mod state;
 pub mod receiver;
 pub mod reply;
@@ -31,7 +31,9 @@ pub struct Snip1155InstantiateMsg {
     pub initial_tokens: Vec<CurateTokenId>,
     /// for `create_viewing_key` function
     pub entropy: String,
-    pub lb_pair_info: LbPair,
+    /// only set for collections traded on an LB pair
+    #[serde(default)]
+    pub lb_pair_info: Option<LbPair>,
     pub label: String,
     pub source_nft_contract_address: String,
     pub source_chain: String,
@@ -26,7 +26,7 @@ pub struct ContractConfig {
     pub tx_cnt: u64,
     pub prng_seed: Vec<u8>,
     pub contract_address: Addr,
-    pub lb_pair_info: LbPair,
+    pub lb_pair_info: Option<LbPair>,
 }
 
 /// message sent my instantiator and curators for a specific `token_id`'s token info
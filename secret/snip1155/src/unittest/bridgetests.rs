@@ -0,0 +1,172 @@
+use super::super::{bridge::*, handles::*, msg::*, state::state_structs::*, state::*};
+use super::testhelpers::*;
+
+use cosmwasm_std::{testing::*, OwnedDeps, Response, StdResult, Uint256};
+
+/////////////////////////////////////////////////////////////////////////////////
+// Helper functions
+/////////////////////////////////////////////////////////////////////////////////
+
+/// inits a collection the way the collection deployer does for the bridge addr0,
+/// with the claim of 100 token_id 0 by addr1
+fn init_bridge_collection() -> (
+    StdResult<Response>,
+    OwnedDeps<MockStorage, MockApi, MockQuerier>,
+) {
+    let addr = init_addrs();
+    let mut deps = mock_dependencies();
+    let info = mock_info("collection_deployer", &[]);
+
+    let init_msg = Snip1155InstantiateMsg {
+        has_admin: true,
+        admin: Some(addr.a()),
+        curators: vec![addr.a()],
+        initial_tokens: vec![bridge_token(
+            addr.a(),
+            "0".to_string(),
+            "token0".to_string(),
+            "TKN".to_string(),
+            "token0_uri".to_string(),
+            None,
+            addr.b(),
+            100,
+        )],
+        entropy: "seedentropy".to_string(),
+        lb_pair_info: None,
+        label: "bridge_collection".to_string(),
+        source_nft_contract_address: "source_collection".to_string(),
+        source_chain: "BSC".to_string(),
+        destination_user_address: addr.b(),
+        token_id: "0".to_string(),
+        token_amount: 100,
+        royalty: 0,
+        royalty_receiver: addr.a(),
+        metadata: "token0_uri".to_string(),
+        transaction_hash: "lock_tx".to_string(),
+        lock_tx_chain: "BSC".to_string(),
+    };
+
+    (instantiate(deps.as_mut(), mock_env(), info, init_msg), deps)
+}
+
+/////////////////////////////////////////////////////////////////////////////////
+// Tests
+/////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_bridge_collection_init() -> StdResult<()> {
+    let addr = init_addrs();
+    let (init_result, deps) = init_bridge_collection();
+    init_result?;
+
+    // no LB pair, the bridge is the admin and the only curator
+    let config = contr_conf_r(&deps.storage).load()?;
+    assert!(config.lb_pair_info.is_none());
+    assert_eq!(config.admin, Some(addr.a()));
+    assert_eq!(config.curators, vec![addr.a()]);
+    assert_eq!(config.token_id_list, vec!["0".to_string()]);
+
+    // the bridge is the only minter of the token
+    let token_info = tkn_info_r(&deps.storage).load("0".as_bytes())?;
+    let token_config = token_info.token_config.flatten();
+    assert_eq!(token_config.minters, vec![addr.a()]);
+    assert!(token_config.enable_mint);
+    assert!(!token_config.is_nft);
+    assert_eq!(
+        token_info.public_metadata.unwrap().token_uri,
+        Some("token0_uri".to_string())
+    );
+
+    // the claimed amount is on the recipient, not the bridge
+    assert_eq!(
+        chk_bal(&deps.storage, "0", &addr.b()),
+        Some(Uint256::from(100u128))
+    );
+    assert_eq!(chk_bal(&deps.storage, "0", &addr.a()), None);
+
+    Ok(())
+}
+
+#[test]
+fn test_bridge_collection_mint() -> StdResult<()> {
+    let addr = init_addrs();
+    let (init_result, mut deps) = init_bridge_collection();
+    init_result?;
+
+    let msg = Snip1155ExecuteMsg::MintTokens {
+        mint_tokens: vec![TokenAmount {
+            token_id: "0".to_string(),
+            balances: vec![TokenIdBalance {
+                address: addr.c(),
+                amount: Uint256::from(50u128),
+            }],
+        }],
+        memo: None,
+        padding: None,
+    };
+
+    // the recipient of the claim cannot mint more
+    let info = mock_info(addr.b().as_str(), &[]);
+    let result = execute(deps.as_mut(), mock_env(), info, msg.clone());
+    assert!(extract_error_msg(&result).contains("Only curators are allowed"));
+    assert_eq!(chk_bal(&deps.storage, "0", &addr.c()), None);
+
+    // the bridge mints the supply of a later claim
+    let info = mock_info(addr.a().as_str(), &[]);
+    execute(deps.as_mut(), mock_env(), info, msg)?;
+    assert_eq!(
+        chk_bal(&deps.storage, "0", &addr.c()),
+        Some(Uint256::from(50u128))
+    );
+    assert_eq!(
+        chk_bal(&deps.storage, "0", &addr.b()),
+        Some(Uint256::from(100u128))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_bridge_collection_curate() -> StdResult<()> {
+    let addr = init_addrs();
+    let (init_result, mut deps) = init_bridge_collection();
+    init_result?;
+
+    let curate = |token_id: &str| Snip1155ExecuteMsg::CurateTokenIds {
+        initial_tokens: vec![bridge_token(
+            addr.a(),
+            token_id.to_string(),
+            "token1".to_string(),
+            "TKNA".to_string(),
+            "token1_uri".to_string(),
+            None,
+            addr.c(),
+            10,
+        )],
+        memo: None,
+        padding: None,
+    };
+
+    // only the bridge curates the token of a new claim
+    let info = mock_info(addr.b().as_str(), &[]);
+    let result = execute(deps.as_mut(), mock_env(), info, curate("1"));
+    assert!(extract_error_msg(&result).contains("Only curators are allowed"));
+
+    let info = mock_info(addr.a().as_str(), &[]);
+    execute(deps.as_mut(), mock_env(), info, curate("1"))?;
+    assert_eq!(
+        chk_bal(&deps.storage, "1", &addr.c()),
+        Some(Uint256::from(10u128))
+    );
+    let token_config = tkn_info_r(&deps.storage)
+        .load("1".as_bytes())?
+        .token_config
+        .flatten();
+    assert_eq!(token_config.minters, vec![addr.a()]);
+    assert_eq!(
+        contr_conf_r(&deps.storage).load()?.token_id_list,
+        vec!["0".to_string(), "1".to_string()]
+    );
+
+    Ok(())
+}
@@ -409,12 +409,12 @@ fn test_change_metadata_nft() -> StdResult<()> {
         curators: vec![addr.b()],
         initial_tokens: vec![],
         entropy: "seedentropy".to_string(),
-        lb_pair_info: LbPair {
+        lb_pair_info: Some(LbPair {
             name: String::new(),
             symbol: String::new(),
             lb_pair_address: Addr::unchecked("address"),
             decimals: 18,
-        },
+        }),
     };
     instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg)?;
 
@@ -663,12 +663,12 @@ fn test_change_metadata_fungible() -> StdResult<()> {
         curators: vec![addr.b()],
         initial_tokens: vec![],
         entropy: "seedentropy".to_string(),
-        lb_pair_info: LbPair {
+        lb_pair_info: Some(LbPair {
             name: String::new(),
             symbol: String::new(),
             lb_pair_address: Addr::unchecked("address"),
             decimals: 18,
-        },
+        }),
     };
     instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg)?;
 
@@ -2184,12 +2184,12 @@ fn test_instantiate_admin_inputs() -> StdResult<()> {
         curators: vec![],
         initial_tokens: vec![],
         entropy: "seedentropy".to_string(),
-        lb_pair_info: LbPair {
+        lb_pair_info: Some(LbPair {
             name: String::new(),
             symbol: String::new(),
             lb_pair_address: Addr::unchecked("address"),
             decimals: 18,
-        },
+        }),
     };
 
     instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg)?;
@@ -2204,12 +2204,12 @@ fn test_instantiate_admin_inputs() -> StdResult<()> {
         curators: vec![],
         initial_tokens: vec![],
         entropy: "seedentropy".to_string(),
-        lb_pair_info: LbPair {
+        lb_pair_info: Some(LbPair {
             name: String::new(),
             symbol: String::new(),
             lb_pair_address: Addr::unchecked("address"),
             decimals: 18,
-        },
+        }),
     };
 
     instantiate(deps.as_mut(), mock_env(), info.clone(), init_msg)?;
@@ -2224,12 +2224,12 @@ fn test_instantiate_admin_inputs() -> StdResult<()> {
         curators: vec![],
         initial_tokens: vec![],
         entropy: "seedentropy".to_string(),
-        lb_pair_info: LbPair {
+        lb_pair_info: Some(LbPair {
             name: String::new(),
             symbol: String::new(),
             lb_pair_address: Addr::unchecked("address"),
             decimals: 18,
-        },
+        }),
     };
 
     info.sender = addr.a();
@@ -2245,12 +2245,12 @@ fn test_instantiate_admin_inputs() -> StdResult<()> {
         curators: vec![],
         initial_tokens: vec![],
         entropy: "seedentropy".to_string(),
-        lb_pair_info: LbPair {
+        lb_pair_info: Some(LbPair {
             name: String::new(),
             symbol: String::new(),
             lb_pair_address: Addr::unchecked("address"),
             decimals: 18,
-        },
+        }),
     };
 
     info.sender = addr.a();
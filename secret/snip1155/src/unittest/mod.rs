@@ -1,3 +1,4 @@
+mod bridgetests;
 mod handletests;
 mod querytests;
 mod testhelpers;